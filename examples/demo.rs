@@ -3,7 +3,7 @@
 //! Run with: cargo run --example demo
 
 use floem::prelude::*;
-use floem_tailwind::TailwindExt;
+use floem_tailwind::prelude::*;
 
 fn main() {
     floem::launch(app_view);
@@ -0,0 +1,7 @@
+//! Prints the utility manifest as JSON, for editor plugins and autocompletion
+//!
+//! Run with: cargo run --example manifest --features json > manifest.json
+
+fn main() {
+    println!("{}", floem_tailwind::manifest::manifest().to_json());
+}
@@ -0,0 +1,1614 @@
+//! Data-driven styling with `TwClass`
+//!
+//! `TwClass` is a value-level description of a single utility. Unlike the
+//! `TailwindExt` methods it can be stored, compared, generated, and converted
+//! to and from Tailwind class names.
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::TailwindExt;
+//! use floem_tailwind::class::TwClass;
+//! use floem_tailwind::colors::Family;
+//! use floem_tailwind::Spacing;
+//!
+//! let classes = [TwClass::P(Spacing::S4.into()), TwClass::BgColor(Family::Blue, 500)];
+//! let style = Style::new().apply_tw_classes(&classes);
+//!
+//! let parsed: TwClass = "bg-blue-500".parse().unwrap();
+//! assert_eq!(parsed, classes[1]);
+//! assert_eq!(classes[0].to_string(), "p-4");
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{LazyLock, Mutex};
+
+use floem::style::Style;
+use floem::unit::{PxPct, PxPctAuto};
+use floem::views::PlaceholderTextClass;
+
+use crate::colors::{self, Family};
+use crate::registry::{self, Utility};
+use crate::screen::Breakpoint;
+use crate::spacing::{self, Spacing};
+use crate::{
+    contrast, direction, dpi, font_size, font_weight, line_height, presets, radius, rem, shadow,
+};
+
+/// Macro to define a keyword parameter enum with class-name fragments
+macro_rules! keyword_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $fragment:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            /// Every value, in scale order
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// The value's class-name fragment
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => $fragment,)*
+                }
+            }
+
+            /// Looks up a value by its class-name fragment
+            pub fn from_name(name: &str) -> Option<$name> {
+                $name::ALL.iter().copied().find(|value| value.name() == name)
+            }
+        }
+    };
+}
+
+keyword_enum!(
+    /// Named container sizes (w-xs ... w-7xl)
+    NamedSize {
+        Xs => "xs",
+        Sm => "sm",
+        Md => "md",
+        Lg => "lg",
+        Xl => "xl",
+        Xl2 => "2xl",
+        Xl3 => "3xl",
+        Xl4 => "4xl",
+        Xl5 => "5xl",
+        Xl6 => "6xl",
+        Xl7 => "7xl",
+    }
+);
+
+impl NamedSize {
+    /// The size in pixels
+    pub const fn to_px(self) -> f64 {
+        match self {
+            NamedSize::Xs => spacing::SIZE_XS,
+            NamedSize::Sm => spacing::SIZE_SM,
+            NamedSize::Md => spacing::SIZE_MD,
+            NamedSize::Lg => spacing::SIZE_LG,
+            NamedSize::Xl => spacing::SIZE_XL,
+            NamedSize::Xl2 => spacing::SIZE_2XL,
+            NamedSize::Xl3 => spacing::SIZE_3XL,
+            NamedSize::Xl4 => spacing::SIZE_4XL,
+            NamedSize::Xl5 => spacing::SIZE_5XL,
+            NamedSize::Xl6 => spacing::SIZE_6XL,
+            NamedSize::Xl7 => spacing::SIZE_7XL,
+        }
+    }
+
+    /// The size in pixels at the current [`rem`](crate::rem) root
+    pub fn scaled_px(self) -> f64 {
+        rem::scale(self.to_px())
+    }
+}
+
+/// A length value for sizing, margin, inset, and translate utilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Length {
+    /// A step on the spacing scale (`w-4`)
+    Spacing(Spacing),
+    /// A named container size (`w-md`)
+    Named(NamedSize),
+    /// A fraction of the parent, numerator / denominator (`w-1/2`)
+    Fraction(u8, u8),
+    /// 100% (`w-full`)
+    Full,
+    /// auto (`w-auto`)
+    Auto,
+}
+
+impl Length {
+    /// Looks up a length by its class-name fragment
+    pub fn from_name(name: &str) -> Option<Length> {
+        match name {
+            "full" => return Some(Length::Full),
+            "auto" => return Some(Length::Auto),
+            _ => {}
+        }
+        if let Some((num, den)) = name.split_once('/') {
+            let num: u8 = num.parse().ok()?;
+            let den: u8 = den.parse().ok()?;
+            return (den > 0 && num <= den).then_some(Length::Fraction(num, den));
+        }
+        Spacing::from_name(name)
+            .map(Length::Spacing)
+            .or_else(|| NamedSize::from_name(name).map(Length::Named))
+    }
+
+    /// Resolves the length to a Floem unit
+    pub fn to_unit(self) -> PxPctAuto {
+        match self {
+            Length::Spacing(step) => PxPctAuto::Px(step.scaled_px()),
+            Length::Named(size) => PxPctAuto::Px(size.scaled_px()),
+            Length::Fraction(num, den) => PxPctAuto::Pct(num as f64 / den as f64 * 100.0),
+            Length::Full => PxPctAuto::Pct(100.0),
+            Length::Auto => PxPctAuto::Auto,
+        }
+    }
+
+    /// Resolves the length to a unit without `auto` (treated as 0)
+    fn to_px_pct(self) -> PxPct {
+        match self.to_unit() {
+            PxPctAuto::Px(px) => PxPct::Px(px),
+            PxPctAuto::Pct(pct) => PxPct::Pct(pct),
+            PxPctAuto::Auto => PxPct::Px(0.0),
+        }
+    }
+
+    /// Resolves the length to a unit without `auto`, negated
+    fn to_neg_px_pct(self) -> PxPct {
+        match self.to_px_pct() {
+            PxPct::Px(px) => PxPct::Px(-px),
+            PxPct::Pct(pct) => PxPct::Pct(-pct),
+        }
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Length::Spacing(step) => f.write_str(step.name()),
+            Length::Named(size) => f.write_str(size.name()),
+            Length::Fraction(num, den) => write!(f, "{num}/{den}"),
+            Length::Full => f.write_str("full"),
+            Length::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+impl From<Spacing> for Length {
+    fn from(step: Spacing) -> Self {
+        Length::Spacing(step)
+    }
+}
+
+keyword_enum!(
+    /// Border radius scale (rounded-*); `Default` is plain `rounded`
+    Radius {
+        None => "none",
+        Sm => "sm",
+        Default => "",
+        Md => "md",
+        Lg => "lg",
+        Xl => "xl",
+        Xl2 => "2xl",
+        Xl3 => "3xl",
+        Full => "full",
+    }
+);
+
+impl Radius {
+    /// The radius in pixels
+    pub const fn to_px(self) -> f64 {
+        match self {
+            Radius::None => radius::ROUNDED_NONE,
+            Radius::Sm => radius::ROUNDED_SM,
+            Radius::Default => radius::ROUNDED,
+            Radius::Md => radius::ROUNDED_MD,
+            Radius::Lg => radius::ROUNDED_LG,
+            Radius::Xl => radius::ROUNDED_XL,
+            Radius::Xl2 => radius::ROUNDED_2XL,
+            Radius::Xl3 => radius::ROUNDED_3XL,
+            Radius::Full => radius::ROUNDED_FULL,
+        }
+    }
+
+    /// The radius in pixels at the current [`density`](crate::density)
+    pub fn scaled_px(self) -> f64 {
+        radius::scale(self.to_px())
+    }
+}
+
+keyword_enum!(
+    /// Box shadow presets (shadow-*); `Default` is plain `shadow`
+    Shadow {
+        Sm => "sm",
+        Default => "",
+        Md => "md",
+        Lg => "lg",
+        Xl => "xl",
+        Xl2 => "2xl",
+        None => "none",
+    }
+);
+
+keyword_enum!(
+    /// Drop shadow presets (drop-shadow-*); `Default` is plain `drop-shadow`
+    DropShadow {
+        Sm => "sm",
+        Default => "",
+        Md => "md",
+        Lg => "lg",
+        Xl => "xl",
+        Xl2 => "2xl",
+        None => "none",
+    }
+);
+
+impl DropShadow {
+    /// The preset's shadow layers, built once per program
+    pub fn to_shadows(self) -> &'static [floem::style::BoxShadow] {
+        type Layers<const N: usize> = LazyLock<[floem::style::BoxShadow; N]>;
+        static SM: Layers<1> = LazyLock::new(shadow::drop_shadow_sm);
+        static DEFAULT: Layers<2> = LazyLock::new(shadow::drop_shadow_default);
+        static MD: Layers<2> = LazyLock::new(shadow::drop_shadow_md);
+        static LG: Layers<2> = LazyLock::new(shadow::drop_shadow_lg);
+        static XL: Layers<2> = LazyLock::new(shadow::drop_shadow_xl);
+        static XL2: Layers<1> = LazyLock::new(shadow::drop_shadow_2xl);
+        match self {
+            DropShadow::Sm => &*SM,
+            DropShadow::Default => &*DEFAULT,
+            DropShadow::Md => &*MD,
+            DropShadow::Lg => &*LG,
+            DropShadow::Xl => &*XL,
+            DropShadow::Xl2 => &*XL2,
+            DropShadow::None => shadow::NONE,
+        }
+    }
+}
+
+keyword_enum!(
+    /// Font size scale (text-*)
+    TextSize {
+        Xs => "xs",
+        Sm => "sm",
+        Base => "base",
+        Lg => "lg",
+        Xl => "xl",
+        Xl2 => "2xl",
+        Xl3 => "3xl",
+        Xl4 => "4xl",
+        Xl5 => "5xl",
+        Xl6 => "6xl",
+        Xl7 => "7xl",
+        Xl8 => "8xl",
+        Xl9 => "9xl",
+    }
+);
+
+impl TextSize {
+    /// The font size in pixels
+    pub const fn to_px(self) -> f32 {
+        match self {
+            TextSize::Xs => font_size::TEXT_XS,
+            TextSize::Sm => font_size::TEXT_SM,
+            TextSize::Base => font_size::TEXT_BASE,
+            TextSize::Lg => font_size::TEXT_LG,
+            TextSize::Xl => font_size::TEXT_XL,
+            TextSize::Xl2 => font_size::TEXT_2XL,
+            TextSize::Xl3 => font_size::TEXT_3XL,
+            TextSize::Xl4 => font_size::TEXT_4XL,
+            TextSize::Xl5 => font_size::TEXT_5XL,
+            TextSize::Xl6 => font_size::TEXT_6XL,
+            TextSize::Xl7 => font_size::TEXT_7XL,
+            TextSize::Xl8 => font_size::TEXT_8XL,
+            TextSize::Xl9 => font_size::TEXT_9XL,
+        }
+    }
+
+    /// The font size in pixels at the current [`rem`](crate::rem) root
+    pub fn scaled_px(self) -> f32 {
+        rem::scale_f32(self.to_px())
+    }
+}
+
+keyword_enum!(
+    /// Font weights (font-*)
+    FontWeight {
+        Thin => "thin",
+        ExtraLight => "extralight",
+        Light => "light",
+        Normal => "normal",
+        Medium => "medium",
+        Semibold => "semibold",
+        Bold => "bold",
+        ExtraBold => "extrabold",
+        Black => "black",
+    }
+);
+
+impl FontWeight {
+    /// The matching Floem font weight
+    pub const fn to_weight(self) -> floem::text::Weight {
+        match self {
+            FontWeight::Thin => font_weight::THIN,
+            FontWeight::ExtraLight => font_weight::EXTRALIGHT,
+            FontWeight::Light => font_weight::LIGHT,
+            FontWeight::Normal => font_weight::NORMAL,
+            FontWeight::Medium => font_weight::MEDIUM,
+            FontWeight::Semibold => font_weight::SEMIBOLD,
+            FontWeight::Bold => font_weight::BOLD,
+            FontWeight::ExtraBold => font_weight::EXTRABOLD,
+            FontWeight::Black => font_weight::BLACK,
+        }
+    }
+}
+
+keyword_enum!(
+    /// Font widths (font-stretch-*), for fonts with a width axis or
+    /// condensed and expanded faces
+    FontStretch {
+        UltraCondensed => "ultra-condensed",
+        ExtraCondensed => "extra-condensed",
+        Condensed => "condensed",
+        SemiCondensed => "semi-condensed",
+        Normal => "normal",
+        SemiExpanded => "semi-expanded",
+        Expanded => "expanded",
+        ExtraExpanded => "extra-expanded",
+        UltraExpanded => "ultra-expanded",
+    }
+);
+
+impl FontStretch {
+    /// The font width
+    pub const fn to_stretch(self) -> floem::text::Stretch {
+        use floem::text::Stretch;
+        match self {
+            FontStretch::UltraCondensed => Stretch::UltraCondensed,
+            FontStretch::ExtraCondensed => Stretch::ExtraCondensed,
+            FontStretch::Condensed => Stretch::Condensed,
+            FontStretch::SemiCondensed => Stretch::SemiCondensed,
+            FontStretch::Normal => Stretch::Normal,
+            FontStretch::SemiExpanded => Stretch::SemiExpanded,
+            FontStretch::Expanded => Stretch::Expanded,
+            FontStretch::ExtraExpanded => Stretch::ExtraExpanded,
+            FontStretch::UltraExpanded => Stretch::UltraExpanded,
+        }
+    }
+}
+
+keyword_enum!(
+    /// Line heights (leading-*)
+    Leading {
+        None => "none",
+        Tight => "tight",
+        Snug => "snug",
+        Normal => "normal",
+        Relaxed => "relaxed",
+        Loose => "loose",
+    }
+);
+
+impl Leading {
+    /// The line height multiplier
+    pub const fn to_multiplier(self) -> f32 {
+        match self {
+            Leading::None => line_height::LEADING_NONE,
+            Leading::Tight => line_height::LEADING_TIGHT,
+            Leading::Snug => line_height::LEADING_SNUG,
+            Leading::Normal => line_height::LEADING_NORMAL,
+            Leading::Relaxed => line_height::LEADING_RELAXED,
+            Leading::Loose => line_height::LEADING_LOOSE,
+        }
+    }
+}
+
+keyword_enum!(
+    /// Fixed line heights on the spacing scale, the `6` of `text-sm/6`
+    FixedLeading {
+        L3 => "3",
+        L4 => "4",
+        L5 => "5",
+        L6 => "6",
+        L7 => "7",
+        L8 => "8",
+        L9 => "9",
+        L10 => "10",
+    }
+);
+
+impl FixedLeading {
+    /// The line height in pixels, assuming a 16px root
+    pub const fn to_px(self) -> f64 {
+        match self {
+            FixedLeading::L3 => spacing::SPACING_3,
+            FixedLeading::L4 => spacing::SPACING_4,
+            FixedLeading::L5 => spacing::SPACING_5,
+            FixedLeading::L6 => spacing::SPACING_6,
+            FixedLeading::L7 => spacing::SPACING_7,
+            FixedLeading::L8 => spacing::SPACING_8,
+            FixedLeading::L9 => spacing::SPACING_9,
+            FixedLeading::L10 => spacing::SPACING_10,
+        }
+    }
+
+    /// The line height in pixels at the current [`rem`](crate::rem) root
+    pub fn scaled_px(self) -> f64 {
+        rem::scale(self.to_px())
+    }
+}
+
+keyword_enum!(
+    /// Special colors outside the palette families
+    ColorKeyword {
+        Transparent => "transparent",
+        Black => "black",
+        White => "white",
+    }
+);
+
+impl ColorKeyword {
+    /// The keyword's color
+    pub const fn to_color(self) -> peniko::Color {
+        match self {
+            ColorKeyword::Transparent => colors::TRANSPARENT,
+            ColorKeyword::Black => colors::BLACK,
+            ColorKeyword::White => colors::WHITE,
+        }
+    }
+}
+
+keyword_enum!(
+    /// Display modes
+    Display {
+        Flex => "flex",
+        Block => "block",
+        Grid => "grid",
+        Hidden => "hidden",
+    }
+);
+
+keyword_enum!(
+    /// Flex directions (flex-*)
+    FlexDirection {
+        Row => "flex-row",
+        Col => "flex-col",
+        RowReverse => "flex-row-reverse",
+        ColReverse => "flex-col-reverse",
+    }
+);
+
+keyword_enum!(
+    /// Flex wrapping (flex-wrap, flex-nowrap, flex-wrap-reverse)
+    FlexWrap {
+        Wrap => "flex-wrap",
+        NoWrap => "flex-nowrap",
+        WrapReverse => "flex-wrap-reverse",
+    }
+);
+
+keyword_enum!(
+    /// Positioning schemes
+    Position {
+        Absolute => "absolute",
+        Relative => "relative",
+        Fixed => "fixed",
+    }
+);
+
+keyword_enum!(
+    /// Edges for inset utilities; `All` is `inset-*`
+    Edge {
+        All => "inset",
+        Top => "top",
+        Right => "right",
+        Bottom => "bottom",
+        Left => "left",
+    }
+);
+
+keyword_enum!(
+    /// justify-content values (justify-*)
+    Justify {
+        Start => "start",
+        Center => "center",
+        End => "end",
+        Between => "between",
+        Around => "around",
+        Evenly => "evenly",
+    }
+);
+
+keyword_enum!(
+    /// align-items / align-self values (items-*, self-*); `Auto` is only valid for self-*
+    Align {
+        Auto => "auto",
+        Start => "start",
+        Center => "center",
+        End => "end",
+        Stretch => "stretch",
+        Baseline => "baseline",
+    }
+);
+
+keyword_enum!(
+    /// Inline alignment (align-*), applied as the item's align-self since
+    /// Floem has no vertical-align
+    VerticalAlign {
+        Baseline => "baseline",
+        Middle => "middle",
+        Top => "top",
+        Bottom => "bottom",
+    }
+);
+
+keyword_enum!(
+    /// Cursor styles (cursor-*)
+    Cursor {
+        Pointer => "pointer",
+        Default => "default",
+        Text => "text",
+        Move => "move",
+        Grab => "grab",
+        Grabbing => "grabbing",
+    }
+);
+
+keyword_enum!(
+    /// Overflow behaviors (overflow-*)
+    Overflow {
+        Hidden => "hidden",
+        Visible => "visible",
+        Scroll => "scroll",
+        Clip => "clip",
+        Auto => "auto",
+    }
+);
+
+/// A single Tailwind utility as a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TwClass {
+    // === Sizing ===
+    W(Length),
+    H(Length),
+    Size(Length),
+    MinW(Length),
+    MaxW(Length),
+    MaxWScreen(Breakpoint),
+    MinH(Length),
+    MaxH(Length),
+
+    // === Spacing ===
+    P(Length),
+    Px(Length),
+    Py(Length),
+    Pt(Length),
+    Pr(Length),
+    Pb(Length),
+    Pl(Length),
+    /// Padding on the start side, left or right by [`direction`](crate::direction)
+    Ps(Length),
+    /// Padding on the end side
+    Pe(Length),
+    M(Length),
+    Mx(Length),
+    My(Length),
+    Mt(Length),
+    Mr(Length),
+    Mb(Length),
+    Ml(Length),
+    /// Margin on the start side, left or right by [`direction`](crate::direction)
+    Ms(Length),
+    /// Margin on the end side
+    Me(Length),
+    Gap(Spacing),
+
+    // === Borders ===
+    Rounded(Radius),
+    /// Radius of the start corners, left or right by [`direction`](crate::direction)
+    RoundedStart(Radius),
+    /// Radius of the end corners
+    RoundedEnd(Radius),
+    /// Border width in pixels
+    BorderWidth(u8),
+    BorderColor(Family, u16),
+    BorderKeyword(ColorKeyword),
+    OutlineNone,
+    /// Focus ring offset in pixels
+    OutlineOffset(u8),
+
+    // === Effects ===
+    Shadow(Shadow),
+    DropShadow(DropShadow),
+    Elevation(u8),
+    /// Opacity in percent (0-100)
+    Opacity(u8),
+
+    // === Colors ===
+    BgColor(Family, u16),
+    BgKeyword(ColorKeyword),
+    TextColor(Family, u16),
+    TextKeyword(ColorKeyword),
+    /// Placeholder text color of text inputs
+    PlaceholderColor(Family, u16),
+    PlaceholderKeyword(ColorKeyword),
+
+    // === Typography ===
+    TextSize(TextSize),
+    /// A font size with a fixed line height (`text-sm/6`)
+    TextSizeLeading(TextSize, FixedLeading),
+    FontWeight(FontWeight),
+    FontStretch(FontStretch),
+    Leading(Leading),
+
+    // === Layout ===
+    Display(Display),
+    FlexDirection(FlexDirection),
+    FlexWrap(FlexWrap),
+    Position(Position),
+    Inset(Edge, Length),
+    /// A negative inset (`-top-2`)
+    NegInset(Edge, Length),
+    TranslateX(Length),
+    TranslateY(Length),
+    NegTranslateX(Length),
+    NegTranslateY(Length),
+    /// Stacking order; `None` is `z-auto`
+    ZIndex(Option<i32>),
+    Justify(Justify),
+    Items(Align),
+    SelfAlign(Align),
+    VerticalAlign(VerticalAlign),
+
+    // === Interactivity ===
+    Cursor(Cursor),
+    PointerEventsNone,
+    PointerEventsAuto,
+    Overflow(Overflow),
+    OverflowX(Overflow),
+    OverflowY(Overflow),
+
+    // === Registered ===
+    /// A utility from the [`registry`](crate::registry), e.g. a custom one
+    Utility(Utility),
+
+    // === Variants ===
+    /// A utility applied only in [high-contrast mode](crate::contrast)
+    /// (`contrast-more:border-2`)
+    ContrastMore(ContrastMore),
+    /// A utility applied while the view is pressed (`active:bg-blue-700`)
+    Active(Active),
+}
+
+/// Utilities behind a variant, kept for the rest of the program so
+/// `TwClass` stays `Copy`
+///
+/// Only utilities without a variant of their own are kept, so the set is
+/// bounded by the utilities the parser knows, however many class strings are
+/// built at runtime.
+static VARIANT_CLASSES: LazyLock<Mutex<HashSet<&'static TwClass>>> =
+    LazyLock::new(Default::default);
+
+/// The utility of a `contrast-more:` class
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContrastMore(&'static TwClass);
+
+/// Whether `class` is itself behind a variant, which can't be nested
+const fn is_variant(class: TwClass) -> bool {
+    matches!(class, TwClass::ContrastMore(_) | TwClass::Active(_))
+}
+
+/// The kept copy of a utility behind a variant
+///
+/// # Panics
+///
+/// If `class` is itself behind a variant.
+fn variant_class(class: TwClass) -> &'static TwClass {
+    assert!(!is_variant(class), "variants can't be nested: `{class}`");
+    let mut classes = VARIANT_CLASSES
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    match classes.get(&class) {
+        Some(&class) => class,
+        None => {
+            let class = Box::leak(Box::new(class));
+            classes.insert(class);
+            class
+        }
+    }
+}
+
+impl ContrastMore {
+    /// Puts `class` behind the `contrast-more:` variant
+    ///
+    /// # Panics
+    ///
+    /// If `class` is itself behind a variant.
+    pub fn new(class: TwClass) -> Self {
+        ContrastMore(variant_class(class))
+    }
+
+    /// The utility applied in high-contrast mode
+    pub const fn class(self) -> TwClass {
+        *self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ContrastMore {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ContrastMore {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let class = TwClass::deserialize(deserializer)?;
+        if is_variant(class) {
+            return Err(serde::de::Error::custom("variants can't be nested"));
+        }
+        Ok(ContrastMore::new(class))
+    }
+}
+
+/// The utility of an `active:` class
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Active(&'static TwClass);
+
+impl Active {
+    /// Puts `class` behind the `active:` variant
+    ///
+    /// # Panics
+    ///
+    /// If `class` is itself behind a variant.
+    pub fn new(class: TwClass) -> Self {
+        Active(variant_class(class))
+    }
+
+    /// The utility applied while the view is pressed
+    pub const fn class(self) -> TwClass {
+        *self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Active {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Active {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let class = TwClass::deserialize(deserializer)?;
+        if is_variant(class) {
+            return Err(serde::de::Error::custom("variants can't be nested"));
+        }
+        Ok(Active::new(class))
+    }
+}
+
+/// Resolves an overflow keyword to Floem's overflow value (`auto` scrolls)
+fn overflow_value(overflow: Overflow) -> floem::taffy::Overflow {
+    match overflow {
+        Overflow::Hidden => floem::taffy::Overflow::Hidden,
+        Overflow::Visible => floem::taffy::Overflow::Visible,
+        Overflow::Scroll | Overflow::Auto => floem::taffy::Overflow::Scroll,
+        Overflow::Clip => floem::taffy::Overflow::Clip,
+    }
+}
+
+/// Resolves an alignment keyword to Floem's align value (`auto` is unset)
+fn align_value(align: Align) -> Option<floem::style::AlignItems> {
+    match align {
+        Align::Auto => None,
+        Align::Start => Some(floem::style::AlignItems::FlexStart),
+        Align::Center => Some(floem::style::AlignItems::Center),
+        Align::End => Some(floem::style::AlignItems::FlexEnd),
+        Align::Stretch => Some(floem::style::AlignItems::Stretch),
+        Align::Baseline => Some(floem::style::AlignItems::Baseline),
+    }
+}
+
+impl TwClass {
+    /// Applies this utility to a style
+    pub fn apply(self, s: Style) -> Style {
+        match self {
+            // Sizing
+            TwClass::W(len) => s.width(len.to_unit()),
+            TwClass::H(len) => s.height(len.to_unit()),
+            TwClass::Size(len) => s.width(len.to_unit()).height(len.to_unit()),
+            TwClass::MinW(len) => s.min_width(len.to_unit()),
+            TwClass::MaxW(len) => s.max_width(len.to_unit()),
+            TwClass::MaxWScreen(screen) => s.max_width(screen.min_width()),
+            TwClass::MinH(len) => s.min_height(len.to_unit()),
+            TwClass::MaxH(len) => s.max_height(len.to_unit()),
+
+            // Spacing
+            TwClass::P(len) => s.padding(len.to_px_pct()),
+            TwClass::Px(len) => s.padding_horiz(len.to_px_pct()),
+            TwClass::Py(len) => s.padding_vert(len.to_px_pct()),
+            TwClass::Pt(len) => s.padding_top(len.to_px_pct()),
+            TwClass::Pr(len) => s.padding_right(len.to_px_pct()),
+            TwClass::Pb(len) => s.padding_bottom(len.to_px_pct()),
+            TwClass::Pl(len) => s.padding_left(len.to_px_pct()),
+            TwClass::Ps(len) => s.apply(direction::padding_start(len.to_px_pct())),
+            TwClass::Pe(len) => s.apply(direction::padding_end(len.to_px_pct())),
+            TwClass::M(len) => s.margin(len.to_unit()),
+            TwClass::Mx(len) => s.margin_horiz(len.to_unit()),
+            TwClass::My(len) => s.margin_vert(len.to_unit()),
+            TwClass::Mt(len) => s.margin_top(len.to_unit()),
+            TwClass::Mr(len) => s.margin_right(len.to_unit()),
+            TwClass::Mb(len) => s.margin_bottom(len.to_unit()),
+            TwClass::Ml(len) => s.margin_left(len.to_unit()),
+            TwClass::Ms(len) => s.apply(direction::margin_start(len.to_unit())),
+            TwClass::Me(len) => s.apply(direction::margin_end(len.to_unit())),
+            TwClass::Gap(step) => s.gap(step),
+
+            // Borders
+            TwClass::Rounded(radius) => s.border_radius(radius.scaled_px()),
+            TwClass::RoundedStart(radius) => s.apply(direction::rounded_start(radius.scaled_px())),
+            TwClass::RoundedEnd(radius) => s.apply(direction::rounded_end(radius.scaled_px())),
+            TwClass::BorderWidth(width) => s.border(dpi::snap(width as f64)),
+            TwClass::BorderColor(family, shade) => match family.shade(shade) {
+                Some(color) => s.border_color(color),
+                None => s,
+            },
+            TwClass::BorderKeyword(keyword) => s.border_color(keyword.to_color()),
+            TwClass::OutlineNone => s.outline(0.0),
+            TwClass::OutlineOffset(offset) => crate::outline_offset(s, offset as f64),
+
+            // Effects
+            TwClass::Shadow(preset) => {
+                let shadow = match preset {
+                    Shadow::Sm => Some(shadow::shadow_sm()),
+                    Shadow::Default => Some(shadow::shadow_default()),
+                    Shadow::Md => Some(shadow::shadow_md()),
+                    Shadow::Lg => Some(shadow::shadow_lg()),
+                    Shadow::Xl => Some(shadow::shadow_xl()),
+                    Shadow::Xl2 => Some(shadow::shadow_2xl()),
+                    Shadow::None => None,
+                };
+                s.apply_box_shadows(shadow.as_slice())
+            }
+            TwClass::DropShadow(preset) => s.apply_box_shadows(preset.to_shadows()),
+            TwClass::Elevation(level) => s.apply(presets::elevation(level)),
+            TwClass::Opacity(pct) => s.set(floem::style::Opacity, pct.min(100) as f32 / 100.0),
+
+            // Colors
+            TwClass::BgColor(family, shade) => match family.shade(shade) {
+                Some(color) => s.background(color),
+                None => s,
+            },
+            TwClass::BgKeyword(keyword) => s.background(keyword.to_color()),
+            TwClass::TextColor(family, shade) => match family.shade(shade) {
+                Some(color) => s.color(color),
+                None => s,
+            },
+            TwClass::TextKeyword(keyword) => s.color(keyword.to_color()),
+            TwClass::PlaceholderColor(family, shade) => match family.shade(shade) {
+                Some(color) => s.class(PlaceholderTextClass, |s| s.color(color)),
+                None => s,
+            },
+            TwClass::PlaceholderKeyword(keyword) => {
+                s.class(PlaceholderTextClass, |s| s.color(keyword.to_color()))
+            }
+
+            // Typography
+            TwClass::TextSize(size) => s.font_size(size.scaled_px()),
+            TwClass::TextSizeLeading(size, leading) => s.font_size(size.scaled_px()).set(
+                floem::style::LineHeight,
+                Some(floem::text::LineHeightValue::Px(leading.scaled_px())),
+            ),
+            TwClass::FontWeight(weight) => s.font_weight(weight.to_weight()),
+            TwClass::FontStretch(stretch) => s.font_stretch(stretch.to_stretch()),
+            TwClass::Leading(leading) => s.line_height(leading.to_multiplier()),
+
+            // Layout
+            TwClass::Display(display) => match display {
+                Display::Flex => s.display(floem::style::Display::Flex),
+                Display::Block => s.display(floem::style::Display::Block),
+                Display::Grid => s.display(floem::style::Display::Grid),
+                Display::Hidden => s.display(floem::style::Display::None),
+            },
+            TwClass::FlexDirection(direction) => match direction {
+                FlexDirection::Row => s.flex_direction(direction::row(false)),
+                FlexDirection::Col => s.flex_direction(floem::style::FlexDirection::Column),
+                FlexDirection::RowReverse => s.flex_direction(direction::row(true)),
+                FlexDirection::ColReverse => {
+                    s.flex_direction(floem::style::FlexDirection::ColumnReverse)
+                }
+            },
+            TwClass::FlexWrap(wrap) => match wrap {
+                FlexWrap::Wrap => s.flex_wrap(floem::style::FlexWrap::Wrap),
+                FlexWrap::NoWrap => s.flex_wrap(floem::style::FlexWrap::NoWrap),
+                FlexWrap::WrapReverse => s.flex_wrap(floem::style::FlexWrap::WrapReverse),
+            },
+            TwClass::Position(position) => match position {
+                Position::Absolute => s.position(floem::style::Position::Absolute),
+                Position::Relative => s.position(floem::style::Position::Relative),
+                Position::Fixed => s.set(floem::style::IsFixed, true),
+            },
+            TwClass::Inset(edge, len) => match edge {
+                Edge::All => s.inset(len.to_unit()),
+                Edge::Top => s.inset_top(len.to_unit()),
+                Edge::Right => s.inset_right(len.to_unit()),
+                Edge::Bottom => s.inset_bottom(len.to_unit()),
+                Edge::Left => s.inset_left(len.to_unit()),
+            },
+            TwClass::NegInset(edge, len) => match edge {
+                Edge::All => s.inset(len.to_neg_px_pct()),
+                Edge::Top => s.inset_top(len.to_neg_px_pct()),
+                Edge::Right => s.inset_right(len.to_neg_px_pct()),
+                Edge::Bottom => s.inset_bottom(len.to_neg_px_pct()),
+                Edge::Left => s.inset_left(len.to_neg_px_pct()),
+            },
+            TwClass::TranslateX(len) => s.translate_x(len.to_px_pct()),
+            TwClass::TranslateY(len) => s.translate_y(len.to_px_pct()),
+            TwClass::NegTranslateX(len) => s.translate_x(len.to_neg_px_pct()),
+            TwClass::NegTranslateY(len) => s.translate_y(len.to_neg_px_pct()),
+            TwClass::ZIndex(z) => s.set(floem::style::ZIndex, z),
+            TwClass::Justify(justify) => s.justify_content(Some(match justify {
+                Justify::Start => floem::style::JustifyContent::FlexStart,
+                Justify::Center => floem::style::JustifyContent::Center,
+                Justify::End => floem::style::JustifyContent::FlexEnd,
+                Justify::Between => floem::style::JustifyContent::SpaceBetween,
+                Justify::Around => floem::style::JustifyContent::SpaceAround,
+                Justify::Evenly => floem::style::JustifyContent::SpaceEvenly,
+            })),
+            TwClass::Items(align) => s.align_items(align_value(align)),
+            TwClass::SelfAlign(align) => s.align_self(align_value(align)),
+            TwClass::VerticalAlign(align) => s.align_self(Some(match align {
+                VerticalAlign::Baseline => floem::style::AlignItems::Baseline,
+                VerticalAlign::Middle => floem::style::AlignItems::Center,
+                VerticalAlign::Top => floem::style::AlignItems::FlexStart,
+                VerticalAlign::Bottom => floem::style::AlignItems::FlexEnd,
+            })),
+
+            // Interactivity
+            TwClass::Cursor(cursor) => s.cursor(match cursor {
+                Cursor::Pointer => floem::style::CursorStyle::Pointer,
+                Cursor::Default => floem::style::CursorStyle::Default,
+                Cursor::Text => floem::style::CursorStyle::Text,
+                Cursor::Move => floem::style::CursorStyle::Move,
+                Cursor::Grab => floem::style::CursorStyle::Grab,
+                Cursor::Grabbing => floem::style::CursorStyle::Grabbing,
+            }),
+            TwClass::PointerEventsNone => s.set(
+                floem::style::PointerEventsProp,
+                Some(floem::style::PointerEvents::None),
+            ),
+            TwClass::PointerEventsAuto => s.set(
+                floem::style::PointerEventsProp,
+                Some(floem::style::PointerEvents::Auto),
+            ),
+            TwClass::Overflow(overflow) => s
+                .set(floem::style::OverflowX, overflow_value(overflow))
+                .set(floem::style::OverflowY, overflow_value(overflow)),
+            TwClass::OverflowX(overflow) => {
+                s.set(floem::style::OverflowX, overflow_value(overflow))
+            }
+            TwClass::OverflowY(overflow) => {
+                s.set(floem::style::OverflowY, overflow_value(overflow))
+            }
+
+            // Registered
+            TwClass::Utility(utility) => utility.apply(s),
+
+            // Variants
+            TwClass::ContrastMore(variant) => {
+                if contrast::is_high_contrast() {
+                    variant.class().apply(s)
+                } else {
+                    s
+                }
+            }
+            TwClass::Active(variant) => s.active(|s| variant.class().apply(s)),
+        }
+    }
+}
+
+/// Writes `prefix-fragment`, or just `prefix` when the fragment is empty
+fn write_keyword(f: &mut fmt::Formatter<'_>, prefix: &str, fragment: &str) -> fmt::Result {
+    if fragment.is_empty() {
+        f.write_str(prefix)
+    } else {
+        write!(f, "{prefix}-{fragment}")
+    }
+}
+
+impl fmt::Display for TwClass {
+    /// Writes the Tailwind class name for this utility
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TwClass::W(len) => write!(f, "w-{len}"),
+            TwClass::H(len) => write!(f, "h-{len}"),
+            TwClass::Size(len) => write!(f, "size-{len}"),
+            TwClass::MinW(len) => write!(f, "min-w-{len}"),
+            TwClass::MaxW(len) => write!(f, "max-w-{len}"),
+            TwClass::MaxWScreen(screen) => write!(f, "max-w-screen-{}", screen.name()),
+            TwClass::MinH(len) => write!(f, "min-h-{len}"),
+            TwClass::MaxH(len) => write!(f, "max-h-{len}"),
+            TwClass::P(len) => write!(f, "p-{len}"),
+            TwClass::Px(len) => write!(f, "px-{len}"),
+            TwClass::Py(len) => write!(f, "py-{len}"),
+            TwClass::Pt(len) => write!(f, "pt-{len}"),
+            TwClass::Pr(len) => write!(f, "pr-{len}"),
+            TwClass::Pb(len) => write!(f, "pb-{len}"),
+            TwClass::Pl(len) => write!(f, "pl-{len}"),
+            TwClass::Ps(len) => write!(f, "ps-{len}"),
+            TwClass::Pe(len) => write!(f, "pe-{len}"),
+            TwClass::M(len) => write!(f, "m-{len}"),
+            TwClass::Mx(len) => write!(f, "mx-{len}"),
+            TwClass::My(len) => write!(f, "my-{len}"),
+            TwClass::Mt(len) => write!(f, "mt-{len}"),
+            TwClass::Mr(len) => write!(f, "mr-{len}"),
+            TwClass::Mb(len) => write!(f, "mb-{len}"),
+            TwClass::Ml(len) => write!(f, "ml-{len}"),
+            TwClass::Ms(len) => write!(f, "ms-{len}"),
+            TwClass::Me(len) => write!(f, "me-{len}"),
+            TwClass::Gap(step) => write!(f, "gap-{}", step.name()),
+            TwClass::Rounded(radius) => write_keyword(f, "rounded", radius.name()),
+            TwClass::RoundedStart(radius) => write_keyword(f, "rounded-s", radius.name()),
+            TwClass::RoundedEnd(radius) => write_keyword(f, "rounded-e", radius.name()),
+            TwClass::BorderWidth(1) => f.write_str("border"),
+            TwClass::BorderWidth(width) => write!(f, "border-{width}"),
+            TwClass::BorderColor(family, shade) => write!(f, "border-{}-{shade}", family.name()),
+            TwClass::BorderKeyword(keyword) => write!(f, "border-{}", keyword.name()),
+            TwClass::OutlineNone => f.write_str("outline-none"),
+            TwClass::OutlineOffset(offset) => write!(f, "outline-offset-{offset}"),
+            TwClass::Shadow(shadow) => write_keyword(f, "shadow", shadow.name()),
+            TwClass::DropShadow(shadow) => write_keyword(f, "drop-shadow", shadow.name()),
+            TwClass::Elevation(level) => write!(f, "elevation-{level}"),
+            TwClass::Opacity(pct) => write!(f, "opacity-{pct}"),
+            TwClass::BgColor(family, shade) => write!(f, "bg-{}-{shade}", family.name()),
+            TwClass::BgKeyword(keyword) => write!(f, "bg-{}", keyword.name()),
+            TwClass::TextColor(family, shade) => write!(f, "text-{}-{shade}", family.name()),
+            TwClass::TextKeyword(keyword) => write!(f, "text-{}", keyword.name()),
+            TwClass::PlaceholderColor(family, shade) => {
+                write!(f, "placeholder-{}-{shade}", family.name())
+            }
+            TwClass::PlaceholderKeyword(keyword) => write!(f, "placeholder-{}", keyword.name()),
+            TwClass::TextSize(size) => write!(f, "text-{}", size.name()),
+            TwClass::TextSizeLeading(size, leading) => {
+                write!(f, "text-{}/{}", size.name(), leading.name())
+            }
+            TwClass::FontWeight(weight) => write!(f, "font-{}", weight.name()),
+            TwClass::FontStretch(stretch) => write!(f, "font-stretch-{}", stretch.name()),
+            TwClass::Leading(leading) => write!(f, "leading-{}", leading.name()),
+            TwClass::Display(display) => f.write_str(display.name()),
+            TwClass::FlexDirection(direction) => f.write_str(direction.name()),
+            TwClass::FlexWrap(wrap) => f.write_str(wrap.name()),
+            TwClass::Position(position) => f.write_str(position.name()),
+            TwClass::Inset(edge, len) => write!(f, "{}-{len}", edge.name()),
+            TwClass::NegInset(edge, len) => write!(f, "-{}-{len}", edge.name()),
+            TwClass::TranslateX(len) => write!(f, "translate-x-{len}"),
+            TwClass::TranslateY(len) => write!(f, "translate-y-{len}"),
+            TwClass::NegTranslateX(len) => write!(f, "-translate-x-{len}"),
+            TwClass::NegTranslateY(len) => write!(f, "-translate-y-{len}"),
+            TwClass::ZIndex(None) => f.write_str("z-auto"),
+            TwClass::ZIndex(Some(z)) if z < 0 => write!(f, "-z-{}", -z),
+            TwClass::ZIndex(Some(z)) => write!(f, "z-{z}"),
+            TwClass::Justify(justify) => write!(f, "justify-{}", justify.name()),
+            TwClass::Items(align) => write!(f, "items-{}", align.name()),
+            TwClass::SelfAlign(align) => write!(f, "self-{}", align.name()),
+            TwClass::VerticalAlign(align) => write!(f, "align-{}", align.name()),
+            TwClass::Cursor(cursor) => write!(f, "cursor-{}", cursor.name()),
+            TwClass::PointerEventsNone => f.write_str("pointer-events-none"),
+            TwClass::PointerEventsAuto => f.write_str("pointer-events-auto"),
+            TwClass::Overflow(overflow) => write!(f, "overflow-{}", overflow.name()),
+            TwClass::OverflowX(overflow) => write!(f, "overflow-x-{}", overflow.name()),
+            TwClass::OverflowY(overflow) => write!(f, "overflow-y-{}", overflow.name()),
+            TwClass::Utility(utility) => f.write_str(utility.name()),
+            TwClass::ContrastMore(variant) => write!(f, "contrast-more:{}", variant.class()),
+            TwClass::Active(variant) => write!(f, "active:{}", variant.class()),
+        }
+    }
+}
+
+/// Error returned when a class name doesn't match any utility
+///
+/// ```rust
+/// use floem_tailwind::class;
+///
+/// let error = class::parse_classes("p-4 bg-blu-500").unwrap_err();
+/// assert_eq!(error.class, "bg-blu-500");
+/// assert_eq!(error.position, 4);
+/// assert_eq!(error.suggestion.as_deref(), Some("bg-blue-500"));
+/// assert_eq!(
+///     error.to_string(),
+///     "unknown utility class `bg-blu-500`, did you mean `bg-blue-500`?"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseClassError {
+    /// The class name that failed to parse
+    pub class: String,
+    /// The byte offset of the class in the parsed string; 0 when parsing a
+    /// single class
+    pub position: usize,
+    /// The closest utility name, if one is a few edits away
+    pub suggestion: Option<String>,
+}
+
+impl ParseClassError {
+    /// The error for an unknown `class` at `position`, with the closest
+    /// registered utility as the suggestion
+    pub(crate) fn unknown(class: &str, position: usize) -> Self {
+        ParseClassError {
+            class: class.to_string(),
+            position,
+            suggestion: suggest(class),
+        }
+    }
+}
+
+impl fmt::Display for ParseClassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown utility class `{}`", self.class)?;
+        match &self.suggestion {
+            Some(suggestion) => write!(f, ", did you mean `{suggestion}`?"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseClassError {}
+
+/// Parses `family-shade` (e.g. `blue-500`) into a palette color
+pub(crate) fn parse_palette(value: &str) -> Option<(Family, u16)> {
+    let (family, shade) = value.rsplit_once('-')?;
+    let family = Family::from_name(family)?;
+    let shade: u16 = shade.parse().ok()?;
+    family.shade(shade).map(|_| (family, shade))
+}
+
+/// Parses a keyword utility that has an empty default (`rounded`, `rounded-lg`)
+fn parse_keyword<T>(class: &str, prefix: &str, from_name: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let rest = class.strip_prefix(prefix)?;
+    if rest.is_empty() {
+        return from_name("");
+    }
+    from_name(rest.strip_prefix('-')?)
+}
+
+/// Splits `prefix-value` utilities, trying multi-word prefixes first
+pub(crate) fn split_prefix(class: &str) -> Option<(&str, &str)> {
+    [
+        "pointer-events",
+        "outline-offset",
+        "overflow-x",
+        "overflow-y",
+        "translate-x",
+        "translate-y",
+        "min-w",
+        "max-w",
+    ]
+    .into_iter()
+    .find_map(|prefix| {
+        class
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .map(|value| (prefix, value))
+    })
+    .or_else(|| class.split_once('-'))
+}
+
+/// Parses a single class name into a built-in utility
+pub(crate) fn parse_class(class: &str) -> Option<TwClass> {
+    // Standalone keywords
+    if let Some(display) = Display::from_name(class) {
+        return Some(TwClass::Display(display));
+    }
+    if let Some(direction) = FlexDirection::from_name(class) {
+        return Some(TwClass::FlexDirection(direction));
+    }
+    if let Some(wrap) = FlexWrap::from_name(class) {
+        return Some(TwClass::FlexWrap(wrap));
+    }
+    if let Some(position) = Position::from_name(class) {
+        return Some(TwClass::Position(position));
+    }
+    match class {
+        "border" => return Some(TwClass::BorderWidth(1)),
+        "outline-none" => return Some(TwClass::OutlineNone),
+        "pointer-events-none" => return Some(TwClass::PointerEventsNone),
+        "pointer-events-auto" => return Some(TwClass::PointerEventsAuto),
+        _ => {}
+    }
+    if let Some(radius) = parse_keyword(class, "rounded", Radius::from_name) {
+        return Some(TwClass::Rounded(radius));
+    }
+    if let Some(radius) = parse_keyword(class, "rounded-s", Radius::from_name) {
+        return Some(TwClass::RoundedStart(radius));
+    }
+    if let Some(radius) = parse_keyword(class, "rounded-e", Radius::from_name) {
+        return Some(TwClass::RoundedEnd(radius));
+    }
+    if let Some(shadow) = parse_keyword(class, "shadow", Shadow::from_name) {
+        return Some(TwClass::Shadow(shadow));
+    }
+    if let Some(shadow) = parse_keyword(class, "drop-shadow", DropShadow::from_name) {
+        return Some(TwClass::DropShadow(shadow));
+    }
+
+    // Negative values
+    if let Some(rest) = class.strip_prefix('-') {
+        let (prefix, value) = split_prefix(rest)?;
+        return match prefix {
+            "translate-x" => Length::from_name(value).map(TwClass::NegTranslateX),
+            "translate-y" => Length::from_name(value).map(TwClass::NegTranslateY),
+            "z" => value
+                .parse::<u16>()
+                .ok()
+                .filter(|z| *z > 0)
+                .map(|z| TwClass::ZIndex(Some(-i32::from(z)))),
+            "inset" | "top" | "right" | "bottom" | "left" => {
+                let edge = Edge::from_name(prefix)?;
+                Length::from_name(value)
+                    .filter(|len| *len != Length::Auto)
+                    .map(|len| TwClass::NegInset(edge, len))
+            }
+            _ => None,
+        };
+    }
+
+    let (prefix, value) = split_prefix(class)?;
+    let length = || Length::from_name(value);
+    let step = || Spacing::from_name(value);
+    // Padding can't be `auto`, and named sizes are for widths
+    let padding = || {
+        length().filter(|len| {
+            matches!(
+                len,
+                Length::Spacing(_) | Length::Fraction(..) | Length::Full
+            )
+        })
+    };
+    match prefix {
+        "w" => length().map(TwClass::W),
+        "h" => length().map(TwClass::H),
+        "size" => length().map(TwClass::Size),
+        "min-w" => length().map(TwClass::MinW),
+        "max-w" => length().map(TwClass::MaxW).or_else(|| {
+            let screen = value.strip_prefix("screen-")?;
+            Breakpoint::from_name(screen).map(TwClass::MaxWScreen)
+        }),
+        "min-h" => length().map(TwClass::MinH),
+        "max-h" => length().map(TwClass::MaxH),
+        "p" => padding().map(TwClass::P),
+        "px" => padding().map(TwClass::Px),
+        "py" => padding().map(TwClass::Py),
+        "pt" => padding().map(TwClass::Pt),
+        "pr" => padding().map(TwClass::Pr),
+        "pb" => padding().map(TwClass::Pb),
+        "pl" => padding().map(TwClass::Pl),
+        "ps" => padding().map(TwClass::Ps),
+        "pe" => padding().map(TwClass::Pe),
+        "m" => length().map(TwClass::M),
+        "mx" => length().map(TwClass::Mx),
+        "my" => length().map(TwClass::My),
+        "mt" => length().map(TwClass::Mt),
+        "mr" => length().map(TwClass::Mr),
+        "mb" => length().map(TwClass::Mb),
+        "ml" => length().map(TwClass::Ml),
+        "ms" => length().map(TwClass::Ms),
+        "me" => length().map(TwClass::Me),
+        "gap" => step().map(TwClass::Gap),
+        "border" => value
+            .parse()
+            .ok()
+            .map(TwClass::BorderWidth)
+            .or_else(|| ColorKeyword::from_name(value).map(TwClass::BorderKeyword))
+            .or_else(|| parse_palette(value).map(|(f, s)| TwClass::BorderColor(f, s))),
+        "elevation" => value
+            .parse()
+            .ok()
+            .filter(|level| *level <= 5)
+            .map(TwClass::Elevation),
+        "outline-offset" => value
+            .parse()
+            .ok()
+            .filter(|offset| [0, 1, 2, 4, 8].contains(offset))
+            .map(TwClass::OutlineOffset),
+        "opacity" => value
+            .parse()
+            .ok()
+            .filter(|pct| *pct <= 100)
+            .map(TwClass::Opacity),
+        "bg" => ColorKeyword::from_name(value)
+            .map(TwClass::BgKeyword)
+            .or_else(|| parse_palette(value).map(|(f, s)| TwClass::BgColor(f, s))),
+        "text" => TextSize::from_name(value)
+            .map(TwClass::TextSize)
+            .or_else(|| {
+                let (size, leading) = value.split_once('/')?;
+                Some(TwClass::TextSizeLeading(
+                    TextSize::from_name(size)?,
+                    FixedLeading::from_name(leading)?,
+                ))
+            })
+            .or_else(|| ColorKeyword::from_name(value).map(TwClass::TextKeyword))
+            .or_else(|| parse_palette(value).map(|(f, s)| TwClass::TextColor(f, s))),
+        "placeholder" => ColorKeyword::from_name(value)
+            .map(TwClass::PlaceholderKeyword)
+            .or_else(|| parse_palette(value).map(|(f, s)| TwClass::PlaceholderColor(f, s))),
+        "font" => FontWeight::from_name(value)
+            .map(TwClass::FontWeight)
+            .or_else(|| {
+                let stretch = value.strip_prefix("stretch-")?;
+                FontStretch::from_name(stretch).map(TwClass::FontStretch)
+            }),
+        "leading" => Leading::from_name(value).map(TwClass::Leading),
+        "inset" | "top" | "right" | "bottom" | "left" => {
+            let edge = Edge::from_name(prefix)?;
+            length().map(|len| TwClass::Inset(edge, len))
+        }
+        "z" if value == "auto" => Some(TwClass::ZIndex(None)),
+        "z" => value
+            .parse::<u16>()
+            .ok()
+            .map(|z| TwClass::ZIndex(Some(z.into()))),
+        "translate-x" => length().map(TwClass::TranslateX),
+        "translate-y" => length().map(TwClass::TranslateY),
+        "justify" => Justify::from_name(value).map(TwClass::Justify),
+        "items" => Align::from_name(value)
+            .filter(|align| *align != Align::Auto)
+            .map(TwClass::Items),
+        "self" => Align::from_name(value).map(TwClass::SelfAlign),
+        "align" => VerticalAlign::from_name(value).map(TwClass::VerticalAlign),
+        "cursor" => Cursor::from_name(value).map(TwClass::Cursor),
+        "overflow" => Overflow::from_name(value).map(TwClass::Overflow),
+        "overflow-x" => Overflow::from_name(value).map(TwClass::OverflowX),
+        "overflow-y" => Overflow::from_name(value).map(TwClass::OverflowY),
+        _ => None,
+    }
+}
+
+impl FromStr for TwClass {
+    type Err = ParseClassError;
+
+    /// Parses a single Tailwind class name (e.g. `"px-4"`, `"bg-blue-500"`)
+    ///
+    /// Names that aren't built-in utilities are looked up in the registry.
+    /// `contrast-more:` in front of a utility applies it only in
+    /// high-contrast mode, and `active:` only while the view is pressed.
+    fn from_str(class: &str) -> Result<Self, Self::Err> {
+        parse_known(class).ok_or_else(|| ParseClassError::unknown(class, 0))
+    }
+}
+
+/// Parses a class name, built-in or registered, behind at most one variant
+pub(crate) fn parse_known(class: &str) -> Option<TwClass> {
+    if let Some(utility) = class.strip_prefix("contrast-more:") {
+        return parse_utility(utility)
+            .map(|utility| TwClass::ContrastMore(ContrastMore::new(utility)));
+    }
+    if let Some(utility) = class.strip_prefix("active:") {
+        return parse_utility(utility).map(|utility| TwClass::Active(Active::new(utility)));
+    }
+    parse_utility(class)
+}
+
+/// Parses a class name, built-in or registered, without a variant
+fn parse_utility(class: &str) -> Option<TwClass> {
+    lookup(class)
+        .or_else(|| parse_class(class))
+        .or_else(|| registry::lookup(class).map(TwClass::Utility))
+}
+
+/// Class names of the utility methods with their utilities, sorted by name
+static CLASS_TABLE: LazyLock<Box<[(&'static str, TwClass)]>> = LazyLock::new(|| {
+    let mut table: Vec<_> = crate::utilities()
+        .filter_map(|(name, _)| Some((name, parse_class(name)?)))
+        .collect();
+    table.sort_unstable_by_key(|&(name, _)| name);
+    table.dedup_by_key(|&mut (name, _)| name);
+    table.into()
+});
+
+/// Every class name of a utility method, like `"w-4"`, with its utility,
+/// sorted by name
+///
+/// This is a sorted slice searched with `binary_search`, not a perfect-hash
+/// table. The parser checks it before parsing a name's prefix and value, so
+/// tooling can reuse it for the names that have a method. Names the parser
+/// accepts without a method, like `m-md`, `opacity-37`, or `z-7`, aren't in
+/// it. Built on first use.
+pub fn class_table() -> &'static [(&'static str, TwClass)] {
+    &CLASS_TABLE
+}
+
+/// Looks up a class name in [`class_table`], without allocating
+///
+/// ```rust
+/// use floem_tailwind::class::{self, TwClass};
+///
+/// assert_eq!(class::lookup("bg-blue-500"), "bg-blue-500".parse::<TwClass>().ok());
+/// assert_eq!(class::lookup("opacity-37"), None);
+/// assert!("opacity-37".parse::<TwClass>().is_ok());
+/// ```
+pub fn lookup(class: &str) -> Option<TwClass> {
+    let table = class_table();
+    let i = table.binary_search_by_key(&class, |&(name, _)| name).ok()?;
+    Some(table[i].1)
+}
+
+/// The classes of a class string, with their byte offsets
+pub(crate) fn tokens(classes: &str) -> impl Iterator<Item = (usize, &str)> {
+    classes
+        .split_whitespace()
+        .map(move |class| (class.as_ptr() as usize - classes.as_ptr() as usize, class))
+}
+
+/// Parses a whitespace-separated class string into utilities
+pub fn parse_classes(classes: &str) -> Result<Vec<TwClass>, ParseClassError> {
+    tokens(classes)
+        .map(|(position, class)| {
+            parse_known(class).ok_or_else(|| ParseClassError::unknown(class, position))
+        })
+        .collect()
+}
+
+/// Parses a whitespace-separated class string, skipping unknown classes
+pub fn parse_classes_lossy(classes: &str) -> Vec<TwClass> {
+    classes.split_whitespace().filter_map(parse_known).collect()
+}
+
+/// The registered utility closest to an unknown class, by edit distance
+///
+/// Only names within a third of the class's length count, so unrelated
+/// names aren't suggested. The variant prefix is kept as written.
+fn suggest(class: &str) -> Option<String> {
+    let (variant, utility) = ["contrast-more:", "active:"]
+        .into_iter()
+        .find_map(|variant| Some((variant, class.strip_prefix(variant)?)))
+        .unwrap_or(("", class));
+    let max = (utility.chars().count() / 3).max(1);
+    registry::all()
+        .into_iter()
+        .map(Utility::name)
+        .filter(|name| name.len().abs_diff(utility.len()) <= max)
+        .map(|name| (edit_distance(utility, name), name))
+        .filter(|&(distance, _)| distance <= max)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, name)| format!("{variant}{name}"))
+}
+
+/// The Levenshtein distance between two strings, in chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_classes() {
+        assert_eq!(
+            parse_classes("p-4 w-1/2 bg-blue-500 text-sm text-white -translate-x-1/2").unwrap(),
+            vec![
+                TwClass::P(Spacing::S4.into()),
+                TwClass::W(Length::Fraction(1, 2)),
+                TwClass::BgColor(Family::Blue, 500),
+                TwClass::TextSize(TextSize::Sm),
+                TwClass::TextKeyword(ColorKeyword::White),
+                TwClass::NegTranslateX(Length::Fraction(1, 2)),
+            ]
+        );
+        assert!("bg-blue-550".parse::<TwClass>().is_err());
+        assert!("items-auto".parse::<TwClass>().is_err());
+        assert!("p-auto".parse::<TwClass>().is_err());
+        assert!("px-md".parse::<TwClass>().is_err());
+        assert_eq!(
+            "-top-2".parse::<TwClass>(),
+            Ok(TwClass::NegInset(Edge::Top, Spacing::S2.into()))
+        );
+        assert!("-left-auto".parse::<TwClass>().is_err());
+        assert_eq!("-z-10".parse::<TwClass>(), Ok(TwClass::ZIndex(Some(-10))));
+        assert!("-z-0".parse::<TwClass>().is_err());
+        assert_eq!(
+            "contrast-more:border-2".parse::<TwClass>(),
+            Ok(TwClass::ContrastMore(ContrastMore::new(
+                TwClass::BorderWidth(2)
+            )))
+        );
+        assert!("contrast-more:bogus".parse::<TwClass>().is_err());
+        let active = "active:bg-rose-500".parse::<TwClass>();
+        assert_eq!(
+            active,
+            Ok(TwClass::Active(Active::new(TwClass::BgColor(Family::Rose, 500))))
+        );
+        assert_eq!(active.unwrap().to_string(), "active:bg-rose-500");
+        assert!("active:active:bg-rose-500".parse::<TwClass>().is_err());
+        assert!("contrast-more:active:border-2".parse::<TwClass>().is_err());
+    }
+
+    #[test]
+    fn test_class_table() {
+        let table = class_table();
+        assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for &(name, class) in table {
+            assert_eq!(parse_class(name), Some(class), "{name}");
+        }
+        assert_eq!(lookup("p-4"), Some(TwClass::P(Spacing::S4.into())));
+        assert_eq!(lookup("p-nope"), None);
+    }
+
+    #[test]
+    fn test_parse_error_suggestion() {
+        let error = parse_classes("flex  itms-center gap-2").unwrap_err();
+        assert_eq!(error.position, 6);
+        assert_eq!(error.suggestion.as_deref(), Some("items-center"));
+        let error = "contrast-more:bordr-2".parse::<TwClass>().unwrap_err();
+        assert_eq!(error.suggestion.as_deref(), Some("contrast-more:border-2"));
+        assert_eq!("bogus".parse::<TwClass>().unwrap_err().suggestion, None);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "p-4"), 3);
+    }
+
+    #[test]
+    fn test_class_name_round_trip() {
+        for class in [
+            "w-0.5",
+            "max-w-7xl",
+            "max-w-screen-2xl",
+            "min-h-md",
+            "mx-auto",
+            "ps-4",
+            "me-auto",
+            "px-1/2",
+            "rounded",
+            "rounded-lg",
+            "rounded-s",
+            "rounded-e-full",
+            "border",
+            "border-2",
+            "border-gray-300",
+            "placeholder-gray-400",
+            "placeholder-transparent",
+            "outline-offset-4",
+            "shadow",
+            "shadow-none",
+            "drop-shadow",
+            "drop-shadow-2xl",
+            "opacity-50",
+            "flex-col-reverse",
+            "top-1/2",
+            "inset-0",
+            "self-auto",
+            "align-middle",
+            "text-sm/6",
+            "font-stretch-semi-condensed",
+            "overflow-x-auto",
+            "pointer-events-none",
+            "z-auto",
+            "-z-10",
+            "contrast-more:text-gray-900",
+        ] {
+            let parsed: TwClass = class.parse().unwrap();
+            assert_eq!(parsed.to_string(), class);
+        }
+    }
+}
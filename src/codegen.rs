@@ -0,0 +1,300 @@
+//! Build-script code generation from a theme config file
+//!
+//! Generates constant modules and a `ThemeExt` extension trait for the values
+//! in a [`TailwindConfig`], so custom design tokens get the same
+//! `bg_brand_500()` / `p_18()` methods as the built-in scale.
+//!
+//! In `build.rs`:
+//!
+//! ```rust,no_run
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("theme.rs");
+//! floem_tailwind::codegen::generate("tailwind.toml", out).unwrap();
+//! ```
+//!
+//! And in the crate:
+//!
+//! ```rust,ignore
+//! mod theme {
+//!     include!(concat!(env!("OUT_DIR"), "/theme.rs"));
+//! }
+//! use theme::ThemeExt;
+//!
+//! let style = Style::new().p_18().bg_brand_500();
+//! ```
+//!
+//! Entries that reuse a built-in name (e.g. spacing `"4"` or color
+//! `blue-500`) only get a constant; their methods would be ambiguous with `TailwindExt`. The
+//! methods scale the constants like the built-in ones, with the
+//! [`rem`](crate::rem) root, [`density`](crate::density), and
+//! [`dpi`](crate::dpi) snapping.
+
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use crate::Spacing;
+use crate::class::{ColorKeyword, Radius, TextSize, parse_palette};
+use crate::config::{ColorValue, TailwindConfig, parse_config_color};
+
+/// Spacing utilities generated for each custom spacing value: (method prefix, `Style` method)
+const SPACING_METHODS: &[(&str, &str)] = &[
+    ("w", "width"),
+    ("h", "height"),
+    ("min_w", "min_width"),
+    ("max_w", "max_width"),
+    ("min_h", "min_height"),
+    ("max_h", "max_height"),
+    ("p", "padding"),
+    ("px", "padding_horiz"),
+    ("py", "padding_vert"),
+    ("pt", "padding_top"),
+    ("pr", "padding_right"),
+    ("pb", "padding_bottom"),
+    ("pl", "padding_left"),
+    ("m", "margin"),
+    ("mx", "margin_horiz"),
+    ("my", "margin_vert"),
+    ("mt", "margin_top"),
+    ("mr", "margin_right"),
+    ("mb", "margin_bottom"),
+    ("ml", "margin_left"),
+    ("gap", "gap"),
+];
+
+/// Color utilities generated for each custom color: (method prefix, `Style` method)
+const COLOR_METHODS: &[(&str, &str)] = &[
+    ("bg", "background"),
+    ("text", "color"),
+    ("border", "border_color"),
+];
+
+/// Turns a class-name fragment into a snake_case identifier part
+/// (`"4.5"` → `"4p5"`, so it doesn't read as the fraction `4_5`)
+fn ident(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '.' => 'p',
+            c if c.is_ascii_alphanumeric() => c.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Turns a class-name fragment into a module name, which can't start with a digit
+fn module_ident(name: &str) -> String {
+    let name = ident(name);
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("c_{name}")
+    } else {
+        name
+    }
+}
+
+/// Turns a class-name fragment into a SCREAMING_CASE identifier part
+fn const_ident(name: &str) -> String {
+    ident(name).to_ascii_uppercase()
+}
+
+/// Formats a color as a `const` expression
+fn color_expr(value: &str) -> Option<String> {
+    let rgba = parse_config_color(value)?.to_rgba8();
+    Some(format!(
+        "peniko::Color::from_rgba8({}, {}, {}, {})",
+        rgba.r, rgba.g, rgba.b, rgba.a
+    ))
+}
+
+/// Generates the theme module source for a config
+///
+/// Colors that fail to parse are skipped.
+pub fn generate_source(config: &TailwindConfig) -> String {
+    let mut consts = String::new();
+    let mut decls = String::new();
+    let mut impls = String::new();
+    let mut method = |name: String, doc: String, body: String| {
+        let _ = writeln!(decls, "    /// {doc}\n    fn {name}(self) -> Self;");
+        let _ = writeln!(
+            impls,
+            "    fn {name}(self) -> Self {{\n        {body}\n    }}"
+        );
+    };
+
+    consts.push_str("/// Custom spacing scale (in pixels)\npub mod spacing {\n");
+    for (name, px) in &config.spacing {
+        let konst = format!("SPACING_{}", const_ident(name));
+        let _ = writeln!(consts, "    pub const {konst}: f64 = {px:?};");
+        if Spacing::from_name(name).is_some() {
+            continue;
+        }
+        for (prefix, style_method) in SPACING_METHODS {
+            method(
+                format!("{prefix}_{}", ident(name)),
+                format!("{}-{name} ({px}px)", prefix.replace('_', "-")),
+                format!("self.{style_method}(floem_tailwind::rem::scale(spacing::{konst}))"),
+            );
+        }
+    }
+    consts.push_str("}\n\n");
+
+    consts.push_str("/// Custom border radius scale (in pixels)\npub mod radius {\n");
+    for (name, px) in &config.border_radius {
+        let builtin = if name == "DEFAULT" { "" } else { name.as_str() };
+        let konst = match builtin {
+            "" => "ROUNDED".to_string(),
+            name => format!("ROUNDED_{}", const_ident(name)),
+        };
+        let _ = writeln!(consts, "    pub const {konst}: f64 = {px:?};");
+        if Radius::from_name(builtin).is_some() {
+            continue;
+        }
+        method(
+            format!("rounded_{}", ident(name)),
+            format!("rounded-{name} ({px}px)"),
+            format!("self.border_radius(floem_tailwind::radius::scale(radius::{konst}))"),
+        );
+    }
+    consts.push_str("}\n\n");
+
+    consts.push_str("/// Custom font size scale (in pixels)\npub mod font_size {\n");
+    for (name, px) in &config.font_size {
+        let konst = format!("TEXT_{}", const_ident(name));
+        let _ = writeln!(consts, "    pub const {konst}: f32 = {px:?};");
+        if TextSize::from_name(name).is_some() {
+            continue;
+        }
+        method(
+            format!("text_{}", ident(name)),
+            format!("text-{name} ({px}px)"),
+            format!("self.font_size(floem_tailwind::rem::scale_f32(font_size::{konst}))"),
+        );
+    }
+    consts.push_str("}\n\n");
+
+    consts.push_str("/// Custom breakpoints (minimum window width in pixels)\npub mod screen {\n");
+    for (name, px) in &config.screens {
+        let _ = writeln!(consts, "    pub const {}: f64 = {px:?};", const_ident(name));
+    }
+    consts.push_str("}\n\n");
+
+    consts.push_str("/// Custom colors\npub mod colors {\n");
+    let mut color = |class: String, path: String| {
+        if ColorKeyword::from_name(&class).is_some() || parse_palette(&class).is_some() {
+            return;
+        }
+        for (prefix, style_method) in COLOR_METHODS {
+            method(
+                format!("{prefix}_{}", ident(&class)),
+                format!("{prefix}-{class}"),
+                format!("self.{style_method}(colors::{path})"),
+            );
+        }
+    };
+    for (name, value) in &config.colors {
+        match value {
+            ColorValue::Single(value) => {
+                let Some(expr) = color_expr(value) else {
+                    continue;
+                };
+                let konst = const_ident(name);
+                let _ = writeln!(consts, "    pub const {konst}: peniko::Color = {expr};");
+                color(name.clone(), konst);
+            }
+            ColorValue::Shades(shades) => {
+                let module = module_ident(name);
+                let _ = writeln!(consts, "    pub mod {module} {{");
+                for (shade, value) in shades {
+                    let Some(expr) = color_expr(value) else {
+                        continue;
+                    };
+                    let (konst, class) = match shade.as_str() {
+                        "DEFAULT" => ("DEFAULT".to_string(), name.clone()),
+                        shade => (
+                            format!("C{}", const_ident(shade)),
+                            format!("{name}-{shade}"),
+                        ),
+                    };
+                    let _ = writeln!(consts, "        pub const {konst}: peniko::Color = {expr};");
+                    color(class, format!("{module}::{konst}"));
+                }
+                consts.push_str("    }\n");
+            }
+        }
+    }
+    consts.push_str("}\n");
+
+    format!(
+        "// @generated by floem_tailwind::codegen. Do not edit.\n\n\
+         {consts}\n\
+         /// Utility methods for the custom theme values\n\
+         pub trait ThemeExt: Sized {{\n{decls}}}\n\n\
+         impl ThemeExt for floem::style::Style {{\n{impls}}}\n"
+    )
+}
+
+/// Reads a TOML (or `.json`) config file and writes the generated theme module
+///
+/// Also prints `cargo:rerun-if-changed` for the config file.
+pub fn generate(config: impl AsRef<Path>, out: impl AsRef<Path>) -> io::Result<()> {
+    let config = config.as_ref();
+    println!("cargo:rerun-if-changed={}", config.display());
+
+    let source = std::fs::read_to_string(config)?;
+    let parsed = if config.extension().is_some_and(|ext| ext == "json") {
+        TailwindConfig::from_json(&source).map_err(io::Error::other)?
+    } else {
+        TailwindConfig::from_toml(&source).map_err(io::Error::other)?
+    };
+    std::fs::write(out, generate_source(&parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_source() {
+        let mut config = TailwindConfig::default();
+        config.spacing.insert("4".to_string(), 20.0);
+        config.spacing.insert("18".to_string(), 72.0);
+        config.colors.insert(
+            "brand".to_string(),
+            ColorValue::Shades([("500".to_string(), "#6366f1".to_string())].into()),
+        );
+
+        let source = generate_source(&config);
+        assert!(source.contains("pub const SPACING_4: f64 = 20.0;"));
+        assert!(source.contains("pub const SPACING_18: f64 = 72.0;"));
+        assert!(
+            source.contains(
+                "fn p_18(self) -> Self {\n        \
+                 self.padding(floem_tailwind::rem::scale(spacing::SPACING_18))"
+            )
+        );
+        assert!(!source.contains("fn p_4(self)"));
+        assert!(source.contains(
+            "pub const C500: peniko::Color = peniko::Color::from_rgba8(99, 102, 241, 255);"
+        ));
+        assert!(source.contains("fn bg_brand_500(self) -> Self;"));
+    }
+
+    #[test]
+    fn test_generate_source_skips_builtin_colors() {
+        let mut config = TailwindConfig::default();
+        config.spacing.insert("4.5".to_string(), 18.0);
+        config
+            .colors
+            .insert("white".to_string(), ColorValue::Single("#fafafa".to_string()));
+        config.colors.insert(
+            "blue".to_string(),
+            ColorValue::Shades([("500".to_string(), "#2563eb".to_string())].into()),
+        );
+
+        let source = generate_source(&config);
+        assert!(source.contains("pub const SPACING_4P5: f64 = 18.0;"));
+        assert!(source.contains("fn p_4p5(self) -> Self;"));
+        assert!(source.contains("pub const WHITE: peniko::Color"));
+        assert!(source.contains("pub const C500: peniko::Color"));
+        assert!(!source.contains("fn bg_white(self)"));
+        assert!(!source.contains("fn text_blue_500(self)"));
+    }
+}
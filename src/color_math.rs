@@ -0,0 +1,94 @@
+//! Perceptual color adjustments
+//!
+//! Derives hover, pressed, and muted variants from a color instead of
+//! hardcoding them. Lightness and saturation are adjusted in OKLCH and mixing
+//! happens in Oklab, so equal amounts look like equal steps across hues;
+//! results outside sRGB lose chroma until they fit.
+//!
+//! ```rust
+//! use floem_tailwind::color_math::{darken, mix};
+//! use floem_tailwind::colors;
+//!
+//! let hover = darken(colors::blue::C500, 0.05);
+//! let pressed = darken(colors::blue::C500, 0.1);
+//! let tint = mix(colors::blue::C500, colors::WHITE, 0.8);
+//! ```
+//!
+//! Alpha is kept from the input color (interpolated by [`mix`]).
+
+use peniko::Color;
+use peniko::color::{Oklab, Oklch};
+
+use crate::colors::from_oklch;
+
+/// Applies `f` to the OKLCH lightness, chroma, and hue of `color`
+fn map_oklch(color: Color, f: impl FnOnce([f32; 3]) -> [f32; 3]) -> Color {
+    let [l, c, h, alpha] = color.convert::<Oklch>().components;
+    let [l, c, h] = f([l, c, h]);
+    from_oklch([l.clamp(0.0, 1.0), c.max(0.0), h], alpha)
+}
+
+/// Raises the OKLCH lightness by `amount` (0–1)
+pub fn lighten(color: Color, amount: f32) -> Color {
+    map_oklch(color, |[l, c, h]| [l + amount, c, h])
+}
+
+/// Lowers the OKLCH lightness by `amount` (0–1)
+pub fn darken(color: Color, amount: f32) -> Color {
+    lighten(color, -amount)
+}
+
+/// Scales the chroma up by `amount` (`0.2` is 20% more saturated)
+pub fn saturate(color: Color, amount: f32) -> Color {
+    map_oklch(color, |[l, c, h]| [l, c * (1.0 + amount), h])
+}
+
+/// Scales the chroma down by `amount` (`1.0` is fully gray)
+pub fn desaturate(color: Color, amount: f32) -> Color {
+    saturate(color, -amount.min(1.0))
+}
+
+/// Replaces the hue, in degrees
+pub fn with_hue(color: Color, hue: f32) -> Color {
+    map_oklch(color, |[l, c, _]| [l, c, hue])
+}
+
+/// Mixes `a` toward `b` in Oklab: `t = 0` is `a`, `t = 1` is `b`
+pub fn mix(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    a.convert::<Oklab>()
+        .lerp_rect(b.convert::<Oklab>(), t)
+        .convert()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors;
+
+    fn lightness(color: Color) -> f32 {
+        color.convert::<Oklch>().components[0]
+    }
+
+    #[test]
+    fn test_adjustments() {
+        let base = colors::blue::C500;
+        assert!((lightness(lighten(base, 0.1)) - lightness(base) - 0.1).abs() < 0.01);
+        assert!(lightness(darken(base, 0.1)) < lightness(base));
+        assert_eq!(
+            lighten(colors::WHITE, 0.2).to_rgba8(),
+            colors::WHITE.to_rgba8()
+        );
+
+        let gray = desaturate(base, 1.0).to_rgba8();
+        assert!(gray.r.abs_diff(gray.g) <= 1 && gray.g.abs_diff(gray.b) <= 1);
+
+        assert_eq!(mix(base, colors::WHITE, 0.0).to_rgba8(), base.to_rgba8());
+        assert_eq!(
+            mix(base, colors::BLACK, 1.0).to_rgba8(),
+            colors::BLACK.to_rgba8()
+        );
+        let half = mix(colors::BLACK, colors::WHITE, 0.5);
+        assert!((lightness(half) - 0.5).abs() < 0.01);
+    }
+}
@@ -2,8 +2,18 @@
 //!
 //! This module provides Tailwind's default color palette as `peniko::Color` values.
 //! Each color family includes shades from 50 (lightest) to 950 (darkest).
+//! [`generate_palette`] derives a ramp like these from a single brand color,
+//! [`shade`] interpolates between the stops, and [`one_darker`] and
+//! [`one_lighter`] step to a neighboring stop, e.g. for hover and pressed
+//! states of a palette color. [`v4_shade`] and [`to_v4`] give Tailwind v4's
+//! values of the palette.
+
+use std::sync::LazyLock;
 
 use peniko::Color;
+use peniko::color::{AlphaColor, Oklch, Srgb};
+
+use crate::class::ColorKeyword;
 
 /// Macro to define a color palette
 macro_rules! define_palette {
@@ -351,3 +361,842 @@ define_palette!(rose,
 pub const WHITE: Color = Color::from_rgb8(255, 255, 255);
 pub const BLACK: Color = Color::from_rgb8(0, 0, 0);
 pub const TRANSPARENT: Color = Color::from_rgba8(0, 0, 0, 0);
+
+/// A palette color with its alpha replaced (0.0 - 1.0), for overlays and shadows
+///
+/// The same as `color.with_alpha(alpha)`, which palette constants get from
+/// `peniko::Color`:
+///
+/// ```rust
+/// use floem_tailwind::colors::{self, alpha};
+///
+/// let scrim = alpha(colors::slate::C900, 0.5);
+/// assert_eq!(scrim, colors::slate::C900.with_alpha(0.5));
+/// ```
+pub const fn alpha(color: Color, alpha: f32) -> Color {
+    color.with_alpha(alpha)
+}
+
+/// Shade stops available in every palette family, lightest to darkest
+pub const SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// Macro to look up a shade constant in a palette module
+macro_rules! palette_lookup {
+    ($palette:ident, $shade:expr) => {
+        match $shade {
+            50 => Some($palette::C50),
+            100 => Some($palette::C100),
+            200 => Some($palette::C200),
+            300 => Some($palette::C300),
+            400 => Some($palette::C400),
+            500 => Some($palette::C500),
+            600 => Some($palette::C600),
+            700 => Some($palette::C700),
+            800 => Some($palette::C800),
+            900 => Some($palette::C900),
+            950 => Some($palette::C950),
+            _ => None,
+        }
+    };
+}
+
+/// A palette color family, for picking colors at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Family {
+    Slate,
+    Gray,
+    Zinc,
+    Neutral,
+    Stone,
+    Red,
+    Orange,
+    Amber,
+    Yellow,
+    Lime,
+    Green,
+    Emerald,
+    Teal,
+    Cyan,
+    Sky,
+    Blue,
+    Indigo,
+    Violet,
+    Purple,
+    Fuchsia,
+    Pink,
+    Rose,
+}
+
+impl Family {
+    /// Every family, in Tailwind's palette order
+    pub const ALL: [Family; 22] = [
+        Family::Slate,
+        Family::Gray,
+        Family::Zinc,
+        Family::Neutral,
+        Family::Stone,
+        Family::Red,
+        Family::Orange,
+        Family::Amber,
+        Family::Yellow,
+        Family::Lime,
+        Family::Green,
+        Family::Emerald,
+        Family::Teal,
+        Family::Cyan,
+        Family::Sky,
+        Family::Blue,
+        Family::Indigo,
+        Family::Violet,
+        Family::Purple,
+        Family::Fuchsia,
+        Family::Pink,
+        Family::Rose,
+    ];
+
+    /// The family's class-name fragment (e.g. `"blue"`)
+    pub const fn name(self) -> &'static str {
+        match self {
+            Family::Slate => "slate",
+            Family::Gray => "gray",
+            Family::Zinc => "zinc",
+            Family::Neutral => "neutral",
+            Family::Stone => "stone",
+            Family::Red => "red",
+            Family::Orange => "orange",
+            Family::Amber => "amber",
+            Family::Yellow => "yellow",
+            Family::Lime => "lime",
+            Family::Green => "green",
+            Family::Emerald => "emerald",
+            Family::Teal => "teal",
+            Family::Cyan => "cyan",
+            Family::Sky => "sky",
+            Family::Blue => "blue",
+            Family::Indigo => "indigo",
+            Family::Violet => "violet",
+            Family::Purple => "purple",
+            Family::Fuchsia => "fuchsia",
+            Family::Pink => "pink",
+            Family::Rose => "rose",
+        }
+    }
+
+    /// Looks up a family by its class-name fragment
+    pub fn from_name(name: &str) -> Option<Family> {
+        Family::ALL.into_iter().find(|family| family.name() == name)
+    }
+
+    /// Returns the color for a shade stop (50, 100, ... 950), if it exists
+    pub const fn shade(self, shade: u16) -> Option<Color> {
+        match self {
+            Family::Slate => palette_lookup!(slate, shade),
+            Family::Gray => palette_lookup!(gray, shade),
+            Family::Zinc => palette_lookup!(zinc, shade),
+            Family::Neutral => palette_lookup!(neutral, shade),
+            Family::Stone => palette_lookup!(stone, shade),
+            Family::Red => palette_lookup!(red, shade),
+            Family::Orange => palette_lookup!(orange, shade),
+            Family::Amber => palette_lookup!(amber, shade),
+            Family::Yellow => palette_lookup!(yellow, shade),
+            Family::Lime => palette_lookup!(lime, shade),
+            Family::Green => palette_lookup!(green, shade),
+            Family::Emerald => palette_lookup!(emerald, shade),
+            Family::Teal => palette_lookup!(teal, shade),
+            Family::Cyan => palette_lookup!(cyan, shade),
+            Family::Sky => palette_lookup!(sky, shade),
+            Family::Blue => palette_lookup!(blue, shade),
+            Family::Indigo => palette_lookup!(indigo, shade),
+            Family::Violet => palette_lookup!(violet, shade),
+            Family::Purple => palette_lookup!(purple, shade),
+            Family::Fuchsia => palette_lookup!(fuchsia, shade),
+            Family::Pink => palette_lookup!(pink, shade),
+            Family::Rose => palette_lookup!(rose, shade),
+        }
+    }
+}
+
+/// Formats a color as `#rrggbb`, or `#rrggbbaa` if it is translucent
+pub fn to_hex(color: Color) -> String {
+    let rgba = color.to_rgba8();
+    let hex = format!("#{:02x}{:02x}{:02x}", rgba.r, rgba.g, rgba.b);
+    match rgba.a {
+        255 => hex,
+        a => format!("{hex}{a:02x}"),
+    }
+}
+
+/// Formats a color as CSS `rgb(59 130 246)`, or `rgb(59 130 246 / 0.5)` if
+/// it is translucent
+pub fn to_css_rgb(color: Color) -> String {
+    let rgba = color.to_rgba8();
+    let rgb = format!("{} {} {}", rgba.r, rgba.g, rgba.b);
+    match rgba.a {
+        255 => format!("rgb({rgb})"),
+        _ => format!("rgb({rgb} / {})", color.components[3]),
+    }
+}
+
+/// The palette name of a color (`"blue-500"`, `"white"`), if it is exactly a
+/// palette or keyword color
+///
+/// ```rust
+/// use floem_tailwind::colors::{self, name_of, to_hex};
+///
+/// assert_eq!(name_of(colors::blue::C500).as_deref(), Some("blue-500"));
+/// assert_eq!(to_hex(colors::blue::C500), "#3b82f6");
+/// ```
+pub fn name_of(color: Color) -> Option<String> {
+    let rgba = color.to_rgba8();
+    if let Some(keyword) = ColorKeyword::ALL
+        .iter()
+        .find(|keyword| keyword.to_color().to_rgba8() == rgba)
+    {
+        return Some(keyword.name().to_string());
+    }
+    let (family, i) = palette_shade(color)?;
+    Some(format!("{}-{}", family.name(), SHADES[i]))
+}
+
+/// The family and [`SHADES`] index of an exact palette color
+pub(crate) fn palette_shade(color: Color) -> Option<(Family, usize)> {
+    let rgba = color.to_rgba8();
+    Family::ALL.into_iter().find_map(|family| {
+        let i = SHADES
+            .iter()
+            .position(|&shade| family.shade(shade).map(|c| c.to_rgba8()) == Some(rgba))?;
+        Some((family, i))
+    })
+}
+
+/// The next darker shade of a palette color (blue-500 → blue-600)
+///
+/// `None` for 950 and for colors that aren't palette shades.
+pub fn one_darker(color: Color) -> Option<Color> {
+    let (family, i) = palette_shade(color)?;
+    family.shade(*SHADES.get(i + 1)?)
+}
+
+/// The next lighter shade of a palette color (blue-500 → blue-400)
+///
+/// `None` for 50 and for colors that aren't palette shades.
+pub fn one_lighter(color: Color) -> Option<Color> {
+    let (family, i) = palette_shade(color)?;
+    family.shade(SHADES[i.checked_sub(1)?])
+}
+
+/// Resolves a color name (`"blue-500"`, `"white"`) or a CSS color string
+/// (`"#1e293b"`, `"rgb(30 41 59)"`)
+pub fn resolve(value: &str) -> Option<Color> {
+    ColorKeyword::from_name(value)
+        .map(ColorKeyword::to_color)
+        .or_else(|| crate::class::parse_palette(value).and_then(|(f, shade)| f.shade(shade)))
+        .or_else(|| crate::config::parse_config_color(value))
+}
+
+/// A color that (de)serializes as a Tailwind name or CSS color string
+///
+/// Deserializes anything [`resolve`] accepts, so config structs can hold
+/// `"blue-500"`, `"#1e293b"`, or `"white"`; serializes as the palette name
+/// when there is one and as hex otherwise. For a plain `Color` field use
+/// `#[serde(with = "floem_tailwind::colors::TwColor")]`:
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use floem_tailwind::colors::{self, TwColor};
+///
+/// #[derive(serde::Deserialize)]
+/// struct Theme {
+///     accent: TwColor,
+///     #[serde(with = "TwColor")]
+///     surface: peniko::Color,
+/// }
+///
+/// let theme: Theme =
+///     serde_json::from_str(r##"{ "accent": "blue-500", "surface": "#1e293b" }"##).unwrap();
+/// assert_eq!(theme.accent.0, colors::blue::C500);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwColor(pub Color);
+
+impl From<TwColor> for Color {
+    fn from(color: TwColor) -> Self {
+        color.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TwColor {
+    /// Serializes a `Color` field, for `#[serde(with = "TwColor")]`
+    pub fn serialize<S: serde::Serializer>(
+        color: &Color,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&TwColor(*color), serializer)
+    }
+
+    /// Deserializes a `Color` field, for `#[serde(with = "TwColor")]`
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Color, D::Error> {
+        <TwColor as serde::Deserialize>::deserialize(deserializer).map(Color::from)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TwColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = name_of(self.0).unwrap_or_else(|| to_hex(self.0));
+        serializer.serialize_str(&value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TwColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        resolve(&value)
+            .map(TwColor)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown color `{value}`")))
+    }
+}
+
+/// OKLCH lightness of each shade in Tailwind's v4 palette, roughly averaged
+/// over the chromatic families
+const LIGHTNESS: [f32; 11] = [
+    0.975, 0.94, 0.885, 0.81, 0.71, 0.625, 0.55, 0.485, 0.425, 0.38, 0.275,
+];
+
+/// Chroma of each shade relative to shade 500
+const CHROMA: [f32; 11] = [0.08, 0.18, 0.35, 0.6, 0.85, 1.0, 1.0, 0.9, 0.75, 0.6, 0.45];
+
+/// Index of shade 500 in [`SHADES`]
+const BASE: usize = 5;
+
+/// The sRGB color of OKLCH components, reducing chroma until it is in gamut
+pub(crate) fn from_oklch([l, c, h]: [f32; 3], alpha: f32) -> Color {
+    let mut chroma = c;
+    loop {
+        let color = AlphaColor::<Oklch>::new([l, chroma, h, alpha]).convert::<Srgb>();
+        let [r, g, b, _] = color.components;
+        let in_gamut = [r, g, b].iter().all(|v| (-1e-4..=1.0 + 1e-4).contains(v));
+        if in_gamut || chroma < 1e-3 {
+            return color;
+        }
+        chroma *= 0.95;
+    }
+}
+
+/// Generates a 50–950 ramp, in [`SHADES`] order, from a brand color
+///
+/// The base color becomes shade 500. The other shades keep its hue and step
+/// the OKLCH lightness like Tailwind's palette, stretched so 50 and 950 land
+/// at Tailwind's lightest and darkest shades; chroma tapers toward both
+/// ends. Register the result with
+/// [`ColorValue::from_palette`](crate::config::ColorValue::from_palette):
+///
+/// ```rust
+/// use floem_tailwind::TailwindConfig;
+/// use floem_tailwind::colors::generate_palette;
+/// use floem_tailwind::config::ColorValue;
+/// use peniko::Color;
+///
+/// let brand = generate_palette(Color::from_rgb8(0x63, 0x66, 0xf1));
+/// let mut config = TailwindConfig::default();
+/// config
+///     .colors
+///     .insert("brand".to_string(), ColorValue::from_palette(brand));
+/// assert!(config.color("brand-950").is_some());
+/// ```
+pub fn generate_palette(base: Color) -> [Color; 11] {
+    let [l, c, h, alpha] = base.convert::<Oklch>().components;
+    let (lightest, darkest) = (LIGHTNESS[0], LIGHTNESS[10]);
+    std::array::from_fn(|i| {
+        if i == BASE {
+            return base;
+        }
+        // Map the reference step onto the range between the base and the end
+        let lightness = if i < BASE {
+            let t = (LIGHTNESS[i] - LIGHTNESS[BASE]) / (lightest - LIGHTNESS[BASE]);
+            l + t * (lightest.max(l) - l)
+        } else {
+            let t = (LIGHTNESS[BASE] - LIGHTNESS[i]) / (LIGHTNESS[BASE] - darkest);
+            l - t * (l - darkest.min(l))
+        };
+        from_oklch([lightness, c * CHROMA[i], h], alpha)
+    })
+}
+
+/// OKLCH components of Tailwind v4's palette, in [`Family::ALL`] and
+/// [`SHADES`] order
+const V4_OKLCH: [[[f32; 3]; 11]; 22] = [
+    // Slate
+    [
+        [0.984, 0.003, 247.858],
+        [0.968, 0.007, 247.896],
+        [0.929, 0.013, 255.508],
+        [0.869, 0.022, 252.894],
+        [0.704, 0.04, 256.788],
+        [0.554, 0.046, 257.417],
+        [0.446, 0.043, 257.281],
+        [0.372, 0.044, 257.287],
+        [0.279, 0.041, 260.031],
+        [0.208, 0.042, 265.755],
+        [0.129, 0.042, 264.695],
+    ],
+    // Gray
+    [
+        [0.985, 0.002, 247.839],
+        [0.967, 0.003, 264.542],
+        [0.928, 0.006, 264.531],
+        [0.872, 0.01, 258.338],
+        [0.707, 0.022, 261.325],
+        [0.551, 0.027, 264.364],
+        [0.446, 0.03, 256.802],
+        [0.373, 0.034, 259.733],
+        [0.278, 0.033, 256.848],
+        [0.21, 0.034, 264.665],
+        [0.13, 0.028, 261.692],
+    ],
+    // Zinc
+    [
+        [0.985, 0.0, 0.0],
+        [0.967, 0.001, 286.375],
+        [0.92, 0.004, 286.32],
+        [0.871, 0.006, 286.286],
+        [0.705, 0.015, 286.067],
+        [0.552, 0.016, 285.938],
+        [0.442, 0.017, 285.786],
+        [0.37, 0.013, 285.805],
+        [0.274, 0.006, 286.033],
+        [0.21, 0.006, 285.885],
+        [0.141, 0.005, 285.823],
+    ],
+    // Neutral
+    [
+        [0.985, 0.0, 0.0],
+        [0.97, 0.0, 0.0],
+        [0.922, 0.0, 0.0],
+        [0.87, 0.0, 0.0],
+        [0.708, 0.0, 0.0],
+        [0.556, 0.0, 0.0],
+        [0.439, 0.0, 0.0],
+        [0.371, 0.0, 0.0],
+        [0.269, 0.0, 0.0],
+        [0.205, 0.0, 0.0],
+        [0.145, 0.0, 0.0],
+    ],
+    // Stone
+    [
+        [0.985, 0.001, 106.423],
+        [0.97, 0.001, 106.424],
+        [0.923, 0.003, 48.717],
+        [0.869, 0.005, 56.366],
+        [0.709, 0.01, 56.259],
+        [0.553, 0.013, 58.071],
+        [0.444, 0.011, 73.639],
+        [0.374, 0.01, 67.558],
+        [0.268, 0.007, 34.298],
+        [0.216, 0.006, 56.043],
+        [0.147, 0.004, 49.25],
+    ],
+    // Red
+    [
+        [0.971, 0.013, 17.38],
+        [0.936, 0.032, 17.717],
+        [0.885, 0.062, 18.334],
+        [0.808, 0.114, 19.571],
+        [0.704, 0.191, 22.216],
+        [0.637, 0.237, 25.331],
+        [0.577, 0.245, 27.325],
+        [0.505, 0.213, 27.518],
+        [0.444, 0.177, 26.899],
+        [0.396, 0.141, 25.723],
+        [0.258, 0.092, 26.042],
+    ],
+    // Orange
+    [
+        [0.98, 0.016, 73.684],
+        [0.954, 0.038, 75.164],
+        [0.901, 0.076, 70.697],
+        [0.837, 0.128, 66.29],
+        [0.75, 0.183, 55.934],
+        [0.705, 0.213, 47.604],
+        [0.646, 0.222, 41.116],
+        [0.553, 0.195, 38.402],
+        [0.47, 0.157, 37.304],
+        [0.408, 0.123, 38.172],
+        [0.266, 0.079, 36.259],
+    ],
+    // Amber
+    [
+        [0.987, 0.022, 95.277],
+        [0.962, 0.059, 95.617],
+        [0.924, 0.12, 95.746],
+        [0.879, 0.169, 91.605],
+        [0.828, 0.189, 84.429],
+        [0.769, 0.188, 70.08],
+        [0.666, 0.179, 58.318],
+        [0.555, 0.163, 48.998],
+        [0.473, 0.137, 46.201],
+        [0.414, 0.112, 45.904],
+        [0.279, 0.077, 45.635],
+    ],
+    // Yellow
+    [
+        [0.987, 0.026, 102.212],
+        [0.973, 0.071, 103.193],
+        [0.945, 0.129, 101.54],
+        [0.905, 0.182, 98.111],
+        [0.852, 0.199, 91.936],
+        [0.795, 0.184, 86.047],
+        [0.681, 0.162, 75.834],
+        [0.554, 0.135, 66.442],
+        [0.476, 0.114, 61.907],
+        [0.421, 0.095, 57.708],
+        [0.286, 0.066, 53.813],
+    ],
+    // Lime
+    [
+        [0.986, 0.031, 120.757],
+        [0.967, 0.067, 122.328],
+        [0.938, 0.127, 124.321],
+        [0.897, 0.196, 126.665],
+        [0.841, 0.238, 128.85],
+        [0.768, 0.233, 130.85],
+        [0.648, 0.2, 131.684],
+        [0.532, 0.157, 131.589],
+        [0.453, 0.124, 130.933],
+        [0.405, 0.101, 131.063],
+        [0.274, 0.072, 132.109],
+    ],
+    // Green
+    [
+        [0.982, 0.018, 155.826],
+        [0.962, 0.044, 156.743],
+        [0.925, 0.084, 155.995],
+        [0.871, 0.15, 154.449],
+        [0.792, 0.209, 151.711],
+        [0.723, 0.219, 149.579],
+        [0.627, 0.194, 149.214],
+        [0.527, 0.154, 150.069],
+        [0.448, 0.119, 151.328],
+        [0.393, 0.095, 152.535],
+        [0.266, 0.065, 152.934],
+    ],
+    // Emerald
+    [
+        [0.979, 0.021, 166.113],
+        [0.95, 0.052, 163.051],
+        [0.905, 0.093, 164.15],
+        [0.845, 0.143, 164.978],
+        [0.765, 0.177, 163.223],
+        [0.696, 0.17, 162.48],
+        [0.596, 0.145, 163.225],
+        [0.508, 0.118, 165.612],
+        [0.432, 0.095, 166.913],
+        [0.378, 0.077, 168.94],
+        [0.262, 0.051, 172.552],
+    ],
+    // Teal
+    [
+        [0.984, 0.014, 180.72],
+        [0.953, 0.051, 180.801],
+        [0.91, 0.096, 180.426],
+        [0.855, 0.138, 181.071],
+        [0.777, 0.152, 181.912],
+        [0.704, 0.14, 182.503],
+        [0.6, 0.118, 184.704],
+        [0.511, 0.096, 186.391],
+        [0.437, 0.078, 188.216],
+        [0.386, 0.063, 188.416],
+        [0.277, 0.046, 192.524],
+    ],
+    // Cyan
+    [
+        [0.984, 0.019, 200.873],
+        [0.956, 0.045, 203.388],
+        [0.917, 0.08, 205.041],
+        [0.865, 0.127, 207.078],
+        [0.789, 0.154, 211.53],
+        [0.715, 0.143, 215.221],
+        [0.609, 0.126, 221.723],
+        [0.52, 0.105, 223.128],
+        [0.45, 0.085, 224.283],
+        [0.398, 0.07, 227.392],
+        [0.302, 0.056, 229.695],
+    ],
+    // Sky
+    [
+        [0.977, 0.013, 236.62],
+        [0.951, 0.026, 236.824],
+        [0.901, 0.058, 230.902],
+        [0.828, 0.111, 230.318],
+        [0.746, 0.16, 232.661],
+        [0.685, 0.169, 237.323],
+        [0.588, 0.158, 241.966],
+        [0.5, 0.134, 242.749],
+        [0.443, 0.11, 240.79],
+        [0.391, 0.09, 240.876],
+        [0.293, 0.066, 243.157],
+    ],
+    // Blue
+    [
+        [0.97, 0.014, 254.604],
+        [0.932, 0.032, 255.585],
+        [0.882, 0.059, 254.128],
+        [0.809, 0.105, 251.813],
+        [0.707, 0.165, 254.624],
+        [0.623, 0.214, 259.815],
+        [0.546, 0.245, 262.881],
+        [0.488, 0.243, 264.376],
+        [0.424, 0.199, 265.638],
+        [0.379, 0.146, 265.522],
+        [0.282, 0.091, 267.935],
+    ],
+    // Indigo
+    [
+        [0.962, 0.018, 272.314],
+        [0.93, 0.034, 272.788],
+        [0.87, 0.065, 274.039],
+        [0.785, 0.115, 274.713],
+        [0.673, 0.182, 276.935],
+        [0.585, 0.233, 277.117],
+        [0.511, 0.262, 276.966],
+        [0.457, 0.24, 277.023],
+        [0.398, 0.195, 277.366],
+        [0.359, 0.144, 278.697],
+        [0.257, 0.09, 281.288],
+    ],
+    // Violet
+    [
+        [0.969, 0.016, 293.756],
+        [0.943, 0.029, 294.588],
+        [0.894, 0.057, 293.283],
+        [0.811, 0.111, 293.571],
+        [0.702, 0.183, 293.541],
+        [0.606, 0.25, 292.717],
+        [0.541, 0.281, 293.009],
+        [0.491, 0.27, 292.581],
+        [0.432, 0.232, 292.759],
+        [0.38, 0.189, 293.745],
+        [0.283, 0.141, 291.089],
+    ],
+    // Purple
+    [
+        [0.977, 0.014, 308.299],
+        [0.946, 0.033, 307.174],
+        [0.902, 0.063, 306.703],
+        [0.827, 0.119, 306.383],
+        [0.714, 0.203, 305.504],
+        [0.627, 0.265, 303.9],
+        [0.558, 0.288, 302.321],
+        [0.496, 0.265, 301.924],
+        [0.438, 0.218, 303.724],
+        [0.381, 0.176, 304.987],
+        [0.291, 0.149, 302.717],
+    ],
+    // Fuchsia
+    [
+        [0.977, 0.017, 320.058],
+        [0.952, 0.037, 318.852],
+        [0.903, 0.076, 319.62],
+        [0.833, 0.145, 321.434],
+        [0.74, 0.238, 322.16],
+        [0.667, 0.295, 322.15],
+        [0.591, 0.293, 322.896],
+        [0.518, 0.253, 323.949],
+        [0.452, 0.211, 324.591],
+        [0.401, 0.17, 325.612],
+        [0.293, 0.136, 325.661],
+    ],
+    // Pink
+    [
+        [0.971, 0.014, 343.198],
+        [0.948, 0.028, 342.258],
+        [0.899, 0.061, 343.231],
+        [0.823, 0.12, 346.018],
+        [0.718, 0.202, 349.761],
+        [0.656, 0.241, 354.308],
+        [0.592, 0.249, 0.584],
+        [0.525, 0.223, 3.958],
+        [0.459, 0.187, 3.815],
+        [0.408, 0.153, 2.432],
+        [0.284, 0.109, 3.907],
+    ],
+    // Rose
+    [
+        [0.969, 0.015, 12.422],
+        [0.941, 0.03, 12.58],
+        [0.892, 0.058, 10.001],
+        [0.81, 0.117, 11.638],
+        [0.712, 0.194, 13.428],
+        [0.645, 0.246, 16.439],
+        [0.586, 0.253, 17.585],
+        [0.514, 0.222, 16.935],
+        [0.455, 0.188, 13.697],
+        [0.41, 0.159, 10.272],
+        [0.271, 0.105, 12.094],
+    ],
+];
+
+/// Tailwind v4's palette in sRGB
+static V4: LazyLock<[[Color; 11]; 22]> =
+    LazyLock::new(|| V4_OKLCH.map(|family| family.map(|components| from_oklch(components, 1.0))));
+
+/// Returns the color for a shade stop in Tailwind v4's palette, if it exists
+///
+/// v4 defines its palette in OKLCH, with some shades outside sRGB; those
+/// keep their lightness and hue and lose chroma until they fit. The palette
+/// constants and utilities are v3's unless
+/// [`theme::set_palette_version`](crate::theme::set_palette_version) selects
+/// v4.
+pub fn v4_shade(family: Family, shade: u16) -> Option<Color> {
+    let i = SHADES.iter().position(|&stop| stop == shade)?;
+    Some(V4[family as usize][i])
+}
+
+/// The v4 counterpart of a v3 palette color, keeping its alpha
+///
+/// Colors that aren't v3 palette shades are returned unchanged.
+///
+/// ```rust
+/// use floem_tailwind::colors::{self, Family};
+///
+/// let blue = colors::to_v4(colors::blue::C500);
+/// assert_eq!(Some(blue), colors::v4_shade(Family::Blue, 500));
+/// assert_eq!(colors::to_v4(colors::WHITE), colors::WHITE);
+/// ```
+pub fn to_v4(color: Color) -> Color {
+    let rgb = |color: Color| {
+        let [r, g, b, _] = color.to_rgba8().to_u8_array();
+        [r, g, b]
+    };
+    let target = rgb(color);
+    Family::ALL
+        .into_iter()
+        .find_map(|family| {
+            let i = SHADES
+                .iter()
+                .position(|&shade| family.shade(shade).map(rgb) == Some(target))?;
+            Some(V4[family as usize][i].with_alpha(color.components[3]))
+        })
+        .unwrap_or(color)
+}
+
+/// A shade of `family` anywhere on the 50–950 scale (e.g. 550)
+///
+/// Shades between two stops are mixed in Oklab, so they sit perceptually
+/// between their neighbors; stops return the palette color itself, and
+/// shades outside the scale are clamped to it. For charts and gradients that
+/// need more steps than [`SHADES`]:
+///
+/// ```rust
+/// use floem_tailwind::colors::{self, Family};
+///
+/// assert_eq!(colors::shade(Family::Blue, 500), colors::blue::C500);
+/// let ramp: Vec<_> = (0..=18).map(|i| colors::shade(Family::Blue, 50 + i * 50)).collect();
+/// ```
+pub fn shade(family: Family, shade: u16) -> Color {
+    let shade = shade.clamp(SHADES[0], SHADES[SHADES.len() - 1]);
+    let upper = SHADES
+        .iter()
+        .position(|&stop| stop >= shade)
+        .unwrap_or(SHADES.len() - 1);
+    let color = |i: usize| {
+        family
+            .shade(SHADES[i])
+            .expect("every family has every stop")
+    };
+    if SHADES[upper] == shade {
+        return color(upper);
+    }
+    let (from, to) = (SHADES[upper - 1], SHADES[upper]);
+    let t = f32::from(shade - from) / f32::from(to - from);
+    crate::color_math::mix(color(upper - 1), color(upper), t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_palette() {
+        let palette = generate_palette(blue::C500);
+        assert_eq!(palette[BASE], blue::C500);
+        let lightness = palette.map(|color| color.convert::<Oklch>().components[0]);
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+        assert!((lightness[0] - LIGHTNESS[0]).abs() < 0.01);
+        assert!((lightness[10] - LIGHTNESS[10]).abs() < 0.01);
+
+        // Close to Tailwind's own ramp for a Tailwind base color
+        let distance = |a: Color, b: Color| {
+            let [a, b] = [a, b].map(|color| color.convert::<Oklch>().components);
+            (a[0] - b[0]).abs()
+        };
+        assert!(distance(palette[1], blue::C100) < 0.03);
+        assert!(distance(palette[8], blue::C800) < 0.05);
+    }
+
+    #[test]
+    fn test_shade() {
+        let lightness = |color: Color| color.convert::<Oklch>().components[0];
+        assert_eq!(shade(Family::Blue, 600), blue::C600);
+        let between = lightness(shade(Family::Blue, 550));
+        assert!(lightness(blue::C600) < between && between < lightness(blue::C500));
+        assert!(lightness(shade(Family::Blue, 525)) > between);
+        assert_eq!(shade(Family::Gray, 0), gray::C50);
+        assert_eq!(shade(Family::Gray, 1000), gray::C950);
+    }
+
+    #[test]
+    fn test_shade_steps() {
+        assert_eq!(one_darker(blue::C500), Some(blue::C600));
+        assert_eq!(one_lighter(blue::C500), Some(blue::C400));
+        assert_eq!(one_lighter(blue::C100), Some(blue::C50));
+        assert_eq!(one_darker(blue::C950), None);
+        assert_eq!(one_lighter(blue::C50), None);
+        assert_eq!(one_darker(Color::from_rgb8(1, 2, 3)), None);
+    }
+
+    #[test]
+    fn test_color_strings() {
+        assert_eq!(to_hex(rose::C500), "#f43f5e");
+        assert_eq!(to_hex(alpha(BLACK, 0.5)), "#00000080");
+        assert_eq!(to_css_rgb(blue::C500), "rgb(59 130 246)");
+        assert_eq!(to_css_rgb(alpha(BLACK, 0.5)), "rgb(0 0 0 / 0.5)");
+
+        assert_eq!(name_of(slate::C950).as_deref(), Some("slate-950"));
+        assert_eq!(name_of(TRANSPARENT).as_deref(), Some("transparent"));
+        assert_eq!(name_of(Color::from_rgb8(1, 2, 3)), None);
+    }
+
+    #[test]
+    fn test_v4_palette() {
+        assert_eq!(
+            v4_shade(Family::Gray, 500).map(to_hex).as_deref(),
+            Some("#6a7282")
+        );
+        assert_eq!(to_hex(to_v4(red::C500)), "#fb2c36");
+        assert_eq!(v4_shade(Family::Gray, 550), None);
+        assert_eq!(to_v4(alpha(red::C500, 0.5)), alpha(to_v4(red::C500), 0.5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tw_color_serde() {
+        let colors: Vec<TwColor> =
+            serde_json::from_str(r##"["blue-500", "#1e293b", "white", "rgb(0 0 0 / 0.5)"]"##)
+                .unwrap();
+        assert_eq!(colors[0], TwColor(blue::C500));
+        assert_eq!(colors[1].0.to_rgba8(), slate::C800.to_rgba8());
+        assert_eq!(colors[2], TwColor(WHITE));
+        assert_eq!(
+            serde_json::to_string(&colors).unwrap(),
+            r##"["blue-500","slate-800","white","#00000080"]"##
+        );
+        assert!(serde_json::from_str::<TwColor>(r#""blue-550""#).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! Styles as composable values
+//!
+//! A [`TwStyle`] accumulates utilities like a `Style` does, but is meant to
+//! be passed around: component libraries can export their styles, combine
+//! them with `+` (the right-hand side wins, as with `Style::apply`), keep
+//! them in signals, and apply them to a view's style later.
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::TwStyle;
+//! use floem_tailwind::prelude::*;
+//!
+//! fn button_style() -> TwStyle {
+//!     TwStyle::new().px_4().py_2().rounded_md().font_medium()
+//! }
+//!
+//! let primary = button_style() + TwStyle::new().bg_blue_500().text_white();
+//! let selected = RwSignal::new(primary.clone());
+//! let save = button("Save").style(move |s| selected.get().apply_to(s));
+//! ```
+//!
+//! Floem styles are single-threaded, so shared `TwStyle`s live in a
+//! function, a `thread_local!`, or a signal rather than a `static`.
+//!
+//! Styles that never change can be named with [`apply!`](crate::apply), the
+//! counterpart of Tailwind's `@apply`: it defines a style function from class
+//! strings and other style functions.
+//!
+//! ```rust
+//! use floem::prelude::*;
+//! use floem_tailwind::apply;
+//!
+//! apply! {
+//!     /// Base button
+//!     pub btn = "px-4 py-2 rounded-md font-medium";
+//!     /// Primary button, on top of [`btn`]
+//!     pub btn_primary = btn, "bg-blue-500 text-white";
+//! }
+//!
+//! let save = button("Save").style(btn_primary);
+//! ```
+
+use std::ops::{Add, AddAssign};
+use std::sync::{Arc, OnceLock};
+
+use floem::style::Style;
+
+use crate::StyleBuilder;
+use crate::class::{self, ParseClassError, TwClass};
+use crate::{intern, warning};
+
+/// A reusable set of utilities, applied to a `Style` later
+#[derive(Clone, Default)]
+pub struct TwStyle(Style);
+
+impl TwStyle {
+    /// An empty style
+    pub fn new() -> Self {
+        TwStyle(Style::new())
+    }
+
+    /// The utilities of a class string
+    pub fn parse(classes: &str) -> Result<Self, ParseClassError> {
+        let classes = class::parse_classes(classes)?;
+        Ok(TwStyle(
+            classes.iter().fold(Style::new(), |s, class| class.apply(s)),
+        ))
+    }
+
+    /// Applies the utilities on top of `s`
+    pub fn apply_to(&self, s: Style) -> Style {
+        s.apply(self.0.clone())
+    }
+
+    /// The accumulated style
+    pub fn to_style(&self) -> Style {
+        self.0.clone()
+    }
+}
+
+impl StyleBuilder for TwStyle {
+    fn map_style(self, f: impl FnOnce(Style) -> Style) -> Self {
+        TwStyle(f(self.0))
+    }
+}
+
+impl From<Style> for TwStyle {
+    fn from(style: Style) -> Self {
+        TwStyle(style)
+    }
+}
+
+impl From<TwStyle> for Style {
+    fn from(style: TwStyle) -> Self {
+        style.0
+    }
+}
+
+/// Combines two styles; utilities of `rhs` override those of `self`
+impl Add for TwStyle {
+    type Output = TwStyle;
+
+    fn add(self, rhs: TwStyle) -> TwStyle {
+        TwStyle(self.0.apply(rhs.0))
+    }
+}
+
+impl AddAssign for TwStyle {
+    fn add_assign(&mut self, rhs: TwStyle) {
+        *self = std::mem::take(self) + rhs;
+    }
+}
+
+/// The utilities of an [`apply!`](crate::apply) class string, parsed on first use
+#[doc(hidden)]
+pub type Parsed = OnceLock<Arc<[TwClass]>>;
+
+/// A part of an [`apply!`](crate::apply) style: a class string or a style function
+#[doc(hidden)]
+pub trait ApplyPart {
+    fn apply_part(self, name: &str, parsed: &Parsed, s: Style) -> Style;
+}
+
+impl ApplyPart for &str {
+    fn apply_part(self, name: &str, parsed: &Parsed, s: Style) -> Style {
+        let classes = parsed.get_or_init(|| {
+            intern::parse_classes(self).unwrap_or_else(|error| {
+                if cfg!(debug_assertions) {
+                    warning::warn(format_args!("`{name}`: {error}"));
+                }
+                intern::parse_classes_lossy(self)
+            })
+        });
+        classes.iter().fold(s, |s, class| class.apply(s))
+    }
+}
+
+impl<F: FnOnce(Style) -> Style> ApplyPart for F {
+    fn apply_part(self, _name: &str, _parsed: &Parsed, s: Style) -> Style {
+        self(s)
+    }
+}
+
+/// Defines named style functions from class strings and other style functions
+///
+/// Each definition becomes a `fn(Style) -> Style` that applies its parts in
+/// order, so it can be passed to a view's `.style(...)`, to
+/// [`TailwindExt::breakpoint`](crate::TailwindExt::breakpoint) and other
+/// methods taking a style function, or to later definitions as a part:
+///
+/// ```rust
+/// floem_tailwind::apply! {
+///     pub card = "p-6 rounded-lg bg-white";
+///     pub card_raised = card, "shadow-md", floem_tailwind::presets::interactive;
+/// }
+/// ```
+///
+/// Each class string is parsed once, the first time its style is computed,
+/// so register utilities before then; logical utilities still follow the
+/// direction on every pass. Unknown classes are skipped and, in debug builds,
+/// reported as a [`warning`](crate::warning) with the style's name.
+#[macro_export]
+macro_rules! apply {
+    ($($(#[$meta:meta])* $vis:vis $name:ident = $($part:expr),+);+ $(;)?) => {
+        $(
+            $(#[$meta])*
+            $vis fn $name(s: $crate::__floem::style::Style) -> $crate::__floem::style::Style {
+                $(
+                    let s = {
+                        static PARSED: $crate::compose::Parsed = $crate::compose::Parsed::new();
+                        $crate::compose::ApplyPart::apply_part(
+                            $part,
+                            ::core::stringify!($name),
+                            &PARSED,
+                            s,
+                        )
+                    };
+                )+
+                s
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspect::to_class_string;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_combine() {
+        let base = TwStyle::new().p_4().bg_gray_100();
+        let mut style = base.clone() + TwStyle::parse("bg-blue-500 rounded").unwrap();
+        assert_eq!(
+            to_class_string(&style.to_style()),
+            "p-4 rounded bg-blue-500"
+        );
+        style += TwStyle::new().p_2();
+        assert_eq!(
+            to_class_string(&style.apply_to(Style::new().w_4())),
+            "w-4 p-2 rounded bg-blue-500"
+        );
+        assert_eq!(to_class_string(&base.into()), "p-4 bg-gray-100");
+        assert!(TwStyle::parse("p-4 bogus").is_err());
+    }
+
+    crate::apply! {
+        btn = "px-4 py-2 rounded-md";
+        btn_primary = btn, "bg-blue-500", |s: Style| s.p_2();
+        bogus = "p-4 bogus";
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(to_class_string(&btn(Style::new())), "px-4 py-2 rounded-md");
+        assert_eq!(
+            to_class_string(&btn_primary(Style::new().w_4())),
+            "w-4 p-2 rounded-md bg-blue-500"
+        );
+    }
+
+    #[test]
+    fn test_apply_unknown_class() {
+        static WARNINGS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+        warning::set_hook(|message| WARNINGS.lock().unwrap().push(message.to_string()));
+        assert_eq!(to_class_string(&bogus(Style::new())), "p-4");
+        assert_eq!(to_class_string(&bogus(Style::new())), "p-4");
+        warning::reset_hook();
+        let warnings = WARNINGS.lock().unwrap();
+        let expected = "`bogus`: unknown utility class `bogus`";
+        assert_eq!(warnings.iter().filter(|w| *w == expected).count(), 1);
+    }
+}
@@ -0,0 +1,550 @@
+//! Project-level theme configuration
+//!
+//! A `TailwindConfig` extends the built-in scales with project-specific
+//! values, in the spirit of `tailwind.config.js`'s `theme.extend`. Entries
+//! with the same name as a built-in value override it; everything else falls
+//! back to the defaults.
+//!
+//! With the `toml` or `json` feature the config can be loaded from a file:
+//!
+//! ```toml
+//! [spacing]
+//! "18" = 72.0
+//!
+//! [borderRadius]
+//! DEFAULT = 6.0
+//!
+//! [colors]
+//! accent = "#f97316"
+//!
+//! [colors.brand]
+//! 500 = "#6366f1"
+//! 600 = "#4f46e5"
+//! ```
+//!
+//! Class strings are then resolved against the config instance:
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::TailwindConfig;
+//!
+//! let mut config = TailwindConfig::default();
+//! config.spacing.insert("18".to_string(), 72.0);
+//!
+//! let style = config.apply_classes(Style::new(), "p-18 rounded-lg").unwrap();
+//! assert_eq!(config.spacing("18"), Some(72.0));
+//! assert_eq!(config.spacing("4"), Some(16.0));
+//! ```
+//!
+//! Config values are pixels at the default 16px root, and are scaled like the
+//! built-in values: spacing and font sizes follow the [`rem`](crate::rem)
+//! root, spacing and radii the [`density`](crate::density), and lengths are
+//! snapped by [`dpi`](crate::dpi).
+
+use std::collections::BTreeMap;
+
+use floem::style::Style;
+use floem::views::PlaceholderTextClass;
+use peniko::Color;
+use peniko::color::{Srgb, parse_color};
+
+use crate::class::{self, ColorKeyword, ParseClassError, Radius, TextSize, TwClass};
+use crate::screen::Breakpoint;
+use crate::{Spacing, colors, radius, rem};
+
+/// A color entry: a single color or a family of shades keyed by shade name
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum ColorValue {
+    /// A single color, used as `bg-accent`
+    Single(String),
+    /// Shades of a family, used as `bg-brand-500`
+    Shades(BTreeMap<String, String>),
+}
+
+impl ColorValue {
+    /// Shades from a 50–950 ramp in [`SHADES`](colors::SHADES) order,
+    /// such as one made by [`generate_palette`](colors::generate_palette)
+    pub fn from_palette(palette: [Color; 11]) -> Self {
+        let shades = colors::SHADES
+            .iter()
+            .zip(palette)
+            .map(|(shade, color)| (shade.to_string(), colors::to_hex(color)));
+        ColorValue::Shades(shades.collect())
+    }
+}
+
+/// Theme overrides and extensions, keyed by class-name fragment
+///
+/// Lengths are in pixels; colors are CSS color strings (`#6366f1`,
+/// `rgb(99 102 241)`, ...).
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct TailwindConfig {
+    /// Spacing scale (`p-*`, `m-*`, `w-*`, `gap-*`, ...)
+    pub spacing: BTreeMap<String, f64>,
+    /// Border radius scale (`rounded-*`); `DEFAULT` is plain `rounded`
+    #[cfg_attr(feature = "serde", serde(alias = "border_radius"))]
+    pub border_radius: BTreeMap<String, f64>,
+    /// Font size scale (`text-*`)
+    #[cfg_attr(feature = "serde", serde(alias = "font_size"))]
+    pub font_size: BTreeMap<String, f32>,
+    /// Responsive breakpoints (minimum window width)
+    pub screens: BTreeMap<String, f64>,
+    /// Extra colors (`bg-*`, `text-*`, `border-*`)
+    pub colors: BTreeMap<String, ColorValue>,
+}
+
+/// Parses a CSS color string from the config
+pub(crate) fn parse_config_color(value: &str) -> Option<Color> {
+    parse_color(value)
+        .ok()
+        .map(|color| color.to_alpha_color::<Srgb>())
+}
+
+/// Utility prefixes that take a value from the spacing scale
+pub(crate) const SPACING_PREFIXES: &[&str] = &[
+    "w",
+    "h",
+    "size",
+    "min-w",
+    "max-w",
+    "p",
+    "px",
+    "py",
+    "pt",
+    "pr",
+    "pb",
+    "pl",
+    "m",
+    "mx",
+    "my",
+    "mt",
+    "mr",
+    "mb",
+    "ml",
+    "gap",
+    "inset",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "translate-x",
+    "translate-y",
+];
+
+/// Applies a spacing utility with prefix `prefix` (one of
+/// [`SPACING_PREFIXES`]) and a value in pixels
+pub(crate) fn apply_spacing(s: Style, prefix: &str, px: f64) -> Style {
+    match prefix {
+        "w" => s.width(px),
+        "h" => s.height(px),
+        "size" => s.width(px).height(px),
+        "min-w" => s.min_width(px),
+        "max-w" => s.max_width(px),
+        "p" => s.padding(px),
+        "px" => s.padding_horiz(px),
+        "py" => s.padding_vert(px),
+        "pt" => s.padding_top(px),
+        "pr" => s.padding_right(px),
+        "pb" => s.padding_bottom(px),
+        "pl" => s.padding_left(px),
+        "m" => s.margin(px),
+        "mx" => s.margin_horiz(px),
+        "my" => s.margin_vert(px),
+        "mt" => s.margin_top(px),
+        "mr" => s.margin_right(px),
+        "mb" => s.margin_bottom(px),
+        "ml" => s.margin_left(px),
+        "gap" => s.gap(px),
+        "inset" => s.inset(px),
+        "top" => s.inset_top(px),
+        "right" => s.inset_right(px),
+        "bottom" => s.inset_bottom(px),
+        "left" => s.inset_left(px),
+        "translate-x" => s.translate_x(px),
+        "translate-y" => s.translate_y(px),
+        _ => s,
+    }
+}
+
+/// A class resolved against values defined in a config
+enum Custom<'a> {
+    Spacing(&'a str, f64),
+    Radius(f64),
+    FontSize(f32),
+    TextColor(Color),
+    PlaceholderColor(Color),
+    BgColor(Color),
+    BorderColor(Color),
+}
+
+impl Custom<'_> {
+    fn apply(self, s: Style) -> Style {
+        match self {
+            Custom::Spacing(prefix, px) => apply_spacing(s, prefix, rem::scale(px)),
+            Custom::Radius(px) => s.border_radius(radius::scale(px)),
+            Custom::FontSize(px) => s.font_size(rem::scale_f32(px)),
+            Custom::TextColor(color) => s.color(color),
+            Custom::PlaceholderColor(color) => s.class(PlaceholderTextClass, |s| s.color(color)),
+            Custom::BgColor(color) => s.background(color),
+            Custom::BorderColor(color) => s.border_color(color),
+        }
+    }
+}
+
+impl TailwindConfig {
+    /// Parses a config from TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(source)
+    }
+
+    /// Parses a config from JSON
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(source)
+    }
+
+    /// Parses a Figma Tokens / Tokens Studio export (see [`tokens`](crate::tokens))
+    #[cfg(feature = "json")]
+    pub fn from_figma_tokens(source: &str) -> Result<Self, serde_json::Error> {
+        crate::tokens::from_figma(source)
+    }
+
+    /// Parses a W3C Design Tokens (DTCG) file (see [`tokens`](crate::tokens))
+    #[cfg(feature = "json")]
+    pub fn from_dtcg_tokens(source: &str) -> Result<Self, serde_json::Error> {
+        crate::tokens::from_dtcg(source)
+    }
+
+    /// The full theme, built-in scales merged with this config, as the
+    /// `theme` of a `tailwind.config.js`
+    ///
+    /// Lengths become `px` strings and palette colors hex strings, in the
+    /// [palette version](crate::theme::palette_version) in use, so web
+    /// tooling renders the same values as the app:
+    ///
+    /// ```json
+    /// { "theme": { "spacing": { "4": "16px" }, "colors": { "blue": { "500": "#3b82f6" } } } }
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn export_tailwind_config(&self) -> String {
+        use serde_json::{Map, Value, json};
+
+        /// A scale in `px` strings, entries of the config last so they override
+        fn lengths<T: Copy + std::fmt::Display>(
+            builtin: impl Iterator<Item = (String, T)>,
+            custom: &BTreeMap<String, T>,
+        ) -> Map<String, Value> {
+            builtin
+                .chain(custom.iter().map(|(name, &px)| (name.clone(), px)))
+                .map(|(name, px)| (name, json!(format!("{px}px"))))
+                .collect()
+        }
+
+        let v4 = crate::theme::palette_version() == crate::theme::PaletteVersion::V4;
+        let mut colors: Map<String, Value> = ColorKeyword::ALL
+            .iter()
+            .map(|keyword| (keyword.name().to_string(), keyword.to_color()))
+            .map(|(name, color)| (name, json!(colors::to_hex(color))))
+            .collect();
+        for family in colors::Family::ALL {
+            let shades: Map<String, Value> = colors::SHADES
+                .iter()
+                .filter_map(|&shade| {
+                    let color = if v4 {
+                        colors::v4_shade(family, shade)
+                    } else {
+                        family.shade(shade)
+                    }?;
+                    Some((shade.to_string(), json!(colors::to_hex(color))))
+                })
+                .collect();
+            colors.insert(family.name().to_string(), Value::Object(shades));
+        }
+        for (name, value) in &self.colors {
+            match value {
+                ColorValue::Single(color) => {
+                    colors.insert(name.clone(), json!(color));
+                }
+                ColorValue::Shades(shades) => {
+                    let entry = colors.entry(name.clone()).or_insert_with(|| json!({}));
+                    if !entry.is_object() {
+                        *entry = json!({});
+                    }
+                    if let Value::Object(entry) = entry {
+                        for (shade, color) in shades {
+                            entry.insert(shade.clone(), json!(color));
+                        }
+                    }
+                }
+            }
+        }
+
+        let spacing = lengths(
+            Spacing::ALL
+                .into_iter()
+                .map(|step| (step.name().to_string(), step.to_px())),
+            &self.spacing,
+        );
+        let screens = lengths(
+            Breakpoint::ALL
+                .into_iter()
+                .map(|bp| (bp.name().to_string(), bp.min_width())),
+            &self.screens,
+        );
+        let border_radius = lengths(
+            Radius::ALL.iter().map(|radius| {
+                let name = match radius.name() {
+                    "" => "DEFAULT",
+                    name => name,
+                };
+                (name.to_string(), radius.to_px())
+            }),
+            &self.border_radius,
+        );
+        let font_size = lengths(
+            TextSize::ALL
+                .iter()
+                .map(|size| (size.name().to_string(), size.to_px())),
+            &self.font_size,
+        );
+
+        let theme = json!({
+            "theme": {
+                "colors": colors,
+                "spacing": spacing,
+                "screens": screens,
+                "borderRadius": border_radius,
+                "fontSize": font_size,
+            }
+        });
+        serde_json::to_string_pretty(&theme).expect("a JSON value always serializes")
+    }
+
+    /// Resolves a spacing step in pixels (`"4"` → 16.0)
+    pub fn spacing(&self, name: &str) -> Option<f64> {
+        self.spacing
+            .get(name)
+            .copied()
+            .or_else(|| Spacing::from_name(name).map(Spacing::to_px))
+    }
+
+    /// Resolves a border radius in pixels; `""` or `"DEFAULT"` is plain `rounded`
+    pub fn radius(&self, name: &str) -> Option<f64> {
+        let key = if name.is_empty() { "DEFAULT" } else { name };
+        let builtin = if name == "DEFAULT" { "" } else { name };
+        self.border_radius
+            .get(key)
+            .copied()
+            .or_else(|| Radius::from_name(builtin).map(Radius::to_px))
+    }
+
+    /// Resolves a font size in pixels (`"lg"` → 18.0)
+    pub fn font_size(&self, name: &str) -> Option<f32> {
+        self.font_size
+            .get(name)
+            .copied()
+            .or_else(|| TextSize::from_name(name).map(TextSize::to_px))
+    }
+
+    /// Resolves a breakpoint's minimum width in pixels (`"md"` → 768.0)
+    pub fn screen(&self, name: &str) -> Option<f64> {
+        self.screens
+            .get(name)
+            .copied()
+            .or_else(|| Breakpoint::from_name(name).map(Breakpoint::min_width))
+    }
+
+    /// Resolves a color name (`"brand-500"`, `"accent"`, `"blue-500"`, `"white"`)
+    pub fn color(&self, name: &str) -> Option<Color> {
+        self.custom_color(name)
+            .or_else(|| ColorKeyword::from_name(name).map(ColorKeyword::to_color))
+            .or_else(|| class::parse_palette(name).and_then(|(family, shade)| family.shade(shade)))
+    }
+
+    /// Resolves a color defined in this config only
+    fn custom_color(&self, name: &str) -> Option<Color> {
+        if let Some(ColorValue::Single(value)) = self.colors.get(name) {
+            return parse_config_color(value);
+        }
+        let (family, shade) = name.rsplit_once('-')?;
+        match self.colors.get(family)? {
+            ColorValue::Shades(shades) => parse_config_color(shades.get(shade)?),
+            ColorValue::Single(_) => None,
+        }
+    }
+
+    /// Resolves a class that uses a value defined in this config
+    fn custom_class<'a>(&self, class: &'a str) -> Option<Custom<'a>> {
+        if class == "rounded" {
+            return self
+                .border_radius
+                .get("DEFAULT")
+                .copied()
+                .map(Custom::Radius);
+        }
+        let (prefix, value) = class::split_prefix(class)?;
+        if SPACING_PREFIXES.contains(&prefix) {
+            return self
+                .spacing
+                .get(value)
+                .map(|&px| Custom::Spacing(prefix, px));
+        }
+        match prefix {
+            "rounded" => self.border_radius.get(value).copied().map(Custom::Radius),
+            "text" => self
+                .font_size
+                .get(value)
+                .copied()
+                .map(Custom::FontSize)
+                .or_else(|| self.custom_color(value).map(Custom::TextColor)),
+            "placeholder" => self.custom_color(value).map(Custom::PlaceholderColor),
+            "bg" => self.custom_color(value).map(Custom::BgColor),
+            "border" => self.custom_color(value).map(Custom::BorderColor),
+            _ => None,
+        }
+    }
+
+    /// Applies a single class, preferring values from this config over the defaults
+    pub fn apply_class(&self, s: Style, class: &str) -> Result<Style, ParseClassError> {
+        match self.custom_class(class) {
+            Some(custom) => Ok(custom.apply(s)),
+            None => Ok(class.parse::<TwClass>()?.apply(s)),
+        }
+    }
+
+    /// Applies a whitespace-separated class string
+    pub fn apply_classes(&self, s: Style, classes: &str) -> Result<Style, ParseClassError> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!(target: crate::trace::TARGET, "apply_classes", classes).entered();
+        classes
+            .split_whitespace()
+            .try_fold(s, |s, class| self.apply_class(s, class))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floem::style as fs;
+    use floem::unit::PxPct;
+
+    use super::*;
+    use crate::density;
+
+    #[test]
+    fn test_config_overrides_and_extends() {
+        let mut config = TailwindConfig::default();
+        config.spacing.insert("4".to_string(), 20.0);
+        config.spacing.insert("18".to_string(), 72.0);
+        config.border_radius.insert("DEFAULT".to_string(), 6.0);
+        config.colors.insert(
+            "brand".to_string(),
+            ColorValue::Shades(BTreeMap::from([("500".to_string(), "#6366f1".to_string())])),
+        );
+
+        assert_eq!(config.spacing("4"), Some(20.0));
+        assert_eq!(config.spacing("18"), Some(72.0));
+        assert_eq!(config.spacing("8"), Some(32.0));
+        assert_eq!(config.radius(""), Some(6.0));
+        assert_eq!(config.radius("lg"), Some(8.0));
+        assert_eq!(config.screen("md"), Some(768.0));
+        assert_eq!(
+            config.color("brand-500"),
+            Some(Color::from_rgb8(0x63, 0x66, 0xf1))
+        );
+        assert!(
+            config
+                .apply_classes(Style::new(), "p-18 bg-brand-500 text-sm")
+                .is_ok()
+        );
+        assert!(config.apply_class(Style::new(), "bg-brand-900").is_err());
+
+        let s = density::with_factor(0.5, || {
+            config.apply_classes(Style::new(), "pt-18 pl-4").unwrap()
+        });
+        assert_eq!(s.get(fs::PaddingTop), PxPct::Px(36.0));
+        assert_eq!(s.get(fs::PaddingLeft), PxPct::Px(10.0));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_config_from_toml() {
+        let config = TailwindConfig::from_toml(
+            r##"
+            [spacing]
+            "18" = 72.0
+
+            [borderRadius]
+            DEFAULT = 6.0
+
+            [colors]
+            accent = "#f97316"
+
+            [colors.brand]
+            500 = "#6366f1"
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(config.spacing("18"), Some(72.0));
+        assert_eq!(config.radius("DEFAULT"), Some(6.0));
+        assert_eq!(
+            config.color("accent"),
+            Some(Color::from_rgb8(0xf9, 0x73, 0x16))
+        );
+        assert_eq!(
+            config.color("brand-500"),
+            Some(Color::from_rgb8(0x63, 0x66, 0xf1))
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_export_tailwind_config() {
+        use crate::theme::{self, PaletteVersion};
+
+        let mut config = TailwindConfig::default();
+        config.spacing.insert("18".to_string(), 72.0);
+        config.border_radius.insert("DEFAULT".to_string(), 6.0);
+        config.colors.insert(
+            "accent".to_string(),
+            ColorValue::Single("#f97316".to_string()),
+        );
+        config.colors.insert(
+            "blue".to_string(),
+            ColorValue::Shades(BTreeMap::from([("500".to_string(), "#2563eb".to_string())])),
+        );
+
+        let export = |version| {
+            let json = theme::with_palette_version(version, || config.export_tailwind_config());
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()
+        };
+        let v3 = export(PaletteVersion::V3);
+        let theme = &v3["theme"];
+        assert_eq!(theme["spacing"]["4"], "16px");
+        assert_eq!(theme["spacing"]["18"], "72px");
+        assert_eq!(theme["spacing"]["0.5"], "2px");
+        assert_eq!(theme["screens"]["md"], "768px");
+        assert_eq!(theme["borderRadius"]["DEFAULT"], "6px");
+        assert_eq!(theme["borderRadius"]["lg"], "8px");
+        assert_eq!(theme["fontSize"]["sm"], "14px");
+        assert_eq!(theme["colors"]["white"], "#ffffff");
+        assert_eq!(theme["colors"]["accent"], "#f97316");
+        assert_eq!(theme["colors"]["blue"]["500"], "#2563eb");
+        assert_eq!(theme["colors"]["gray"]["500"], "#6b7280");
+
+        let v4 = export(PaletteVersion::V4);
+        assert_eq!(v4["theme"]["colors"]["gray"]["500"], "#6a7282");
+    }
+}
@@ -0,0 +1,232 @@
+//! High-contrast mode
+//!
+//! In high-contrast mode, text colors are adjusted to reach WCAG AAA contrast
+//! (7:1) against the background, and border colors to reach 3:1. Palette
+//! colors move along their own family (`text-gray-500` becomes
+//! `text-gray-600` on white) so a design keeps its hues; when the text color
+//! has no darker or lighter shade that reaches the target, the background
+//! shade moves instead, and as a last resort the text becomes black or white.
+//! Views without a background are checked against white, or against
+//! [`DARK_SURFACE`] in [dark mode](crate::theme::is_dark). Borders thinner
+//! than [`BORDER_WIDTH`] are thickened to it.
+//!
+//! Utilities written with the `contrast-more:` prefix, like Tailwind's
+//! `contrast-more:` variant, apply only in high-contrast mode:
+//! `"border contrast-more:border-gray-600"`.
+//!
+//! Floem doesn't report the OS contrast preference, so the mode is set by
+//! the app:
+//!
+//! ```rust
+//! floem_tailwind::contrast::set_high_contrast(true);
+//! ```
+//!
+//! The mode is kept in a signal, like the [`theme`](crate::theme), so styles
+//! that read it rerun when it is set.
+//!
+//! [`TailwindViewExt`](crate::TailwindViewExt) methods, the
+//! [`tw`](crate::tw) preset classes, and [`presets`](crate::presets) follow
+//! it; styles built by hand can use [`high_contrast`] and
+//! [`contrast_more`](crate::TailwindExt::contrast_more).
+
+use floem::reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+use floem::style::{self as fs, StrokeWrap, Style, StyleProp, StyleValue};
+use peniko::{Brush, Color};
+
+use crate::colors::{self, SHADES, palette_shade};
+use crate::theme;
+
+/// Minimum contrast ratio for text, WCAG AAA
+pub const TEXT_RATIO: f64 = 7.0;
+/// Minimum contrast ratio for borders, WCAG non-text contrast
+pub const BORDER_RATIO: f64 = 3.0;
+/// Minimum width of borders, in pixels
+pub const BORDER_WIDTH: f64 = 2.0;
+/// Surface assumed behind views without a background in dark mode
+pub const DARK_SURFACE: Color = colors::gray::C900;
+
+thread_local! {
+    /// Created in its own root scope so no view's disposal drops it
+    static HIGH_CONTRAST: RwSignal<bool> = Scope::new().create_rw_signal(false);
+}
+
+/// Turns high-contrast mode on or off
+pub fn set_high_contrast(enabled: bool) {
+    HIGH_CONTRAST.with(|signal| signal.set(enabled));
+}
+
+/// Whether high-contrast mode is on, subscribing the caller to changes
+pub fn is_high_contrast() -> bool {
+    HIGH_CONTRAST.with(|signal| signal.get())
+}
+
+/// Applies [`high_contrast`] if high-contrast mode is on
+pub(crate) fn apply(s: Style) -> Style {
+    if is_high_contrast() {
+        high_contrast(s)
+    } else {
+        s
+    }
+}
+
+/// WCAG relative luminance of a color, ignoring alpha
+pub fn luminance(color: Color) -> f64 {
+    let rgba = color.to_rgba8();
+    let channel = |v: u8| {
+        let v = v as f64 / 255.0;
+        if v <= 0.04045 {
+            v / 12.92
+        } else {
+            ((v + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(rgba.r) + 0.7152 * channel(rgba.g) + 0.0722 * channel(rgba.b)
+}
+
+/// WCAG contrast ratio between two colors, from 1 to 21
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The nearest shade of `color`'s family, away from `against`, that reaches `ratio`
+fn shift(color: Color, against: Color, ratio: f64) -> Option<Color> {
+    let (family, i) = palette_shade(color)?;
+    let shade = |i: usize| family.shade(SHADES[i]);
+    let passes = |c: &Color| contrast_ratio(*c, against) >= ratio;
+    if luminance(color) < luminance(against) {
+        (i..SHADES.len()).filter_map(shade).find(passes)
+    } else {
+        (0..=i).rev().filter_map(shade).find(passes)
+    }
+}
+
+fn value<P: StyleProp>(s: &Style, prop: P) -> Option<P::Type> {
+    match s.get_style_value(prop) {
+        StyleValue::Val(value) | StyleValue::Animated(value) => Some(value),
+        StyleValue::Unset | StyleValue::Base => None,
+    }
+}
+
+fn solid(brush: Option<Brush>) -> Option<Color> {
+    match brush? {
+        Brush::Solid(color) => Some(color),
+        _ => None,
+    }
+}
+
+fn raise_border<P: StyleProp<Type = Option<Brush>>>(s: Style, prop: P, surface: Color) -> Style {
+    let Some(border) = solid(value(&s, prop).flatten()) else {
+        return s;
+    };
+    if contrast_ratio(border, surface) >= BORDER_RATIO {
+        return s;
+    }
+    match shift(border, surface, BORDER_RATIO) {
+        Some(border) => s.set(prop, Some(Brush::Solid(border))),
+        None => s,
+    }
+}
+
+fn thicken_border<P: StyleProp<Type = StrokeWrap>>(s: Style, prop: P) -> Style {
+    match value(&s, prop) {
+        Some(mut stroke) if stroke.0.width > 0.0 && stroke.0.width < BORDER_WIDTH => {
+            stroke.0.width = BORDER_WIDTH;
+            s.set(prop, stroke)
+        }
+        _ => s,
+    }
+}
+
+/// Raises the text and border contrast of `s` to [`TEXT_RATIO`] and
+/// [`BORDER_RATIO`], and its borders to [`BORDER_WIDTH`]
+pub fn high_contrast(s: Style) -> Style {
+    let background = solid(value(&s, fs::Background).flatten());
+    let mut surface = background.unwrap_or_else(|| {
+        if theme::is_dark() {
+            DARK_SURFACE
+        } else {
+            colors::WHITE
+        }
+    });
+    let mut s = s;
+
+    let low_contrast_text = value(&s, fs::TextColor)
+        .flatten()
+        .filter(|&text| contrast_ratio(text, surface) < TEXT_RATIO);
+    if let Some(text) = low_contrast_text {
+        if let Some(text) = shift(text, surface, TEXT_RATIO) {
+            s = s.color(text);
+        } else if let Some(bg) = background.and_then(|bg| shift(bg, text, TEXT_RATIO)) {
+            surface = bg;
+            s = s.background(bg);
+        } else {
+            let black = contrast_ratio(colors::BLACK, surface);
+            let white = contrast_ratio(colors::WHITE, surface);
+            s = s.color(if black >= white {
+                colors::BLACK
+            } else {
+                colors::WHITE
+            });
+        }
+    }
+
+    let s = raise_border(s, fs::BorderTopColor, surface);
+    let s = raise_border(s, fs::BorderRightColor, surface);
+    let s = raise_border(s, fs::BorderBottomColor, surface);
+    let s = raise_border(s, fs::BorderLeftColor, surface);
+
+    let s = thicken_border(s, fs::BorderTop);
+    let s = thicken_border(s, fs::BorderRight);
+    let s = thicken_border(s, fs::BorderBottom);
+    thicken_border(s, fs::BorderLeft)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::class::parse_classes;
+    use crate::inspect::to_class_string;
+
+    fn high_contrast_classes(classes: &str) -> String {
+        let s = parse_classes(classes)
+            .unwrap()
+            .iter()
+            .fold(Style::new(), |s, class| class.apply(s));
+        to_class_string(&high_contrast(s))
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio(colors::BLACK, colors::WHITE) - 21.0).abs() < 1e-9);
+        assert_eq!(contrast_ratio(colors::WHITE, colors::WHITE), 1.0);
+    }
+
+    #[test]
+    fn test_high_contrast() {
+        assert_eq!(
+            high_contrast_classes("text-gray-500 border border-gray-300"),
+            "border-2 border-gray-500 text-gray-600"
+        );
+        assert_eq!(
+            high_contrast_classes("bg-blue-500 text-white"),
+            "bg-blue-800 text-white"
+        );
+        assert_eq!(
+            high_contrast_classes("bg-gray-900 text-gray-100"),
+            "bg-gray-900 text-gray-100"
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_dark_surface() {
+        use crate::theme::{Theme, with_theme};
+
+        let dark = |classes: &str| with_theme(Theme::Dark, || high_contrast_classes(classes));
+        assert_eq!(dark("text-gray-100"), "text-gray-100");
+        assert_eq!(
+            dark("text-gray-500 border border-gray-700"),
+            "border-2 border-gray-500 text-gray-300"
+        );
+    }
+}
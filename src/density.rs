@@ -0,0 +1,148 @@
+//! Density scaling for compact and comfortable layouts
+//!
+//! A density factor multiplies the pixel values of the spacing, size, and
+//! radius scales, so one set of utilities serves both a compact and a
+//! comfortable layout.
+//!
+//! The factor is set globally:
+//!
+//! ```rust
+//! floem_tailwind::density::set_global(floem_tailwind::density::COMPACT);
+//! ```
+//!
+//! or per subtree with a signal, which restyles the subtree when it changes:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::prelude::*;
+//! use floem_tailwind::density;
+//!
+//! let factor = RwSignal::new(density::COMFORTABLE);
+//! density::provide(factor.read_only());
+//! // Views created from here on in this scope follow `factor`
+//! let toolbar = h_stack((button("Open"), button("Save"))).tw("flex gap-2 p-2");
+//! factor.set(density::COMPACT);
+//! ```
+//!
+//! The factor is applied where the utilities compute their pixel values,
+//! before [`dpi`](crate::dpi) snapping, so it covers every length of the
+//! spacing and size scales (`p_4()`, `p(Spacing::S4)`, `gap_units(3.0)`,
+//! `w_64()`, ...) and the radius scale in any style closure. Pixel values
+//! written by hand, percentages, `auto`, and font sizes are left alone.
+//!
+//! The global factor is kept in a signal, so styles that use the scales
+//! rerun when it's set. [`TailwindViewExt`](crate::TailwindViewExt) methods
+//! follow the subtree's provided signal; style closures written by hand can
+//! use [`with_factor`].
+
+use std::cell::Cell;
+
+use floem::reactive::{
+    ReadSignal, RwSignal, Scope, SignalGet, SignalUpdate, provide_context, use_context,
+};
+
+/// Density factor for compact desktop layouts
+pub const COMPACT: f64 = 0.875;
+/// Density factor of the unscaled Tailwind scale
+pub const COMFORTABLE: f64 = 1.0;
+
+thread_local! {
+    /// Created in its own root scope so no view's disposal drops it
+    static GLOBAL: RwSignal<f64> = Scope::new().create_rw_signal(COMFORTABLE);
+    /// The factor of the [`with_factor`] call being run, if any
+    static SCOPED: Cell<Option<f64>> = const { Cell::new(None) };
+}
+
+/// Sets the density factor for views without a [`provide`]d one
+///
+/// Styles that use the scales rerun with the new factor.
+pub fn set_global(factor: f64) {
+    GLOBAL.with(|signal| signal.set(factor));
+}
+
+/// The global density factor, subscribing the caller to changes
+pub fn global() -> f64 {
+    GLOBAL.with(|signal| signal.get())
+}
+
+/// The density factor in effect, subscribing the caller to changes: the
+/// [`with_factor`] factor, or the global one
+pub fn factor() -> f64 {
+    SCOPED.get().unwrap_or_else(global)
+}
+
+/// Runs `f` with `factor` in effect, e.g. to build a style for a subtree
+pub fn with_factor<R>(factor: f64, f: impl FnOnce() -> R) -> R {
+    /// Restores the outer factor, even if `f` panics
+    struct Restore(Option<f64>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.set(self.0);
+        }
+    }
+    let _restore = Restore(SCOPED.replace(Some(factor)));
+    f()
+}
+
+/// Scales a pixel value of the scales by the factor in effect
+pub fn px(px: f64) -> f64 {
+    px * factor()
+}
+
+/// The density signal provided to a scope
+#[derive(Clone, Copy)]
+struct Provided(ReadSignal<f64>);
+
+/// Sets the density factor for views created in the current reactive scope
+/// and its children
+pub fn provide(factor: ReadSignal<f64>) {
+    provide_context(Provided(factor));
+}
+
+/// The density in effect where a view is created
+#[derive(Clone, Copy)]
+pub(crate) struct Source(Option<ReadSignal<f64>>);
+
+impl Source {
+    /// Captures the provided signal, if any, of the current scope
+    pub(crate) fn current() -> Self {
+        Source(use_context::<Provided>().map(|provided| provided.0))
+    }
+
+    /// Runs `f` with the provided factor in effect, subscribing to its signal
+    pub(crate) fn with<R>(self, f: impl FnOnce() -> R) -> R {
+        match self.0 {
+            Some(factor) => with_factor(factor.get(), f),
+            None => f(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floem::style::{self as fs, Style};
+    use floem::unit::{PxPct, PxPctAuto};
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::{Spacing, spacing};
+
+    #[test]
+    fn test_factor_scales_utilities() {
+        let s = with_factor(0.5, || {
+            Style::new()
+                .p_4()
+                .gap(Spacing::S8)
+                .rounded_lg()
+                .mx_auto()
+                .padding_left(10.0)
+        });
+        assert_eq!(s.get(fs::PaddingTop), PxPct::Px(8.0));
+        assert_eq!(s.get(fs::PaddingLeft), PxPct::Px(10.0));
+        assert_eq!(s.get(fs::ColGap), PxPct::Px(16.0));
+        assert_eq!(s.get(fs::BorderTopLeftRadius), PxPct::Px(4.0));
+        assert_eq!(s.get(fs::MarginLeft), PxPctAuto::Auto);
+        assert_eq!(with_factor(0.5, || spacing::units(4.0)), 8.0);
+        assert_eq!(SCOPED.get(), None);
+    }
+}
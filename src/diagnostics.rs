@@ -0,0 +1,88 @@
+//! Warnings for conflicting utilities (`diagnostics` feature)
+//!
+//! In debug builds, the utility methods and `apply_tw_class` check the style
+//! they are applied to and report a [`warning`](crate::warning) when they
+//! override a utility that is already set, as in `s.w_4().w_full()` or
+//! `s.flex_row().flex_col()`:
+//!
+//! ```text
+//! floem-tailwind: `w-full` overrides `w-4`
+//! ```
+//!
+//! [`TailwindViewExt::tw`](crate::TailwindViewExt::tw) class strings are
+//! checked with [`merge::conflicts`](crate::merge::conflicts) as they are
+//! parsed. Release builds skip the checks.
+//!
+//! The existing utilities are recovered with [`inspect::classes_of`], so a
+//! conflict is reported against the class closest to the current value.
+
+use floem::style::Style;
+
+use crate::class::TwClass;
+use crate::inspect;
+use crate::merge::{self, Conflict};
+
+/// Conflicts between the utilities set on `s` and `class` applied on top
+pub fn style_conflicts(s: &Style, class: TwClass) -> Vec<Conflict> {
+    inspect::classes_of(s)
+        .into_iter()
+        .filter(|&earlier| earlier != class && merge::overrides(earlier, class))
+        .map(|earlier| Conflict {
+            overridden: earlier.to_string(),
+            by: class.to_string(),
+        })
+        .collect()
+}
+
+/// Reports a warning for each conflict
+fn warn(conflicts: Vec<Conflict>) {
+    for conflict in conflicts {
+        crate::warning::warn(conflict);
+    }
+}
+
+/// Warns if `class` overrides a utility already set on `s`
+pub(crate) fn check(s: &Style, class: TwClass) {
+    if cfg!(debug_assertions) {
+        warn(style_conflicts(s, class));
+    }
+}
+
+/// Warns if a generated utility method overrides a utility already set on `s`
+pub(crate) fn check_name(s: &Style, class: &str) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    if let Ok(class) = class.parse() {
+        warn(style_conflicts(s, class));
+    }
+}
+
+/// Warns about conflicts within a class string
+pub(crate) fn check_classes(classes: &str) {
+    if cfg!(debug_assertions) {
+        warn(merge::conflicts(classes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::class::parse_classes;
+
+    #[test]
+    fn test_style_conflicts() {
+        let s = parse_classes("w-4 flex-row pt-2")
+            .unwrap()
+            .iter()
+            .fold(Style::new(), |s, class| class.apply(s));
+        let found = style_conflicts(&s, "w-full".parse().unwrap());
+        assert_eq!(found[0].to_string(), "`w-full` overrides `w-4`");
+        assert_eq!(
+            style_conflicts(&s, "flex-col".parse().unwrap())[0].overridden,
+            "flex-row"
+        );
+        assert_eq!(style_conflicts(&s, "p-4".parse().unwrap()).len(), 1);
+        assert!(style_conflicts(&s, "h-4".parse().unwrap()).is_empty());
+    }
+}
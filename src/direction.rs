@@ -0,0 +1,223 @@
+//! Layout direction
+//!
+//! Logical utilities such as `ps-4`, `me-auto`, and `rounded-s-lg` name the
+//! start and end of a line instead of its left and right. They resolve against
+//! the layout direction when the style is computed: start is left in
+//! left-to-right layouts (the default) and right in right-to-left ones.
+//! `flex-row` and `flex-row-reverse` follow it too, so rows start on the
+//! right in right-to-left layouts, and [`rtl`](crate::TailwindExt::rtl)
+//! styles apply only there.
+//!
+//! The direction is set globally, before views are built:
+//!
+//! ```rust
+//! use floem_tailwind::direction::{self, Direction};
+//!
+//! direction::set_direction(Direction::Rtl);
+//! ```
+//!
+//! or per subtree with a signal, which restyles the subtree when it changes:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::direction::{self, Direction};
+//! use floem_tailwind::prelude::*;
+//!
+//! let dir = RwSignal::new(Direction::Rtl);
+//! direction::provide(dir.read_only());
+//! // Views created from here on in this scope follow `dir`
+//! let field = h_stack((label(|| "Name"), button("Edit"))).tw("flex flex-row gap-2 ps-4");
+//! ```
+//!
+//! [`TailwindViewExt`](crate::TailwindViewExt) methods follow the provided
+//! direction; style closures written by hand can use [`with_direction`].
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use floem::reactive::{ReadSignal, SignalGet, provide_context, use_context};
+use floem::style::{FlexDirection, Style};
+use floem::unit::{PxPct, PxPctAuto};
+
+/// The direction text and inline content flow in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Left to right
+    #[default]
+    Ltr,
+    /// Right to left, as in Arabic and Hebrew
+    Rtl,
+}
+
+impl Direction {
+    /// Whether start is on the right
+    pub const fn is_rtl(self) -> bool {
+        matches!(self, Direction::Rtl)
+    }
+}
+
+static RTL: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// The direction of the [`with_direction`] call being run, if any
+    static SCOPED: Cell<Option<Direction>> = const { Cell::new(None) };
+}
+
+/// Sets the layout direction for views without a [`provide`]d one
+///
+/// Styles read the direction when they are computed, so set it before
+/// building views; use a signal to switch direction at runtime.
+pub fn set_direction(direction: Direction) {
+    RTL.store(direction.is_rtl(), Ordering::Relaxed);
+}
+
+/// The global layout direction
+pub fn global() -> Direction {
+    if RTL.load(Ordering::Relaxed) {
+        Direction::Rtl
+    } else {
+        Direction::Ltr
+    }
+}
+
+/// The layout direction in effect: the [`with_direction`] one, or the global one
+pub fn direction() -> Direction {
+    SCOPED.get().unwrap_or_else(global)
+}
+
+/// Runs `f` with `direction` in effect, e.g. to build a style for a subtree
+pub fn with_direction<R>(direction: Direction, f: impl FnOnce() -> R) -> R {
+    /// Restores the outer direction, even if `f` panics
+    struct Restore(Option<Direction>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.set(self.0);
+        }
+    }
+    let _restore = Restore(SCOPED.replace(Some(direction)));
+    f()
+}
+
+/// The direction signal provided to a scope
+#[derive(Clone, Copy)]
+struct Provided(ReadSignal<Direction>);
+
+/// Sets the layout direction for views created in the current reactive scope
+/// and its children
+pub fn provide(direction: ReadSignal<Direction>) {
+    provide_context(Provided(direction));
+}
+
+/// The direction in effect where a view is created
+#[derive(Clone, Copy)]
+pub(crate) struct Source(Option<ReadSignal<Direction>>);
+
+impl Source {
+    /// Captures the provided signal, if any, of the current scope
+    pub(crate) fn current() -> Self {
+        Source(use_context::<Provided>().map(|provided| provided.0))
+    }
+
+    /// The direction, subscribing to the provided signal
+    pub(crate) fn direction(self) -> Direction {
+        self.0.map_or_else(direction, |direction| direction.get())
+    }
+}
+
+/// The flex direction of `flex-row` or, if `reverse`, `flex-row-reverse`
+///
+/// Taffy rows always run left to right, so right-to-left rows are reversed.
+pub(crate) fn row(reverse: bool) -> FlexDirection {
+    if reverse != direction().is_rtl() {
+        FlexDirection::RowReverse
+    } else {
+        FlexDirection::Row
+    }
+}
+
+/// Pads the left or, if `right`, the right side
+fn padding_side(right: bool, padding: PxPct) -> Style {
+    if right {
+        Style::new().padding_right(padding)
+    } else {
+        Style::new().padding_left(padding)
+    }
+}
+
+/// Pads the start side (`ps-*`)
+pub(crate) fn padding_start(padding: impl Into<PxPct>) -> Style {
+    padding_side(direction().is_rtl(), padding.into())
+}
+
+/// Pads the end side (`pe-*`)
+pub(crate) fn padding_end(padding: impl Into<PxPct>) -> Style {
+    padding_side(!direction().is_rtl(), padding.into())
+}
+
+/// Sets the margin on the left or, if `right`, the right side
+fn margin_side(right: bool, margin: PxPctAuto) -> Style {
+    if right {
+        Style::new().margin_right(margin)
+    } else {
+        Style::new().margin_left(margin)
+    }
+}
+
+/// Sets the start margin (`ms-*`)
+pub(crate) fn margin_start(margin: impl Into<PxPctAuto>) -> Style {
+    margin_side(direction().is_rtl(), margin.into())
+}
+
+/// Sets the end margin (`me-*`)
+pub(crate) fn margin_end(margin: impl Into<PxPctAuto>) -> Style {
+    margin_side(!direction().is_rtl(), margin.into())
+}
+
+/// Rounds the two corners on the left or, if `right`, the right
+fn rounded_side(right: bool, radius: f64) -> Style {
+    if right {
+        Style::new()
+            .border_top_right_radius(radius)
+            .border_bottom_right_radius(radius)
+    } else {
+        Style::new()
+            .border_top_left_radius(radius)
+            .border_bottom_left_radius(radius)
+    }
+}
+
+/// Rounds the two start corners (`rounded-s-*`)
+pub(crate) fn rounded_start(radius: f64) -> Style {
+    rounded_side(direction().is_rtl(), radius)
+}
+
+/// Rounds the two end corners (`rounded-e-*`)
+pub(crate) fn rounded_end(radius: f64) -> Style {
+    rounded_side(!direction().is_rtl(), radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use floem::style as fs;
+
+    use super::*;
+    use crate::class::parse_classes;
+    use crate::inspect::to_class_string;
+
+    #[test]
+    fn test_with_direction() {
+        let classes = parse_classes("flex-row ps-4 me-2").unwrap();
+        let style = || classes.iter().fold(Style::new(), |s, class| class.apply(s));
+        let rtl = with_direction(Direction::Rtl, style);
+        assert_eq!(rtl.get(fs::FlexDirectionProp), FlexDirection::RowReverse);
+        assert_eq!(rtl.get(fs::PaddingRight), PxPct::Px(16.0));
+        assert_eq!(rtl.get(fs::MarginLeft), PxPctAuto::Px(8.0));
+        assert_eq!(
+            with_direction(Direction::Rtl, || to_class_string(&rtl)),
+            "pr-4 ml-2 flex-row"
+        );
+        assert_eq!(direction(), global());
+        assert_eq!(style().get(fs::PaddingLeft), PxPct::Px(16.0));
+    }
+}
@@ -0,0 +1,46 @@
+//! Device-pixel snapping for fractional scale factors
+//!
+//! At scale factors like 1.25 or 1.5, a 1px border or a 2px half step covers a
+//! fractional number of device pixels and renders blurred. With snapping on,
+//! the spacing, sizing, and border utilities round their pixel values to whole
+//! device pixels, keeping anything non-zero at least one device pixel wide:
+//!
+//! ```rust
+//! use floem_tailwind::dpi;
+//!
+//! dpi::set_scale_factor(Some(1.5));
+//! assert_eq!(dpi::snap(1.0) * 1.5, 2.0); // border: 2 device pixels
+//! assert_eq!(dpi::snap(2.0) * 1.5, 3.0); // p-0.5: 3 device pixels
+//! ```
+//!
+//! Pass the window's scale factor, including any zoom set with Floem's
+//! `set_window_scale`, and set it again when it changes. Snapping is off by
+//! default, and values pass through unchanged.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The scale factor as `f64` bits; 0 when snapping is off
+static SCALE_FACTOR: AtomicU64 = AtomicU64::new(0);
+
+/// Turns snapping on for a scale factor, or off with `None`
+pub fn set_scale_factor(factor: Option<f64>) {
+    let factor = factor.filter(|factor| *factor > 0.0).unwrap_or(0.0);
+    SCALE_FACTOR.store(factor.to_bits(), Ordering::Relaxed);
+}
+
+/// The scale factor utilities snap to, if snapping is on
+pub fn scale_factor() -> Option<f64> {
+    let factor = f64::from_bits(SCALE_FACTOR.load(Ordering::Relaxed));
+    (factor > 0.0).then_some(factor)
+}
+
+/// Rounds a logical pixel value to whole device pixels
+pub fn snap(px: f64) -> f64 {
+    match scale_factor() {
+        Some(factor) if px != 0.0 => {
+            let device = (px.abs() * factor).round().max(1.0);
+            (device / factor).copysign(px)
+        }
+        _ => px,
+    }
+}
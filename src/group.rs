@@ -0,0 +1,88 @@
+//! Groups of adjacent views
+//!
+//! Floem has no `:first-child` or `:last-child` selectors, so the items of a
+//! group style themselves from their index and the group's length: with
+//! [`first`](crate::TailwindExt::first) and [`last`](crate::TailwindExt::last),
+//! or with the styles here. [`attached`] joins buttons into one control, and
+//! [`divided`] separates the rows of a list:
+//!
+//! ```rust
+//! use floem::prelude::*;
+//! use floem_tailwind::{group, radius};
+//! use floem_tailwind::prelude::*;
+//!
+//! let views = ["Day", "Week", "Month"];
+//! let len = views.len();
+//! let picker = h_stack_from_iter(views.into_iter().enumerate().map(|(i, view)| {
+//!     button(view).style(move |s| {
+//!         s.px_3()
+//!             .py_2()
+//!             .border_1()
+//!             .border_gray_300()
+//!             .apply(group::attached(i, len, radius::ROUNDED_MD))
+//!     })
+//! }));
+//! ```
+
+use floem::style::Style;
+
+use crate::{TailwindExt, colors, contrast, direction, dpi};
+
+/// Item `index` of a row of `len` attached controls, such as a button group
+/// (rounded-none first:rounded-s last:rounded-e -ms-px)
+///
+/// Only the outer corners are rounded, by `radius`, and each item after the
+/// first overlaps the previous one's border so adjacent borders are drawn
+/// once. Start and end follow the [`direction`](crate::direction).
+pub fn attached(index: usize, len: usize, radius: f64) -> Style {
+    Style::new()
+        .border_radius(0.0)
+        .first(index, |s| s.apply(direction::rounded_start(radius)))
+        .last(index, len, |s| s.apply(direction::rounded_end(radius)))
+        .apply_if(index > 0, |s| {
+            s.apply(direction::margin_start(-dpi::snap(1.0)))
+        })
+}
+
+/// Item `index` of a column of `len` rows separated by dividers
+/// (divide-y divide-gray-200)
+///
+/// Each row but the last gets a bottom border; borders follow the
+/// [`contrast`](crate::contrast) mode.
+pub fn divided(index: usize, len: usize) -> Style {
+    let s = Style::new()
+        .border_bottom(dpi::snap(1.0))
+        .border_color(colors::gray::C200);
+    contrast::apply(s.last(index, len, |s| s.border_bottom(0.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use floem::style as fs;
+    use floem::unit::{PxPct, PxPctAuto};
+
+    use super::*;
+
+    #[test]
+    fn test_attached() {
+        let first = attached(0, 3, 6.0);
+        assert_eq!(first.get(fs::BorderTopLeftRadius), PxPct::Px(6.0));
+        assert_eq!(first.get(fs::BorderTopRightRadius), PxPct::Px(0.0));
+        assert_eq!(first.get(fs::MarginLeft), PxPctAuto::Px(0.0));
+
+        let middle = attached(1, 3, 6.0);
+        assert_eq!(middle.get(fs::BorderBottomLeftRadius), PxPct::Px(0.0));
+        assert_eq!(middle.get(fs::BorderBottomRightRadius), PxPct::Px(0.0));
+        assert_eq!(middle.get(fs::MarginLeft), PxPctAuto::Px(-1.0));
+
+        let last = attached(2, 3, 6.0);
+        assert_eq!(last.get(fs::BorderBottomRightRadius), PxPct::Px(6.0));
+        assert_eq!(last.get(fs::BorderBottomLeftRadius), PxPct::Px(0.0));
+    }
+
+    #[test]
+    fn test_divided() {
+        assert_eq!(divided(0, 2).get(fs::BorderBottom).0.width, 1.0);
+        assert_eq!(divided(1, 2).get(fs::BorderBottom).0.width, 0.0);
+    }
+}
@@ -0,0 +1,114 @@
+//! Live-reloading stylesheets for development
+//!
+//! [`HotStyles`] loads a [`StyleSheet`] from a TOML file:
+//!
+//! ```toml
+//! button = "px-4 py-2 rounded-md bg-blue-500 text-white"
+//! card = "p-6 rounded-lg shadow-md bg-white"
+//! ```
+//!
+//! In debug builds [`HotStyles::watch`] polls the file and reparses it on
+//! change; style closures that read from it re-run with the new classes, so
+//! tweaks show up without recompiling. Release builds load the file once.
+//!
+//! `wasm32` targets have no threads to poll from, so they load the file once
+//! too. On the web, which has no file system, [`HotStyles::watch`] fails with
+//! [`StyleSheetError::Io`]; parse a fetched sheet with
+//! [`StyleSheet::from_toml`] instead.
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::hot_reload::HotStyles;
+//!
+//! let styles = HotStyles::watch("styles.toml").unwrap();
+//! let errors = styles.clone();
+//! let banner = label(move || errors.error().map(|error| error.to_string()).unwrap_or_default());
+//! let button = button("Save").style(move |_| styles.style("button"));
+//! ```
+//!
+//! A reload that fails keeps the previous sheet active, and its error is
+//! available from [`HotStyles::error`] until the file loads again.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use floem::reactive::{ReadSignal, SignalWith};
+use floem::style::Style;
+
+use crate::stylesheet::{StyleSheet, StyleSheetError};
+
+/// How often the watcher checks the file's modification time
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A stylesheet that follows its file in debug builds
+#[derive(Clone)]
+pub struct HotStyles {
+    initial: Arc<StyleSheet>,
+    latest: ReadSignal<Option<Arc<StyleSheet>>>,
+    error: ReadSignal<Option<Option<Arc<StyleSheetError>>>>,
+}
+
+impl HotStyles {
+    /// Loads a stylesheet and, in native debug builds, watches it for changes
+    ///
+    /// Reloads that fail keep the previous sheet active and set
+    /// [`error`](Self::error).
+    pub fn watch(path: impl Into<PathBuf>) -> Result<Self, StyleSheetError> {
+        let path = path.into();
+        let initial = Arc::new(StyleSheet::load(&path)?);
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let (error_tx, error_rx) = crossbeam_channel::unbounded();
+        if cfg!(all(debug_assertions, not(target_arch = "wasm32"))) {
+            std::thread::spawn(move || poll(&path, tx, error_tx));
+        }
+        Ok(HotStyles {
+            initial,
+            latest: floem::ext_event::create_signal_from_channel(rx),
+            error: floem::ext_event::create_signal_from_channel(error_rx),
+        })
+    }
+
+    /// The error of the last reload, `None` once the file loads again,
+    /// subscribing the caller to reloads
+    pub fn error(&self) -> Option<Arc<StyleSheetError>> {
+        self.error.with(|error| error.clone().flatten())
+    }
+
+    /// Reads the current sheet, subscribing the caller to reloads
+    pub fn with<O>(&self, f: impl FnOnce(&StyleSheet) -> O) -> O {
+        self.latest
+            .with(|latest| f(latest.as_deref().unwrap_or(&self.initial)))
+    }
+
+    /// Style for a named entry, re-evaluated when the file changes
+    pub fn style(&self, name: &str) -> Style {
+        self.with(|sheet| sheet.style_of(name))
+    }
+}
+
+/// Reparses the file whenever its modification time changes, sending the
+/// sheet or the error
+fn poll(
+    path: &Path,
+    tx: crossbeam_channel::Sender<Arc<StyleSheet>>,
+    error_tx: crossbeam_channel::Sender<Option<Arc<StyleSheetError>>>,
+) {
+    let modified = |path: &Path| -> Option<SystemTime> { path.metadata().ok()?.modified().ok() };
+    let mut last = modified(path);
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current = modified(path);
+        if current == last {
+            continue;
+        }
+        last = current;
+        let sent = match StyleSheet::load(path) {
+            Ok(sheet) => tx.send(Arc::new(sheet)).is_ok() && error_tx.send(None).is_ok(),
+            Err(error) => error_tx.send(Some(Arc::new(error))).is_ok(),
+        };
+        if !sent {
+            return;
+        }
+    }
+}
@@ -0,0 +1,846 @@
+//! Reverse mapping from a `Style` to utility classes
+//!
+//! [`classes_of`] reads the properties set on a style and picks the closest
+//! utility for each, which helps when migrating hand-written styles to class
+//! strings and gives tests a readable snapshot of a style:
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::colors;
+//! use floem_tailwind::inspect::to_class_string;
+//!
+//! let style = Style::new()
+//!     .width(256.0)
+//!     .padding(17.0)
+//!     .border_radius(8.0)
+//!     .background(colors::blue::C500);
+//! assert_eq!(to_class_string(&style), "w-64 p-4 rounded-lg bg-blue-500");
+//! ```
+//!
+//! The result is approximate: lengths snap to the nearest step of the scale
+//! and colors to the nearest palette color. Properties without a matching
+//! utility (per-corner radii, `row_gap` ≠ `col_gap`, gradients, ...) and
+//! selector styles like `hover` are skipped.
+//!
+//! [`diff_styles`] compares two styles the same way, as class changes, to
+//! see why a hover state or theme switch didn't change what was expected:
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::prelude::*;
+//! use floem_tailwind::inspect::diff_styles;
+//!
+//! let idle = Style::new().p_4().bg_blue_500().rounded();
+//! let hovered = Style::new().p_4().bg_blue_600().shadow_md();
+//! assert_eq!(
+//!     diff_styles(&idle, &hovered).to_string(),
+//!     "- rounded\n~ bg-blue-500 → bg-blue-600\n+ shadow-md\n"
+//! );
+//! ```
+//!
+//! ## Views
+//!
+//! With [`set_recording`] on, views styled with
+//! [`tw`](crate::TailwindViewExt::tw) remember their class string, and views
+//! styled with [`tw_style`](crate::TailwindViewExt::tw_style) the class string
+//! of their style. An inspector looks them up by view id with
+//! [`view_classes`], or shows them with the bundled [`class_overlay`]:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::inspect;
+//!
+//! inspect::set_recording(cfg!(debug_assertions));
+//! let selected = RwSignal::new(None);
+//! let overlay = inspect::class_overlay(move || selected.get());
+//! ```
+//!
+//! Only views created while recording is on are recorded, and entries are
+//! kept until [`clear_view_classes`].
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use floem::ViewId;
+use floem::style::{self as fs, Style, StyleProp, StyleValue};
+use floem::views::{Decorators, Label, label};
+use floem::unit::{PxPct, PxPctAuto};
+use peniko::{Brush, Color};
+
+use crate::class::{
+    Align, ColorKeyword, Cursor, Display, DropShadow, Edge, FixedLeading, FlexDirection, FlexWrap,
+    FontStretch, FontWeight, Justify, Leading, Length, NamedSize, Overflow, Position, Radius,
+    Shadow, TextSize, TwClass,
+};
+use crate::colors::{self, Family, SHADES};
+use crate::direction;
+use crate::merge;
+use crate::spacing::Spacing;
+
+/// Fractions with utility methods, used to snap percentages
+const FRACTIONS: &[(u8, u8)] = &[
+    (1, 2),
+    (1, 3),
+    (2, 3),
+    (1, 4),
+    (3, 4),
+    (1, 5),
+    (2, 5),
+    (3, 5),
+    (4, 5),
+    (1, 6),
+    (5, 6),
+    (1, 12),
+];
+
+/// Border widths with utility methods
+const BORDER_WIDTHS: &[u8] = &[0, 1, 2, 4, 8];
+
+/// The value of a prop, if it is set on the style itself
+fn value<P: StyleProp>(s: &Style, prop: P) -> Option<P::Type> {
+    match s.get_style_value(prop) {
+        StyleValue::Val(value) | StyleValue::Animated(value) => Some(value),
+        StyleValue::Unset | StyleValue::Base => None,
+    }
+}
+
+/// The candidate whose key is closest to `target`; ties go to the first
+fn nearest<T: Copy>(target: f64, candidates: impl IntoIterator<Item = (f64, T)>) -> Option<T> {
+    candidates
+        .into_iter()
+        .min_by(|(a, _), (b, _)| (a - target).abs().total_cmp(&(b - target).abs()))
+        .map(|(_, value)| value)
+}
+
+/// The closest spacing step to a pixel value
+fn spacing(px: f64) -> Spacing {
+    nearest(px, Spacing::ALL.map(|step| (step.scaled_px(), step))).unwrap_or(Spacing::S0)
+}
+
+/// The closest spacing step to a unit; percentages have no spacing utility
+fn spacing_of(unit: PxPct) -> Option<Spacing> {
+    match unit {
+        PxPct::Px(px) => Some(spacing(px)),
+        PxPct::Pct(_) => None,
+    }
+}
+
+/// The closest length to a pixel value, from the spacing scale and named sizes
+fn length_px(px: f64) -> Length {
+    let steps = Spacing::ALL.map(|step| (step.scaled_px(), Length::Spacing(step)));
+    let named = NamedSize::ALL
+        .iter()
+        .map(|&size| (size.scaled_px(), Length::Named(size)));
+    nearest(px, steps.into_iter().chain(named)).unwrap_or(Length::Spacing(Spacing::S0))
+}
+
+/// The closest length to a percentage, from the fractions and `full`
+fn length_pct(pct: f64) -> Length {
+    let fractions = FRACTIONS
+        .iter()
+        .map(|&(num, den)| (num as f64 / den as f64 * 100.0, Length::Fraction(num, den)));
+    let ends = [(0.0, Length::Spacing(Spacing::S0)), (100.0, Length::Full)];
+    nearest(pct, fractions.chain(ends)).unwrap_or(Length::Full)
+}
+
+/// The closest padding length to a unit: a spacing step or a percentage
+fn padding_length(unit: PxPct) -> Length {
+    match unit {
+        PxPct::Px(px) => Length::Spacing(spacing(px)),
+        PxPct::Pct(pct) => length_pct(pct),
+    }
+}
+
+/// The closest length to a unit
+fn length(unit: PxPctAuto) -> Length {
+    match unit {
+        PxPctAuto::Px(px) => length_px(px),
+        PxPctAuto::Pct(pct) => length_pct(pct),
+        PxPctAuto::Auto => Length::Auto,
+    }
+}
+
+/// A length and whether it is negative, for translate and inset utilities
+fn signed_length(unit: PxPct) -> (bool, Length) {
+    match unit {
+        PxPct::Px(px) => (px < 0.0, length_px(px.abs())),
+        PxPct::Pct(pct) => (pct < 0.0, length_pct(pct.abs())),
+    }
+}
+
+/// An inset length and whether it is negative
+fn signed_inset(unit: PxPctAuto) -> (bool, Length) {
+    match unit {
+        PxPctAuto::Px(px) => signed_length(PxPct::Px(px)),
+        PxPctAuto::Pct(pct) => signed_length(PxPct::Pct(pct)),
+        PxPctAuto::Auto => (false, Length::Auto),
+    }
+}
+
+/// A color utility value: a keyword or a palette shade
+#[derive(Clone, Copy)]
+enum PaletteColor {
+    Keyword(ColorKeyword),
+    Shade(Family, u16),
+}
+
+/// The closest palette color, by RGB distance; mostly transparent colors are `transparent`
+fn palette_color(color: Color) -> PaletteColor {
+    let rgba = color.to_rgba8();
+    if rgba.a < 128 {
+        return PaletteColor::Keyword(ColorKeyword::Transparent);
+    }
+    let distance = |other: Color| {
+        let other = other.to_rgba8();
+        [(rgba.r, other.r), (rgba.g, other.g), (rgba.b, other.b)]
+            .iter()
+            .map(|&(a, b)| (a as f64 - b as f64).powi(2))
+            .sum::<f64>()
+    };
+    let keywords = [ColorKeyword::Black, ColorKeyword::White]
+        .map(|keyword| (distance(keyword.to_color()), PaletteColor::Keyword(keyword)));
+    let shades = Family::ALL.into_iter().flat_map(|family| {
+        SHADES.into_iter().filter_map(move |shade| {
+            let color = family.shade(shade)?;
+            Some((distance(color), PaletteColor::Shade(family, shade)))
+        })
+    });
+    nearest(0.0, keywords.into_iter().chain(shades))
+        .unwrap_or(PaletteColor::Keyword(ColorKeyword::Black))
+}
+
+/// The solid color of a brush; gradients and images have no utility
+fn solid(brush: Brush) -> Option<PaletteColor> {
+    match brush {
+        Brush::Solid(color) => Some(palette_color(color)),
+        _ => None,
+    }
+}
+
+/// The value shared by all four sides, if they agree
+fn uniform<T: PartialEq>([first, rest @ ..]: [Option<T>; 4]) -> Option<T> {
+    rest.iter().all(|side| *side == first).then_some(first)?
+}
+
+/// Shorthand utilities for the four sides of a box: all, x, y, top, right, bottom, left
+type SideClasses<V> = [fn(V) -> TwClass; 7];
+
+/// Pushes the shortest utilities that cover the sides that are set
+fn push_sides<V: Copy + PartialEq>(
+    out: &mut Vec<TwClass>,
+    [top, right, bottom, left]: [Option<V>; 4],
+    [all, x, y, t, r, b, l]: SideClasses<V>,
+) {
+    if let Some(value) = uniform([top, right, bottom, left]) {
+        out.push(all(value));
+        return;
+    }
+    for (start, end, both, start_class, end_class) in
+        [(left, right, x, l, r), (top, bottom, y, t, b)]
+    {
+        match (start, end) {
+            (Some(start), Some(end)) if start == end => out.push(both(start)),
+            _ => {
+                out.extend(start.map(start_class));
+                out.extend(end.map(end_class));
+            }
+        }
+    }
+}
+
+/// The closest utilities for the props set on a style, in `TwClass` order
+pub fn classes_of(s: &Style) -> Vec<TwClass> {
+    let mut out = Vec::new();
+
+    // Sizing
+    let width = value(s, fs::Width).map(length);
+    let height = value(s, fs::Height).map(length);
+    match (width, height) {
+        (Some(w), Some(h)) if w == h => out.push(TwClass::Size(w)),
+        _ => {
+            out.extend(width.map(TwClass::W));
+            out.extend(height.map(TwClass::H));
+        }
+    }
+    out.extend(value(s, fs::MinWidth).map(length).map(TwClass::MinW));
+    out.extend(value(s, fs::MaxWidth).map(length).map(TwClass::MaxW));
+    out.extend(value(s, fs::MinHeight).map(length).map(TwClass::MinH));
+    out.extend(value(s, fs::MaxHeight).map(length).map(TwClass::MaxH));
+
+    // Spacing
+    let padding = [
+        value(s, fs::PaddingTop),
+        value(s, fs::PaddingRight),
+        value(s, fs::PaddingBottom),
+        value(s, fs::PaddingLeft),
+    ]
+    .map(|side| side.map(padding_length));
+    push_sides(
+        &mut out,
+        padding,
+        [
+            TwClass::P,
+            TwClass::Px,
+            TwClass::Py,
+            TwClass::Pt,
+            TwClass::Pr,
+            TwClass::Pb,
+            TwClass::Pl,
+        ],
+    );
+    let margin = [
+        value(s, fs::MarginTop),
+        value(s, fs::MarginRight),
+        value(s, fs::MarginBottom),
+        value(s, fs::MarginLeft),
+    ]
+    .map(|side| side.map(length));
+    push_sides(
+        &mut out,
+        margin,
+        [
+            TwClass::M,
+            TwClass::Mx,
+            TwClass::My,
+            TwClass::Mt,
+            TwClass::Mr,
+            TwClass::Mb,
+            TwClass::Ml,
+        ],
+    );
+    let row_gap = value(s, fs::RowGap).and_then(spacing_of);
+    let col_gap = value(s, fs::ColGap).and_then(spacing_of);
+    if row_gap.is_some() && row_gap == col_gap {
+        out.extend(row_gap.map(TwClass::Gap));
+    }
+
+    // Borders
+    let radius = uniform([
+        value(s, fs::BorderTopLeftRadius),
+        value(s, fs::BorderTopRightRadius),
+        value(s, fs::BorderBottomRightRadius),
+        value(s, fs::BorderBottomLeftRadius),
+    ]);
+    if let Some(PxPct::Px(px)) = radius {
+        let radii = Radius::ALL.iter().map(|&r| (r.scaled_px(), r));
+        out.extend(nearest(px, radii).map(TwClass::Rounded));
+    }
+    let border = uniform([
+        value(s, fs::BorderTop),
+        value(s, fs::BorderRight),
+        value(s, fs::BorderBottom),
+        value(s, fs::BorderLeft),
+    ]);
+    if let Some(stroke) = border {
+        let widths = BORDER_WIDTHS.iter().map(|&w| (w as f64, w));
+        out.extend(nearest(stroke.0.width, widths).map(TwClass::BorderWidth));
+    }
+    let border_color = uniform([
+        value(s, fs::BorderTopColor),
+        value(s, fs::BorderRightColor),
+        value(s, fs::BorderBottomColor),
+        value(s, fs::BorderLeftColor),
+    ]);
+    out.extend(
+        border_color
+            .flatten()
+            .and_then(solid)
+            .map(|color| match color {
+                PaletteColor::Keyword(keyword) => TwClass::BorderKeyword(keyword),
+                PaletteColor::Shade(family, shade) => TwClass::BorderColor(family, shade),
+            }),
+    );
+    if value(s, fs::Outline).is_some_and(|stroke| stroke.0.width == 0.0) {
+        out.push(TwClass::OutlineNone);
+    }
+    out.extend(
+        s.get(crate::OutlineOffset)
+            .map(|offset| offset as u8)
+            .filter(|offset| [0, 1, 2, 4, 8].contains(offset))
+            .map(TwClass::OutlineOffset),
+    );
+
+    // Effects
+    let drop_shadow = value(s, fs::BoxShadowProp).and_then(|shadows| {
+        DropShadow::ALL
+            .iter()
+            .copied()
+            .filter(|&preset| preset != DropShadow::None)
+            .find(|preset| preset.to_shadows() == shadows.as_slice())
+    });
+    if let Some(preset) = drop_shadow {
+        out.push(TwClass::DropShadow(preset));
+    } else if let Some(shadows) = value(s, fs::BoxShadowProp) {
+        let shadow = match shadows.first() {
+            None => Some(Shadow::None),
+            Some(shadow) => {
+                let blur = match shadow.blur_radius {
+                    PxPct::Px(px) => px,
+                    PxPct::Pct(_) => 0.0,
+                };
+                let presets = [
+                    (2.0, Shadow::Sm),
+                    (3.0, Shadow::Default),
+                    (6.0, Shadow::Md),
+                    (15.0, Shadow::Lg),
+                    (25.0, Shadow::Xl),
+                    (50.0, Shadow::Xl2),
+                ];
+                nearest(blur, presets)
+            }
+        };
+        out.extend(shadow.map(TwClass::Shadow));
+    }
+    if let Some(opacity) = value(s, fs::Opacity) {
+        out.push(TwClass::Opacity(
+            (opacity.clamp(0.0, 1.0) * 100.0).round() as u8
+        ));
+    }
+
+    // Colors
+    out.extend(
+        value(s, fs::Background)
+            .flatten()
+            .and_then(solid)
+            .map(|color| match color {
+                PaletteColor::Keyword(keyword) => TwClass::BgKeyword(keyword),
+                PaletteColor::Shade(family, shade) => TwClass::BgColor(family, shade),
+            }),
+    );
+    out.extend(
+        value(s, fs::TextColor)
+            .flatten()
+            .map(palette_color)
+            .map(|color| match color {
+                PaletteColor::Keyword(keyword) => TwClass::TextKeyword(keyword),
+                PaletteColor::Shade(family, shade) => TwClass::TextColor(family, shade),
+            }),
+    );
+
+    // Typography
+    let size = value(s, fs::FontSize).flatten().and_then(|px| {
+        let sizes = TextSize::ALL
+            .iter()
+            .map(|&size| (size.scaled_px() as f64, size));
+        nearest(px as f64, sizes)
+    });
+    let line_height = value(s, fs::LineHeight).flatten();
+    let fixed_leading = match line_height {
+        Some(floem::text::LineHeightValue::Px(px)) => FixedLeading::ALL
+            .iter()
+            .copied()
+            .find(|leading| (leading.scaled_px() - px).abs() < 0.5),
+        _ => None,
+    };
+    match (size, fixed_leading) {
+        (Some(size), Some(leading)) => out.push(TwClass::TextSizeLeading(size, leading)),
+        _ => out.extend(size.map(TwClass::TextSize)),
+    }
+    if let Some(weight) = value(s, fs::FontWeight).flatten() {
+        let weights = FontWeight::ALL.iter().map(|&w| (w.to_weight().0 as f64, w));
+        out.extend(nearest(weight.0 as f64, weights).map(TwClass::FontWeight));
+    }
+    if let Some(stretch) = value(s, fs::FontStretch).flatten() {
+        out.extend(
+            FontStretch::ALL
+                .iter()
+                .copied()
+                .find(|s| s.to_stretch() == stretch)
+                .map(TwClass::FontStretch),
+        );
+    }
+    if let Some(floem::text::LineHeightValue::Normal(multiplier)) = line_height {
+        let leadings = Leading::ALL
+            .iter()
+            .map(|&leading| (leading.to_multiplier() as f64, leading));
+        out.extend(nearest(multiplier as f64, leadings).map(TwClass::Leading));
+    }
+
+    // Layout
+    out.extend(value(s, fs::DisplayProp).map(|display| {
+        TwClass::Display(match display {
+            fs::Display::Flex => Display::Flex,
+            fs::Display::Block => Display::Block,
+            fs::Display::Grid => Display::Grid,
+            fs::Display::None => Display::Hidden,
+        })
+    }));
+    // Right-to-left rows are stored reversed
+    let rtl = direction::direction().is_rtl();
+    out.extend(value(s, fs::FlexDirectionProp).map(|flex_direction| {
+        TwClass::FlexDirection(match flex_direction {
+            fs::FlexDirection::Row if rtl => FlexDirection::RowReverse,
+            fs::FlexDirection::RowReverse if rtl => FlexDirection::Row,
+            fs::FlexDirection::Row => FlexDirection::Row,
+            fs::FlexDirection::Column => FlexDirection::Col,
+            fs::FlexDirection::RowReverse => FlexDirection::RowReverse,
+            fs::FlexDirection::ColumnReverse => FlexDirection::ColReverse,
+        })
+    }));
+    out.extend(value(s, fs::FlexWrapProp).map(|wrap| {
+        TwClass::FlexWrap(match wrap {
+            fs::FlexWrap::Wrap => FlexWrap::Wrap,
+            fs::FlexWrap::NoWrap => FlexWrap::NoWrap,
+            fs::FlexWrap::WrapReverse => FlexWrap::WrapReverse,
+        })
+    }));
+    if value(s, fs::IsFixed) == Some(true) {
+        out.push(TwClass::Position(Position::Fixed));
+    } else {
+        out.extend(value(s, fs::PositionProp).map(|position| {
+            TwClass::Position(match position {
+                fs::Position::Absolute => Position::Absolute,
+                fs::Position::Relative => Position::Relative,
+            })
+        }));
+    }
+    let inset_class = |edge, (negative, len)| {
+        if negative {
+            TwClass::NegInset(edge, len)
+        } else {
+            TwClass::Inset(edge, len)
+        }
+    };
+    let inset = [
+        value(s, fs::InsetTop),
+        value(s, fs::InsetRight),
+        value(s, fs::InsetBottom),
+        value(s, fs::InsetLeft),
+    ]
+    .map(|side| side.map(signed_inset));
+    match uniform(inset) {
+        Some(inset) => out.push(inset_class(Edge::All, inset)),
+        None => {
+            for (edge, inset) in [Edge::Top, Edge::Right, Edge::Bottom, Edge::Left]
+                .into_iter()
+                .zip(inset)
+            {
+                out.extend(inset.map(|inset| inset_class(edge, inset)));
+            }
+        }
+    }
+    out.extend(
+        value(s, fs::TranslateX).map(|unit| match signed_length(unit) {
+            (false, len) => TwClass::TranslateX(len),
+            (true, len) => TwClass::NegTranslateX(len),
+        }),
+    );
+    out.extend(
+        value(s, fs::TranslateY).map(|unit| match signed_length(unit) {
+            (false, len) => TwClass::TranslateY(len),
+            (true, len) => TwClass::NegTranslateY(len),
+        }),
+    );
+    out.extend(value(s, fs::ZIndex).map(TwClass::ZIndex));
+    out.extend(
+        value(s, fs::JustifyContentProp)
+            .flatten()
+            .and_then(|justify| match justify {
+                fs::JustifyContent::Start | fs::JustifyContent::FlexStart => Some(Justify::Start),
+                fs::JustifyContent::Center => Some(Justify::Center),
+                fs::JustifyContent::End | fs::JustifyContent::FlexEnd => Some(Justify::End),
+                fs::JustifyContent::SpaceBetween => Some(Justify::Between),
+                fs::JustifyContent::SpaceAround => Some(Justify::Around),
+                fs::JustifyContent::SpaceEvenly => Some(Justify::Evenly),
+                fs::JustifyContent::Stretch => None,
+            })
+            .map(TwClass::Justify),
+    );
+    out.extend(
+        value(s, fs::AlignItemsProp)
+            .flatten()
+            .map(align)
+            .map(TwClass::Items),
+    );
+    out.extend(
+        value(s, fs::AlignSelf)
+            .map(|align_self| align_self.map_or(Align::Auto, align))
+            .map(TwClass::SelfAlign),
+    );
+
+    // Interactivity
+    out.extend(
+        value(s, fs::CursorProp)
+            .flatten()
+            .and_then(|cursor| match cursor {
+                fs::CursorStyle::Pointer => Some(Cursor::Pointer),
+                fs::CursorStyle::Default => Some(Cursor::Default),
+                fs::CursorStyle::Text => Some(Cursor::Text),
+                fs::CursorStyle::Move => Some(Cursor::Move),
+                fs::CursorStyle::Grab => Some(Cursor::Grab),
+                fs::CursorStyle::Grabbing => Some(Cursor::Grabbing),
+                _ => None,
+            })
+            .map(TwClass::Cursor),
+    );
+    out.extend(
+        value(s, fs::PointerEventsProp)
+            .flatten()
+            .map(|events| match events {
+                fs::PointerEvents::None => TwClass::PointerEventsNone,
+                fs::PointerEvents::Auto => TwClass::PointerEventsAuto,
+            }),
+    );
+    let overflow_x = value(s, fs::OverflowX).map(overflow);
+    let overflow_y = value(s, fs::OverflowY).map(overflow);
+    match (overflow_x, overflow_y) {
+        (Some(x), Some(y)) if x == y => out.push(TwClass::Overflow(x)),
+        _ => {
+            out.extend(overflow_x.map(TwClass::OverflowX));
+            out.extend(overflow_y.map(TwClass::OverflowY));
+        }
+    }
+
+    out
+}
+
+/// The closest class string for the props set on a style
+pub fn to_class_string(s: &Style) -> String {
+    classes_of(s)
+        .iter()
+        .map(TwClass::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A utility that differs between two styles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassChange {
+    /// Set only on the second style
+    Added(TwClass),
+    /// Set only on the first style
+    Removed(TwClass),
+    /// Set on both, to different values
+    Changed { from: TwClass, to: TwClass },
+}
+
+impl fmt::Display for ClassChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClassChange::Added(class) => write!(f, "+ {class}"),
+            ClassChange::Removed(class) => write!(f, "- {class}"),
+            ClassChange::Changed { from, to } => write!(f, "~ {from} → {to}"),
+        }
+    }
+}
+
+/// The differences between two styles, one change per line when displayed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleDiff {
+    pub changes: Vec<ClassChange>,
+}
+
+impl StyleDiff {
+    /// Whether the styles map to the same utilities
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for StyleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The utilities that differ from `a` to `b`, as [`classes_of`] sees them
+///
+/// Utilities that set the same property are paired as a change; the rest are
+/// additions and removals, in `TwClass` order.
+pub fn diff_styles(a: &Style, b: &Style) -> StyleDiff {
+    let (before, after) = (classes_of(a), classes_of(b));
+    let mut paired = vec![false; after.len()];
+    let mut changes = Vec::new();
+    for &from in &before {
+        if let Some(index) = after.iter().position(|&to| to == from) {
+            paired[index] = true;
+            continue;
+        }
+        let to = after.iter().enumerate().position(|(index, &to)| {
+            !paired[index] && !before.contains(&to) && merge::same_group(from, to)
+        });
+        match to {
+            Some(index) => {
+                paired[index] = true;
+                changes.push(ClassChange::Changed {
+                    from,
+                    to: after[index],
+                });
+            }
+            None => changes.push(ClassChange::Removed(from)),
+        }
+    }
+    changes.extend(
+        after
+            .iter()
+            .zip(&paired)
+            .filter(|&(_, &paired)| !paired)
+            .map(|(&class, _)| ClassChange::Added(class)),
+    );
+    StyleDiff { changes }
+}
+
+thread_local! {
+    /// Whether views record their classes
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+    /// The class strings of recorded views
+    static VIEW_CLASSES: RefCell<HashMap<ViewId, Rc<str>>> = RefCell::new(HashMap::new());
+}
+
+/// Turns recording of view classes on or off, for views created afterwards
+pub fn set_recording(recording: bool) {
+    RECORDING.with(|cell| cell.set(recording));
+}
+
+/// Whether views record their classes
+pub fn is_recording() -> bool {
+    RECORDING.with(Cell::get)
+}
+
+/// Records the classes of a view, if recording is on
+pub(crate) fn record_view(id: ViewId, classes: impl FnOnce() -> String) {
+    if is_recording() {
+        let classes = classes().into();
+        VIEW_CLASSES.with(|views| views.borrow_mut().insert(id, classes));
+    }
+}
+
+/// The classes recorded for a view
+pub fn view_classes(id: ViewId) -> Option<Rc<str>> {
+    VIEW_CLASSES.with(|views| views.borrow().get(&id).cloned())
+}
+
+/// Forgets the classes of all recorded views
+pub fn clear_view_classes() {
+    VIEW_CLASSES.with(|views| views.borrow_mut().clear());
+}
+
+/// A label showing the recorded classes of the view `selected` returns, one
+/// per line, for an inspector panel or a debug overlay
+pub fn class_overlay(selected: impl Fn() -> Option<ViewId> + 'static) -> Label {
+    label(move || {
+        selected()
+            .and_then(view_classes)
+            .map(|classes| classes.split_whitespace().collect::<Vec<_>>().join("\n"))
+            .unwrap_or_default()
+    })
+    .style(|s| {
+        s.padding(8.0)
+            .border_radius(6.0)
+            .background(colors::gray::C900)
+            .color(colors::WHITE)
+            .font_size(12.0)
+    })
+}
+
+/// The alignment keyword for a Floem align value
+fn align(align: fs::AlignItems) -> Align {
+    match align {
+        fs::AlignItems::Start | fs::AlignItems::FlexStart => Align::Start,
+        fs::AlignItems::Center => Align::Center,
+        fs::AlignItems::End | fs::AlignItems::FlexEnd => Align::End,
+        fs::AlignItems::Stretch => Align::Stretch,
+        fs::AlignItems::Baseline => Align::Baseline,
+    }
+}
+
+/// The overflow keyword for a Floem overflow value; scrolling is `auto`
+fn overflow(overflow: floem::taffy::Overflow) -> Overflow {
+    match overflow {
+        floem::taffy::Overflow::Visible => Overflow::Visible,
+        floem::taffy::Overflow::Clip => Overflow::Clip,
+        floem::taffy::Overflow::Hidden => Overflow::Hidden,
+        floem::taffy::Overflow::Scroll => Overflow::Auto,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::class;
+
+    #[test]
+    fn test_round_trips_classes() {
+        let classes = "w-1/2 h-10 px-4 py-2 mt-auto gap-2 rounded-md border border-gray-300 \
+                       shadow-md opacity-50 bg-white text-slate-900 text-sm font-semibold \
+                       flex flex-col absolute inset-0 -translate-x-1/2 justify-between \
+                       items-center cursor-pointer overflow-hidden";
+        let style = class::parse_classes(classes)
+            .unwrap()
+            .iter()
+            .fold(Style::new(), |s, class| class.apply(s));
+        assert_eq!(
+            to_class_string(&style),
+            classes.split_whitespace().collect::<Vec<_>>().join(" ")
+        );
+
+        let badge = class::parse_classes("text-xs/4 drop-shadow-md absolute -top-2 -right-2")
+            .unwrap()
+            .iter()
+            .fold(Style::new(), |s, class| class.apply(s));
+        assert_eq!(
+            to_class_string(&badge),
+            "drop-shadow-md text-xs/4 absolute -top-2 -right-2"
+        );
+    }
+
+    #[test]
+    fn test_snaps_to_nearest() {
+        let style = Style::new()
+            .width(250.0)
+            .height(250.0)
+            .padding_left(7.5)
+            .padding_top(7.5)
+            .background(Color::from_rgb8(0x3a, 0x80, 0xf0))
+            .font_size(14.5);
+        assert_eq!(
+            to_class_string(&style),
+            "size-64 pl-2 pt-2 bg-blue-500 text-sm"
+        );
+        assert_eq!(to_class_string(&Style::new()), "");
+    }
+
+    #[test]
+    fn test_diff_styles() {
+        let base = class::parse_classes("px-4 py-2 bg-blue-500 text-white rounded-md")
+            .unwrap()
+            .iter()
+            .fold(Style::new(), |s, class| class.apply(s));
+        let hovered = class::parse_classes("px-4 py-2 bg-blue-600 text-white shadow-md")
+            .unwrap()
+            .iter()
+            .fold(Style::new(), |s, class| class.apply(s));
+
+        let diff = diff_styles(&base, &hovered);
+        assert_eq!(
+            diff.changes,
+            [
+                ClassChange::Removed("rounded-md".parse().unwrap()),
+                ClassChange::Changed {
+                    from: "bg-blue-500".parse().unwrap(),
+                    to: "bg-blue-600".parse().unwrap(),
+                },
+                ClassChange::Added("shadow-md".parse().unwrap()),
+            ]
+        );
+        assert!(diff_styles(&base, &base).is_empty());
+    }
+
+    #[test]
+    fn test_view_classes() {
+        let (recorded, skipped) = (ViewId::new(), ViewId::new());
+        record_view(skipped, || "p-4".to_string());
+        set_recording(true);
+        record_view(recorded, || "px-4 py-2".to_string());
+        assert_eq!(view_classes(recorded).as_deref(), Some("px-4 py-2"));
+        assert_eq!(view_classes(skipped), None);
+        clear_view_classes();
+        assert_eq!(view_classes(recorded), None);
+        set_recording(false);
+    }
+}
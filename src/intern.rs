@@ -0,0 +1,144 @@
+//! Interning of parsed class strings
+//!
+//! Styles written with [`apply!`](crate::apply) or
+//! [`tw`](crate::TailwindViewExt::tw) pass the same class strings on every
+//! style pass. The parser keeps each string's utilities, so a string is
+//! parsed once per program and later passes only look it up:
+//!
+//! ```rust
+//! use floem_tailwind::intern;
+//!
+//! let first = intern::parse_classes("px-4 py-2 rounded-lg").unwrap();
+//! let again = intern::parse_classes("px-4 py-2 rounded-lg").unwrap();
+//! assert_eq!(first, again);
+//! assert!(intern::stats().hits >= 1);
+//! ```
+//!
+//! The cache grows with each distinct string. Strings built at runtime, e.g.
+//! with [`format!`], add an entry per value; [`clear`] drops them all.
+//! Registering a utility drops the entries it could change, those with
+//! unknown classes.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
+
+use crate::class::{self, ParseClassError, TwClass};
+
+/// The parse of a class string
+struct Entry {
+    /// The utilities, unknown classes skipped
+    classes: Arc<[TwClass]>,
+    /// The first unknown class, if any
+    error: Option<ParseClassError>,
+}
+
+static CACHE: LazyLock<Mutex<HashMap<Box<str>, Entry>>> = LazyLock::new(Default::default);
+static HITS: AtomicU64 = AtomicU64::new(0);
+static MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Counters of the cache, for profiling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    /// Lookups of a string already parsed
+    pub hits: u64,
+    /// Lookups that parsed their string
+    pub misses: u64,
+    /// Strings kept
+    pub entries: usize,
+}
+
+fn cache() -> MutexGuard<'static, HashMap<Box<str>, Entry>> {
+    CACHE.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Runs `f` on the entry of `classes`, parsing the string on a miss
+fn with_entry<R>(classes: &str, f: impl FnOnce(&Entry) -> R) -> R {
+    if let Some(entry) = cache().get(classes) {
+        HITS.fetch_add(1, Ordering::Relaxed);
+        return f(entry);
+    }
+    MISSES.fetch_add(1, Ordering::Relaxed);
+    // Parsed without the lock, which registering a utility takes
+    let mut error = None;
+    let parsed: Vec<TwClass> = class::tokens(classes)
+        .filter_map(|(position, name)| {
+            let class = class::parse_known(name);
+            if class.is_none() && error.is_none() {
+                error = Some(ParseClassError::unknown(name, position));
+            }
+            class
+        })
+        .collect();
+    let entry = Entry {
+        classes: parsed.into(),
+        error,
+    };
+    let result = f(&entry);
+    cache().insert(classes.into(), entry);
+    result
+}
+
+/// Parses a class string like [`class::parse_classes`](crate::class::parse_classes),
+/// reusing the utilities of an earlier parse
+pub fn parse_classes(classes: &str) -> Result<Arc<[TwClass]>, ParseClassError> {
+    with_entry(classes, |entry| match &entry.error {
+        Some(error) => Err(error.clone()),
+        None => Ok(entry.classes.clone()),
+    })
+}
+
+/// Parses a class string like
+/// [`class::parse_classes_lossy`](crate::class::parse_classes_lossy),
+/// reusing the utilities of an earlier parse
+pub fn parse_classes_lossy(classes: &str) -> Arc<[TwClass]> {
+    with_entry(classes, |entry| entry.classes.clone())
+}
+
+/// The cache's counters since the program started
+pub fn stats() -> Stats {
+    Stats {
+        hits: HITS.load(Ordering::Relaxed),
+        misses: MISSES.load(Ordering::Relaxed),
+        entries: cache().len(),
+    }
+}
+
+/// Drops every kept string; the counters keep counting
+pub fn clear() {
+    cache().clear();
+}
+
+/// Drops the strings with unknown classes, which a new utility may match
+pub(crate) fn clear_unknown() {
+    cache().retain(|_, entry| entry.error.is_none());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interned_parse() {
+        let before = stats();
+        let first = parse_classes("w-1/2 text-sm").unwrap();
+        let again = parse_classes_lossy("w-1/2 text-sm");
+        assert!(Arc::ptr_eq(&first, &again));
+        let after = stats();
+        assert!(after.hits > before.hits);
+
+        let classes = "p-4 intern-test-unknown bg-blue-500";
+        let lossy = parse_classes_lossy(classes);
+        assert_eq!(&*lossy, crate::class::parse_classes_lossy(classes));
+        assert_eq!(
+            parse_classes(classes).unwrap_err().class,
+            "intern-test-unknown"
+        );
+        clear_unknown();
+        assert!(!Arc::ptr_eq(&lossy, &parse_classes_lossy(classes)));
+        assert!(Arc::ptr_eq(
+            &first,
+            &parse_classes("w-1/2 text-sm").unwrap()
+        ));
+    }
+}
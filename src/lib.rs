@@ -2,8 +2,9 @@
 //!
 //! Tailwind-style utility methods for Floem styling.
 //!
-//! This crate provides a `TailwindExt` trait that extends Floem's `Style` with
-//! shorthand methods following Tailwind CSS naming conventions.
+//! This crate provides extension traits for Floem's `Style` with shorthand
+//! methods following Tailwind CSS naming conventions. Import them all with
+//! `use floem_tailwind::prelude::*;`.
 //!
 //! ## Spacing Scale
 //!
@@ -15,11 +16,15 @@
 //! - `w_8()` = 32px
 //! - etc.
 //!
+//! The pixel values assume a 16px root font size; [`rem::set_root`] rescales
+//! spacing, sizes, and font sizes for another root, and [`dpi`] snaps lengths
+//! to device pixels on fractional-DPI displays.
+//!
 //! ## Usage
 //!
 //! ```rust
 //! use floem::style::Style;
-//! use floem_tailwind::TailwindExt;
+//! use floem_tailwind::prelude::*;
 //!
 //! let style = Style::new()
 //!     .w_64()        // width: 256px
@@ -33,23 +38,219 @@
 //!
 //! ```rust
 //! use floem::style::Style;
-//! use floem_tailwind::TailwindExt;
+//! use floem_tailwind::prelude::*;
 //!
 //! let style = Style::new()
 //!     .bg_blue_500()     // background: blue-500
 //!     .text_white()      // text color: white
 //!     .border_gray_300(); // border color: gray-300
 //! ```
+//!
+//! ## Dynamic Spacing
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::Spacing;
+//! use floem_tailwind::prelude::*;
+//!
+//! let density = Spacing::S2;
+//! let style = Style::new()
+//!     .p(density)    // padding: 8px
+//!     .gap(density); // gap: 8px
+//! ```
+//!
+//! ## Class Values
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::{TailwindExt, TwClass, class};
+//!
+//! let classes = class::parse_classes("px-4 py-2 bg-blue-500 rounded-lg").unwrap();
+//! let style = Style::new().apply_tw_classes(&classes);
+//! assert_eq!(classes[0], TwClass::Px(floem_tailwind::Spacing::S4.into()));
+//! ```
+//!
+//! Every utility method has a class name, and [`utilities`] lists them all
+//! with their styles. [`inspect::to_class_string`] goes the other way, from a
+//! `Style` to the closest class string. [`manifest`] describes each utility's
+//! parameter and CSS properties for editor tooling.
+//!
+//! [`TwStyle`] holds utilities as a value that can be combined with `+` and
+//! applied later, for component libraries that export their styles.
+//! [`StyleSheet`] names utility bundles (`card.header`) for the whole app.
+//!
+//! Problems that don't stop styling, such as unknown classes, are reported
+//! through [`warning`] in debug builds.
+//!
+//! The macros, [`cn!`], [`apply!`], [`tw_class!`], [`define_utility!`], and
+//! [`extend_spacing!`], are declarative, so the crate builds without `syn` or
+//! `quote`.
+//!
+//! [`colors::generate_palette`] derives a 50–950 ramp from a brand color, and
+//! [`color_math`] derives hover and pressed variants from any color.
+//! [`contrast::set_high_contrast`] raises text and border contrast to WCAG AAA,
+//! and [`status`] colors have a colorblind-safe palette.
+//!
+//! [`easing`] and [`duration`] hold Tailwind's transition curves and timings
+//! for Floem transitions and animations.
+//!
+//! [`direction`] sets left-to-right or right-to-left layout, globally or per
+//! subtree, for the logical utilities (`ps-4`, `rounded-s-lg`) and rows.
+//! [`theme`] switches between light and dark mode or follows the OS.
+//!
+//! ## Features
+//!
+//! The utility methods are split into traits by group, each behind a default
+//! feature: `spacing` (`TailwindSpacingExt`, and `TailwindFractionExt` for
+//! fractions by name), `color` (`TailwindColorExt`, and
+//! `TailwindActiveExt` for pressed-state colors),
+//! `typography` (`TailwindTypographyExt`), and `layout` (`TailwindLayoutExt`).
+//! Borders, effects, and interactivity stay in `TailwindExt`.
+//!
+//! - `serde`: `Serialize`/`Deserialize` for `TwClass`, its value types,
+//!   `Spacing`, `colors::Family`, palette colors, and `TailwindConfig`, and
+//!   `colors::TwColor` for colors written as Tailwind names
+//! - `toml` / `json`: `TailwindConfig::from_toml` / `TailwindConfig::from_json`,
+//!   `StyleSheet::from_toml` / `StyleSheet::from_json`, and `Manifest::to_json`
+//!   and design token import (see [`tokens`]) with `json`
+//! - `codegen`: build-script generation of constants and methods for a config
+//!   file (see [`codegen`])
+//! - `hot-reload`: stylesheets that reload from disk in debug builds (see
+//!   [`hot_reload`])
+//! - `diagnostics`: warnings for utilities that override earlier ones in debug
+//!   builds (see [`diagnostics`])
+//! - `prefixed`: `tw_`-prefixed copies of the utility methods, for names
+//!   that clash with Floem's (see [`prefixed`])
+//! - `tracing`: spans and events for applied utilities and class strings, for
+//!   profiling with the `tracing` ecosystem (see [`trace`])
+//!
+//! ## WebAssembly
+//!
+//! The crate is written to build for `wasm32-unknown-unknown` with Floem's
+//! web backend, but web builds aren't tested, so expect rough edges there.
+//! Following the OS theme relies on the browser reporting
+//! `prefers-color-scheme` changes to the tracking view. `hot-reload` needs a
+//! file system, so `HotStyles::watch` fails on the web; parse a fetched
+//! sheet with `StyleSheet::from_toml` instead.
 
 use floem::style::Style;
-use floem::unit::{Pct, PxPctAuto};
+#[cfg(any(feature = "spacing", feature = "layout"))]
+use floem::unit::Pct;
+#[cfg(feature = "spacing")]
+use floem::unit::PxPctAuto;
+#[cfg(feature = "color")]
+use floem::views::PlaceholderTextClass;
 use peniko::Color;
 
+use crate::screen::Breakpoint;
+
+pub mod class;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+pub mod color_math;
 pub mod colors;
+pub mod compose;
+pub mod config;
+pub mod contrast;
+pub mod density;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+pub mod direction;
+pub mod dpi;
+pub mod group;
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+pub mod inspect;
+pub mod intern;
+pub mod manifest;
+pub mod merge;
+#[cfg(test)]
+mod parity;
+pub mod presets;
+pub mod registry;
+#[cfg(test)]
+mod snapshot;
+pub mod status;
+pub mod stylesheet;
+pub mod theme;
+#[cfg(feature = "json")]
+pub mod tokens;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod tw;
+pub mod view;
+pub mod warning;
+pub mod window;
+
+pub use class::TwClass;
+pub use compose::TwStyle;
+pub use config::TailwindConfig;
+pub use merge::{classes, tw_merge};
+pub use spacing::Spacing;
+pub use stylesheet::StyleSheet;
+pub use tw::tailwind_theme;
+pub use view::TailwindViewExt;
+
+/// All extension traits enabled by the current features
+pub mod prelude {
+    #[cfg(feature = "layout")]
+    pub use crate::TailwindLayoutExt;
+    #[cfg(feature = "spacing")]
+    pub use crate::{TailwindFractionExt, TailwindSpacingExt};
+    #[cfg(feature = "typography")]
+    pub use crate::TailwindTypographyExt;
+    pub use crate::{StyleBuilder, TailwindAnimationExt, TailwindExt, TailwindViewExt};
+    #[cfg(feature = "color")]
+    pub use crate::{TailwindActiveExt, TailwindColorExt};
+}
+
+/// The utility traits with `tw_`-prefixed methods (`prefixed` feature)
+///
+/// Some utility names match Floem's own `Style` methods, such as `border`,
+/// and Floem may add more; an inherent method wins over a trait method of the
+/// same name, and two imported traits with one name are ambiguous. The
+/// prefixed traits have every method of the utility traits as `tw_flex()`,
+/// `tw_border_2()`, `tw_p(Spacing::S4)`, ..., so importing them instead of the
+/// [`prelude`] avoids the clash:
+///
+/// ```rust
+/// use floem::style::Style;
+/// use floem_tailwind::Spacing;
+/// use floem_tailwind::prefixed::*;
+///
+/// let style = Style::new()
+///     .tw_flex()
+///     .tw_border_2()
+///     .tw_p(Spacing::S4)
+///     .tw_gap_units(3.0)
+///     .tw_w_half()
+///     .tw_dark(|s| s.tw_bg_gray_900())
+///     .tw_active_bg_blue_700();
+/// ```
+///
+/// [`TailwindAnimationExt`](crate::TailwindAnimationExt), whose methods
+/// extend Floem's `Animation` rather than `Style`, is re-exported as is.
+#[cfg(feature = "prefixed")]
+pub mod prefixed {
+    #[cfg(feature = "layout")]
+    pub use crate::TwLayoutExt;
+    #[cfg(feature = "typography")]
+    pub use crate::TwTypographyExt;
+    #[cfg(feature = "spacing")]
+    pub use crate::{TwFractionExt, TwSpacingExt};
+    #[cfg(feature = "color")]
+    pub use crate::{TwActiveExt, TwColorExt};
+    pub use crate::{StyleBuilder, TailwindAnimationExt, TwExt};
+}
+
+#[doc(hidden)]
+pub use floem as __floem;
 
 /// Tailwind-style spacing scale (in pixels)
 /// Each unit = 4px (following Tailwind's 0.25rem base with 16px root)
 pub mod spacing {
+    use floem::unit::{PxPct, PxPctAuto};
+
     pub const SPACING_0: f64 = 0.0;
     pub const SPACING_PX: f64 = 1.0;
     pub const SPACING_0_5: f64 = 2.0;
@@ -98,6 +299,261 @@ pub mod spacing {
     pub const SIZE_5XL: f64 = 1024.0; // 64rem
     pub const SIZE_6XL: f64 = 1152.0; // 72rem
     pub const SIZE_7XL: f64 = 1280.0; // 80rem
+
+    /// A step on the spacing scale, for choosing spacing at runtime
+    /// (e.g. from a density setting) instead of picking a fixed method.
+    ///
+    /// Converts into Floem's `PxPct`/`PxPctAuto`, so it can also be passed to
+    /// `Style` methods such as `gap` or `width` directly.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Spacing {
+        S0,
+        Px,
+        S0_5,
+        S1,
+        S1_5,
+        S2,
+        S2_5,
+        S3,
+        S3_5,
+        S4,
+        S5,
+        S6,
+        S7,
+        S8,
+        S9,
+        S10,
+        S11,
+        S12,
+        S14,
+        S16,
+        S20,
+        S24,
+        S28,
+        S32,
+        S36,
+        S40,
+        S44,
+        S48,
+        S52,
+        S56,
+        S60,
+        S64,
+        S72,
+        S80,
+        S96,
+    }
+
+    impl Spacing {
+        /// Every step, from smallest to largest
+        pub const ALL: [Spacing; 35] = [
+            Spacing::S0,
+            Spacing::Px,
+            Spacing::S0_5,
+            Spacing::S1,
+            Spacing::S1_5,
+            Spacing::S2,
+            Spacing::S2_5,
+            Spacing::S3,
+            Spacing::S3_5,
+            Spacing::S4,
+            Spacing::S5,
+            Spacing::S6,
+            Spacing::S7,
+            Spacing::S8,
+            Spacing::S9,
+            Spacing::S10,
+            Spacing::S11,
+            Spacing::S12,
+            Spacing::S14,
+            Spacing::S16,
+            Spacing::S20,
+            Spacing::S24,
+            Spacing::S28,
+            Spacing::S32,
+            Spacing::S36,
+            Spacing::S40,
+            Spacing::S44,
+            Spacing::S48,
+            Spacing::S52,
+            Spacing::S56,
+            Spacing::S60,
+            Spacing::S64,
+            Spacing::S72,
+            Spacing::S80,
+            Spacing::S96,
+        ];
+
+        /// The step's class-name fragment (e.g. `"0.5"` for `p-0.5`)
+        pub const fn name(self) -> &'static str {
+            match self {
+                Spacing::S0 => "0",
+                Spacing::Px => "px",
+                Spacing::S0_5 => "0.5",
+                Spacing::S1 => "1",
+                Spacing::S1_5 => "1.5",
+                Spacing::S2 => "2",
+                Spacing::S2_5 => "2.5",
+                Spacing::S3 => "3",
+                Spacing::S3_5 => "3.5",
+                Spacing::S4 => "4",
+                Spacing::S5 => "5",
+                Spacing::S6 => "6",
+                Spacing::S7 => "7",
+                Spacing::S8 => "8",
+                Spacing::S9 => "9",
+                Spacing::S10 => "10",
+                Spacing::S11 => "11",
+                Spacing::S12 => "12",
+                Spacing::S14 => "14",
+                Spacing::S16 => "16",
+                Spacing::S20 => "20",
+                Spacing::S24 => "24",
+                Spacing::S28 => "28",
+                Spacing::S32 => "32",
+                Spacing::S36 => "36",
+                Spacing::S40 => "40",
+                Spacing::S44 => "44",
+                Spacing::S48 => "48",
+                Spacing::S52 => "52",
+                Spacing::S56 => "56",
+                Spacing::S60 => "60",
+                Spacing::S64 => "64",
+                Spacing::S72 => "72",
+                Spacing::S80 => "80",
+                Spacing::S96 => "96",
+            }
+        }
+
+        /// Looks up a step by its class-name fragment
+        pub fn from_name(name: &str) -> Option<Spacing> {
+            Spacing::ALL.into_iter().find(|step| step.name() == name)
+        }
+
+        /// The step's size in pixels
+        pub const fn to_px(self) -> f64 {
+            match self {
+                Spacing::S0 => SPACING_0,
+                Spacing::Px => SPACING_PX,
+                Spacing::S0_5 => SPACING_0_5,
+                Spacing::S1 => SPACING_1,
+                Spacing::S1_5 => SPACING_1_5,
+                Spacing::S2 => SPACING_2,
+                Spacing::S2_5 => SPACING_2_5,
+                Spacing::S3 => SPACING_3,
+                Spacing::S3_5 => SPACING_3_5,
+                Spacing::S4 => SPACING_4,
+                Spacing::S5 => SPACING_5,
+                Spacing::S6 => SPACING_6,
+                Spacing::S7 => SPACING_7,
+                Spacing::S8 => SPACING_8,
+                Spacing::S9 => SPACING_9,
+                Spacing::S10 => SPACING_10,
+                Spacing::S11 => SPACING_11,
+                Spacing::S12 => SPACING_12,
+                Spacing::S14 => SPACING_14,
+                Spacing::S16 => SPACING_16,
+                Spacing::S20 => SPACING_20,
+                Spacing::S24 => SPACING_24,
+                Spacing::S28 => SPACING_28,
+                Spacing::S32 => SPACING_32,
+                Spacing::S36 => SPACING_36,
+                Spacing::S40 => SPACING_40,
+                Spacing::S44 => SPACING_44,
+                Spacing::S48 => SPACING_48,
+                Spacing::S52 => SPACING_52,
+                Spacing::S56 => SPACING_56,
+                Spacing::S60 => SPACING_60,
+                Spacing::S64 => SPACING_64,
+                Spacing::S72 => SPACING_72,
+                Spacing::S80 => SPACING_80,
+                Spacing::S96 => SPACING_96,
+            }
+        }
+    }
+
+    impl Spacing {
+        /// The step's size in pixels at the current [`rem`](crate::rem) root
+        /// and [`density`](crate::density); `Px` stays 1px
+        pub fn scaled_px(self) -> f64 {
+            match self {
+                Spacing::Px => crate::dpi::snap(SPACING_PX),
+                step => crate::rem::scale(step.to_px()),
+            }
+        }
+    }
+
+    impl From<Spacing> for PxPct {
+        fn from(spacing: Spacing) -> Self {
+            PxPct::Px(spacing.scaled_px())
+        }
+    }
+
+    impl From<Spacing> for PxPctAuto {
+        fn from(spacing: Spacing) -> Self {
+            PxPctAuto::Px(spacing.scaled_px())
+        }
+    }
+
+    /// `n` steps of the 4px scale in pixels at the current
+    /// [`rem`](crate::rem) root and [`density`](crate::density), for values
+    /// off the scale (`units(13.0)` is
+    /// 52px at the 16px root)
+    pub fn units(n: f64) -> f64 {
+        crate::rem::scale(n * SPACING_1)
+    }
+}
+
+/// Root font size of the rem-based scales
+///
+/// Tailwind defines spacing, named sizes, and font sizes in rem. The pixel
+/// constants of this crate assume the usual 16px root; setting another root
+/// (e.g. 14px for dense desktop UIs) rescales the spacing, sizing, and
+/// font-size utilities applied afterwards. Set it before building views.
+///
+/// ```rust
+/// use floem_tailwind::{Spacing, rem};
+///
+/// rem::set_root(14.0);
+/// assert_eq!(Spacing::S4.scaled_px(), 14.0); // p-4 = 1rem
+/// assert_eq!(Spacing::Px.scaled_px(), 1.0);
+/// ```
+pub mod rem {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// The root font size the pixel constants are based on
+    pub const DEFAULT_ROOT: f64 = 16.0;
+
+    /// The root font size, as `f64` bits
+    static ROOT: AtomicU64 = AtomicU64::new(DEFAULT_ROOT.to_bits());
+
+    /// Sets the root font size in pixels
+    pub fn set_root(px: f64) {
+        ROOT.store(px.to_bits(), Ordering::Relaxed);
+    }
+
+    /// The root font size in pixels
+    pub fn root() -> f64 {
+        f64::from_bits(ROOT.load(Ordering::Relaxed))
+    }
+
+    /// Converts rem to pixels at the current root
+    pub fn to_px(rem: f64) -> f64 {
+        rem * root()
+    }
+
+    /// Rescales a pixel value of the 16px-root scale to the current root and
+    /// [`density`](crate::density), snapped to device pixels if
+    /// [`dpi`](crate::dpi) snapping is on
+    pub fn scale(px: f64) -> f64 {
+        crate::dpi::snap(crate::density::px(px * root() / DEFAULT_ROOT))
+    }
+
+    /// [`scale`] for font sizes, which aren't snapped
+    pub fn scale_f32(px: f32) -> f32 {
+        (px as f64 * root() / DEFAULT_ROOT) as f32
+    }
 }
 
 /// Border radius scale (in pixels)
@@ -111,6 +567,12 @@ pub mod radius {
     pub const ROUNDED_2XL: f64 = 16.0;
     pub const ROUNDED_3XL: f64 = 24.0;
     pub const ROUNDED_FULL: f64 = 9999.0;
+
+    /// Rescales a radius to the current [`density`](crate::density), snapped
+    /// to device pixels if [`dpi`](crate::dpi) snapping is on
+    pub fn scale(px: f64) -> f64 {
+        crate::dpi::snap(crate::density::px(px))
+    }
 }
 
 /// Font size scale (in pixels)
@@ -156,15 +618,147 @@ pub mod line_height {
     pub const LEADING_LOOSE: f32 = 2.0;
 }
 
+/// Timing functions matching Tailwind CSS ease-* utilities
+///
+/// The curves are `Bezier`s, so they work as the easing of a
+/// `floem::style::Transition` and of an animation keyframe:
+///
+/// ```rust
+/// use floem::style::{Style, Transition};
+/// use floem_tailwind::{duration, easing};
+///
+/// let style = Style::new()
+///     .transition_background(Transition::new(duration::DURATION_200, easing::EASE_OUT));
+/// ```
+pub mod easing {
+    use floem::easing::Bezier;
+
+    pub const LINEAR: Bezier = Bezier(0.0, 0.0, 1.0, 1.0);
+    pub const EASE_IN: Bezier = Bezier(0.4, 0.0, 1.0, 1.0);
+    pub const EASE_OUT: Bezier = Bezier(0.0, 0.0, 0.2, 1.0);
+    pub const EASE_IN_OUT: Bezier = Bezier(0.4, 0.0, 0.2, 1.0);
+}
+
+/// Durations matching Tailwind CSS duration-* utilities
+pub mod duration {
+    use std::time::Duration;
+
+    pub const DURATION_0: Duration = Duration::ZERO;
+    pub const DURATION_75: Duration = Duration::from_millis(75);
+    pub const DURATION_100: Duration = Duration::from_millis(100);
+    pub const DURATION_150: Duration = Duration::from_millis(150);
+    pub const DURATION_200: Duration = Duration::from_millis(200);
+    pub const DURATION_300: Duration = Duration::from_millis(300);
+    pub const DURATION_500: Duration = Duration::from_millis(500);
+    pub const DURATION_700: Duration = Duration::from_millis(700);
+    pub const DURATION_1000: Duration = Duration::from_millis(1000);
+}
+
+/// Responsive breakpoints (minimum window width in pixels)
+///
+/// The constants and [`Breakpoint`](screen::Breakpoint) are the one definition
+/// of the breakpoints, shared by `max-w-screen-*`, config screens, and
+/// applications that pick layouts by window width.
+pub mod screen {
+    pub const SM: f64 = 640.0; // 40rem
+    pub const MD: f64 = 768.0; // 48rem
+    pub const LG: f64 = 1024.0; // 64rem
+    pub const XL: f64 = 1280.0; // 80rem
+    pub const XL2: f64 = 1536.0; // 96rem
+
+    /// A responsive breakpoint, ordered from narrowest to widest
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Breakpoint {
+        Sm,
+        Md,
+        Lg,
+        Xl,
+        Xl2,
+    }
+
+    impl Breakpoint {
+        /// Every breakpoint, from narrowest to widest
+        pub const ALL: [Breakpoint; 5] = [
+            Breakpoint::Sm,
+            Breakpoint::Md,
+            Breakpoint::Lg,
+            Breakpoint::Xl,
+            Breakpoint::Xl2,
+        ];
+
+        /// The breakpoint's class-name fragment (e.g. `"2xl"`)
+        pub const fn name(self) -> &'static str {
+            match self {
+                Breakpoint::Sm => "sm",
+                Breakpoint::Md => "md",
+                Breakpoint::Lg => "lg",
+                Breakpoint::Xl => "xl",
+                Breakpoint::Xl2 => "2xl",
+            }
+        }
+
+        /// Looks up a breakpoint by its class-name fragment
+        pub fn from_name(name: &str) -> Option<Breakpoint> {
+            Breakpoint::ALL.into_iter().find(|bp| bp.name() == name)
+        }
+
+        /// The minimum window width in pixels
+        pub const fn min_width(self) -> f64 {
+            match self {
+                Breakpoint::Sm => SM,
+                Breakpoint::Md => MD,
+                Breakpoint::Lg => LG,
+                Breakpoint::Xl => XL,
+                Breakpoint::Xl2 => XL2,
+            }
+        }
+
+        /// The widest breakpoint active at a window width, if any
+        pub fn at(width: f64) -> Option<Breakpoint> {
+            Breakpoint::ALL
+                .into_iter()
+                .rev()
+                .find(|bp| width >= bp.min_width())
+        }
+    }
+}
+
+/// The [`screen`] breakpoints under the name of Tailwind's `screens` config
+pub use screen as screens;
+
 /// Box shadow presets matching Tailwind CSS shadow-* utilities
 /// Each shadow is defined by (h_offset, v_offset, blur, spread, opacity)
+///
+/// Shadows are applied from stack slices, which Floem copies into its inline
+/// shadow storage, so the `shadow_*` and `drop_shadow_*` utilities never
+/// heap-allocate.
 pub mod shadow {
     use floem::style::BoxShadow;
     use peniko::Color;
 
+    /// shadow-none: an empty shadow list
+    pub const NONE: &[BoxShadow] = &[];
+
     /// Creates a shadow color with the given opacity (0.0 - 1.0)
     fn shadow_color(opacity: f32) -> Color {
-        Color::from_rgba8(0, 0, 0, (opacity * 255.0) as u8)
+        crate::colors::alpha(crate::colors::BLACK, opacity)
+    }
+
+    /// A shadow from its CSS values: `x` `y` `blur` `spread` `color`
+    pub fn custom(x: f64, y: f64, blur: f64, spread: f64, color: Color) -> BoxShadow {
+        BoxShadow::new()
+            .h_offset(x)
+            .v_offset(y)
+            .blur_radius(blur)
+            .spread(spread)
+            .color(color)
+    }
+
+    /// A solid ring of `width` around the view, like Tailwind's `ring-*`
+    /// (0 0 0 `width` `color`)
+    pub fn ring(width: f64, color: Color) -> BoxShadow {
+        custom(0.0, 0.0, 0.0, width, color)
     }
 
     /// shadow-sm: 0 1px 2px 0 rgb(0 0 0 / 0.05)
@@ -227,3091 +821,2828 @@ pub mod shadow {
             .spread(-12.0)
             .color(shadow_color(0.25))
     }
-}
 
-/// Macro to generate width methods
-macro_rules! width_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
-}
+    // === Drop Shadows ===
+    // Tailwind's drop-shadow-* are CSS filters that follow the shape of the
+    // view's content. Floem has no filters, so these are the same layers as
+    // box shadows: they follow the view's rounded corners, which suits round
+    // icons and pills (pair with rounded-full) but not arbitrary shapes.
 
-/// Macro to implement width methods
-macro_rules! impl_width_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.width($value)
-            }
-        )*
-    };
-}
+    /// A drop-shadow layer: 0 `y` `blur` rgb(0 0 0 / `opacity`)
+    fn drop(y: f64, blur: f64, opacity: f32) -> BoxShadow {
+        custom(0.0, y, blur, 0.0, shadow_color(opacity))
+    }
 
-/// Macro to generate height methods
-macro_rules! height_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
-}
+    /// drop-shadow-sm: 0 1px 1px rgb(0 0 0 / 0.05)
+    pub fn drop_shadow_sm() -> [BoxShadow; 1] {
+        [drop(1.0, 1.0, 0.05)]
+    }
 
-/// Macro to implement height methods
-macro_rules! impl_height_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.height($value)
-            }
-        )*
-    };
-}
+    /// drop-shadow: 0 1px 2px rgb(0 0 0 / 0.1), 0 1px 1px rgb(0 0 0 / 0.06)
+    pub fn drop_shadow_default() -> [BoxShadow; 2] {
+        [drop(1.0, 2.0, 0.1), drop(1.0, 1.0, 0.06)]
+    }
 
-/// Macro to generate size methods (both width and height)
-macro_rules! size_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
-}
+    /// drop-shadow-md: 0 4px 3px rgb(0 0 0 / 0.07), 0 2px 2px rgb(0 0 0 / 0.06)
+    pub fn drop_shadow_md() -> [BoxShadow; 2] {
+        [drop(4.0, 3.0, 0.07), drop(2.0, 2.0, 0.06)]
+    }
 
-/// Macro to implement size methods
-macro_rules! impl_size_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.width($value).height($value)
-            }
-        )*
-    };
-}
+    /// drop-shadow-lg: 0 10px 8px rgb(0 0 0 / 0.04), 0 4px 3px rgb(0 0 0 / 0.1)
+    pub fn drop_shadow_lg() -> [BoxShadow; 2] {
+        [drop(10.0, 8.0, 0.04), drop(4.0, 3.0, 0.1)]
+    }
 
-/// Macro to generate min-width methods
-macro_rules! min_width_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+    /// drop-shadow-xl: 0 20px 13px rgb(0 0 0 / 0.03), 0 8px 5px rgb(0 0 0 / 0.08)
+    pub fn drop_shadow_xl() -> [BoxShadow; 2] {
+        [drop(20.0, 13.0, 0.03), drop(8.0, 5.0, 0.08)]
+    }
+
+    /// drop-shadow-2xl: 0 25px 25px rgb(0 0 0 / 0.15)
+    pub fn drop_shadow_2xl() -> [BoxShadow; 1] {
+        [drop(25.0, 25.0, 0.15)]
+    }
 }
 
-/// Macro to implement min-width methods
-macro_rules! impl_min_width_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.min_width($value)
+/// A class name → style table generated by `utilities!`
+type UtilityTable = &'static [(&'static str, fn(Style) -> Style)];
+
+/// Macro to declare a utility trait from a table of `method "class": style calls;` rows
+///
+/// Each table generates the trait, its impl for every [`StyleBuilder`], and a
+/// `(class, style)` lookup table for [`utilities`], so method names, class
+/// names, and styles can't drift apart. Methods that take arguments go in the
+/// `extra` declarations and `impl` bodies, verbatim.
+macro_rules! utilities {
+    (
+        $(#[$meta:meta])*
+        pub trait $trait:ident => $table:ident, prefixed $prefixed:ident {
+            $(
+                $(#[$doc:meta])*
+                $name:ident $class:literal: $($method:ident($($arg:expr),*)).+;
+            )*
+        }
+        $(extra {
+            $($(#[$extra_doc:meta])* fn $extra:ident(self $(, $extra_arg:ident: $extra_ty:ty)*) -> Self;)*
+        } impl { $($body:tt)* })?
+    ) => {
+        $(#[$meta])*
+        pub trait $trait: Sized {
+            $(
+                $(#[$doc])*
+                fn $name(self) -> Self;
+            )*
+            $($(
+                $(#[$extra_doc])*
+                fn $extra(self $(, $extra_arg: $extra_ty)*) -> Self;
+            )*)?
+        }
+
+        impl<T: StyleBuilder> $trait for T {
+            $(
+                fn $name(self) -> Self {
+                    self.map_style(|s| {
+                        #[cfg(feature = "diagnostics")]
+                        diagnostics::check_name(&s, $class);
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(target: trace::TARGET, class = $class, "utility");
+                        s$(.$method($($arg),*))+
+                    })
+                }
+            )*
+            $($($body)*)?
+        }
+
+        const $table: UtilityTable = &[
+            $(($class, |s| s$(.$method($($arg),*))+),)*
+        ];
+
+        #[cfg(feature = "prefixed")]
+        paste::paste! {
+            #[doc = concat!("The [`", stringify!($trait), "`] utilities with a `tw_` prefix (`prefixed` feature).")]
+            pub trait $prefixed: $trait {
+                $(
+                    #[doc = concat!("`", $class, "`")]
+                    fn [<tw_ $name>](self) -> Self {
+                        <Self as $trait>::$name(self)
+                    }
+                )*
+                $($(
+                    #[doc = concat!("[`", stringify!($trait), "::", stringify!($extra), "`]")]
+                    fn [<tw_ $extra>](self $(, $extra_arg: $extra_ty)*) -> Self {
+                        <Self as $trait>::$extra(self $(, $extra_arg)*)
+                    }
+                )*)?
             }
-        )*
-    };
-}
 
-/// Macro to generate max-width methods
-macro_rules! max_width_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
+            impl<T: $trait> $prefixed for T {}
+        }
     };
 }
 
-/// Macro to implement max-width methods
-macro_rules! impl_max_width_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.max_width($value)
+/// Macro to define state shortcuts, `$state` being a `Style` state method such
+/// as `active`
+///
+/// Besides the listed shortcuts, `palette` generates one for each utility,
+/// family, and shade: `bg: background` gives `active_bg_blue_700` for
+/// `active:bg-blue-700`. The `@palette` rules walk the utilities and families
+/// one at a time, since a macro can't repeat over two lists at once.
+#[cfg(feature = "color")]
+macro_rules! state_shortcuts {
+    (
+        $(#[$meta:meta])*
+        pub trait $trait:ident => $state:ident, $table:ident, prefixed $prefixed:ident {
+            $($name:ident $class:literal: $method:ident($arg:expr);)*
+        }
+        palette { $($utility:ident: $setter:ident),+ } for $families:tt $shades:tt
+        extra $extra:tt impl $body:tt
+    ) => {
+        state_shortcuts! {
+            @palette [] [$($utility $setter)+] $families $families $shades
+            {
+                $(#[$meta])*
+                pub trait $trait => $state, $table, prefixed $prefixed {
+                    $($name $class: $method($arg);)*
+                }
             }
-        )*
+            extra $extra impl $body
+        }
     };
-}
-
-/// Macro to generate padding methods
-macro_rules! padding_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
+    // The shortcuts of one family for the current utility
+    (
+        @palette [$($done:tt)*] [$utility:ident $setter:ident $($utilities:tt)*]
+        [$family:ident $($families:ident)*] $all:tt [$($shade:literal)+]
+        $($rest:tt)*
+    ) => {
+        state_shortcuts! {
+            @palette [$($done)* $(($utility $setter $family $shade))+]
+            [$utility $setter $($utilities)*] [$($families)*] $all [$($shade)+]
+            $($rest)*
+        }
     };
-}
-
-/// Macro to implement padding methods
-macro_rules! impl_padding_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.padding($value)
-            }
-        )*
+    // Every family done: the next utility
+    (
+        @palette $done:tt [$utility:ident $setter:ident $($utilities:tt)*] [] $all:tt $shades:tt
+        $($rest:tt)*
+    ) => {
+        state_shortcuts! { @palette $done [$($utilities)*] $all $all $shades $($rest)* }
     };
-}
+    (
+        @palette [$(($utility:ident $setter:ident $family:ident $shade:literal))*] []
+        $families:tt $all:tt $shades:tt
+        {
+            $(#[$meta:meta])*
+            pub trait $trait:ident => $state:ident, $table:ident, prefixed $prefixed:ident {
+                $($name:ident $class:literal: $method:ident($arg:expr);)*
+            }
+        }
+        extra {
+            $($(#[$extra_doc:meta])* fn $extra:ident(self $(, $extra_arg:ident: $extra_ty:ty)*) -> Self;)*
+        } impl { $($body:tt)* }
+    ) => {
+        paste::paste! {
+            $(#[$meta])*
+            pub trait $trait: Sized {
+                $(
+                    #[doc = $class]
+                    fn $name(self) -> Self;
+                )*
+                $(
+                    #[doc = concat!(
+                        stringify!($state), ":", stringify!($utility), "-",
+                        stringify!($family), "-", stringify!($shade),
+                    )]
+                    fn [<$state _ $utility _ $family _ $shade>](self) -> Self;
+                )*
+                $(
+                    $(#[$extra_doc])*
+                    fn $extra(self $(, $extra_arg: $extra_ty)*) -> Self;
+                )*
+            }
 
-/// Macro to generate horizontal padding methods
-macro_rules! padding_x_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
-}
+            impl<T: StyleBuilder> $trait for T {
+                $(
+                    fn $name(self) -> Self {
+                        self.map_style(|s| s.$state(|s| s.$method($arg)))
+                    }
+                )*
+                $(
+                    fn [<$state _ $utility _ $family _ $shade>](self) -> Self {
+                        self.map_style(|s| s.$state(|s| s.$setter(colors::$family::[<C $shade>])))
+                    }
+                )*
+                $($body)*
+            }
 
-/// Macro to implement horizontal padding methods
-macro_rules! impl_padding_x_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.padding_horiz($value)
+            const $table: UtilityTable = &[
+                $(($class, |s| s.$state(|s| s.$method($arg))),)*
+                $((
+                    concat!(
+                        stringify!($state), ":", stringify!($utility), "-",
+                        stringify!($family), "-", stringify!($shade),
+                    ),
+                    |s| s.$state(|s| s.$setter(colors::$family::[<C $shade>])),
+                ),)*
+            ];
+
+            #[cfg(feature = "prefixed")]
+            #[doc = concat!("The [`", stringify!($trait), "`] shortcuts with a `tw_` prefix (`prefixed` feature).")]
+            pub trait $prefixed: $trait {
+                $(
+                    #[doc = concat!("`", $class, "`")]
+                    fn [<tw_ $name>](self) -> Self {
+                        <Self as $trait>::$name(self)
+                    }
+                )*
+                $(
+                    #[doc = concat!(
+                        "`", stringify!($state), ":", stringify!($utility), "-",
+                        stringify!($family), "-", stringify!($shade), "`",
+                    )]
+                    fn [<tw_ $state _ $utility _ $family _ $shade>](self) -> Self {
+                        <Self as $trait>::[<$state _ $utility _ $family _ $shade>](self)
+                    }
+                )*
+                $(
+                    #[doc = concat!("[`", stringify!($trait), "::", stringify!($extra), "`]")]
+                    fn [<tw_ $extra>](self $(, $extra_arg: $extra_ty)*) -> Self {
+                        <Self as $trait>::$extra(self $(, $extra_arg)*)
+                    }
+                )*
             }
-        )*
-    };
-}
 
-/// Macro to generate vertical padding methods
-macro_rules! padding_y_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
+            #[cfg(feature = "prefixed")]
+            impl<T: $trait> $prefixed for T {}
+        }
     };
 }
 
-/// Macro to implement vertical padding methods
-macro_rules! impl_padding_y_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.padding_vert($value)
+/// Macro to define aliases of other utility methods, and deprecated names
+/// that point to their replacement
+#[cfg(feature = "spacing")]
+macro_rules! aliases {
+    (
+        $(#[$meta:meta])*
+        pub trait $trait:ident, prefixed $prefixed:ident {
+            $($name:ident => $($method:ident($($arg:expr),*)).+;)*
+        }
+        deprecated { $($old:ident => $new:ident;)* }
+    ) => {
+        $(#[$meta])*
+        pub trait $trait: Sized {
+            $(
+                #[doc = concat!("Same as `", stringify!($($method($($arg),*)).+), "`")]
+                fn $name(self) -> Self;
+            )*
+            $(
+                #[doc = concat!("Deprecated: use `", stringify!($new), "()`")]
+                #[deprecated = concat!("use `", stringify!($new), "()`")]
+                fn $old(self) -> Self;
+            )*
+        }
+
+        impl<T: StyleBuilder> $trait for T {
+            $(
+                fn $name(self) -> Self {
+                    self$(.$method($($arg),*))+
+                }
+            )*
+            $(
+                fn $old(self) -> Self {
+                    self.$new()
+                }
+            )*
+        }
+
+        #[cfg(feature = "prefixed")]
+        paste::paste! {
+            #[doc = concat!("The [`", stringify!($trait), "`] aliases with a `tw_` prefix (`prefixed` feature).")]
+            pub trait $prefixed: $trait {
+                $(
+                    #[doc = concat!("Same as `", stringify!($($method($($arg),*)).+), "`")]
+                    fn [<tw_ $name>](self) -> Self {
+                        <Self as $trait>::$name(self)
+                    }
+                )*
             }
-        )*
-    };
-}
 
-/// Macro to generate margin methods
-macro_rules! margin_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
+            impl<T: $trait> $prefixed for T {}
+        }
     };
 }
 
-/// Macro to implement margin methods
-macro_rules! impl_margin_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.margin($value)
-            }
-        )*
-    };
+/// Style types that the Tailwind utilities can be applied to.
+///
+/// Implemented for `Style` and for the custom style builders of Floem's
+/// widgets, so `TailwindExt` methods also work inside `custom_style` closures:
+/// `label(|| "Hi").custom_style(|s| s.selectable(false).text_sm().p_2())`.
+///
+/// This is the extension point for other builders: every utility is a
+/// function of a `Style`, so a type that can hand its `Style` to a closure
+/// gets all of them.
+///
+/// ```rust
+/// use floem::style::Style;
+/// use floem_tailwind::prelude::*;
+/// use floem_tailwind::StyleBuilder;
+///
+/// /// A card builder that owns its `Style` along with its title
+/// struct Card {
+///     title: String,
+///     style: Style,
+/// }
+///
+/// impl StyleBuilder for Card {
+///     fn map_style(self, f: impl FnOnce(Style) -> Style) -> Self {
+///         Card { style: f(self.style), ..self }
+///     }
+/// }
+///
+/// let card = Card { title: "Inbox".to_string(), style: Style::new() }.p_4().rounded_md();
+/// assert_eq!(card.title, "Inbox");
+/// ```
+pub trait StyleBuilder: Sized {
+    /// Transform the underlying `Style`
+    fn map_style(self, f: impl FnOnce(Style) -> Style) -> Self;
 }
 
-/// Macro to generate horizontal margin methods
-macro_rules! margin_x_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+impl StyleBuilder for Style {
+    fn map_style(self, f: impl FnOnce(Style) -> Style) -> Self {
+        f(self)
+    }
 }
 
-/// Macro to implement horizontal margin methods
-macro_rules! impl_margin_x_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
+/// Macro to implement `StyleBuilder` for Floem `CustomStyle` types
+macro_rules! impl_custom_style_builder {
+    ($($ty:ty),* $(,)?) => {
         $(
-            fn $name(self) -> Self {
-                self.margin_horiz($value)
+            impl StyleBuilder for $ty {
+                fn map_style(self, f: impl FnOnce(Style) -> Style) -> Self {
+                    floem::style::CustomStyle::style(self, f)
+                }
             }
         )*
     };
 }
 
-/// Macro to generate vertical margin methods
-macro_rules! margin_y_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+impl_custom_style_builder!(
+    floem::views::LabelCustomStyle,
+    floem::views::scroll::ScrollCustomStyle,
+    floem::views::slider::SliderCustomStyle,
+    floem::views::ToggleButtonCustomStyle,
+    floem::views::dropdown::DropdownCustomStyle,
+);
+
+/// Tailwind's delay-* scale for Floem animations
+///
+/// Delaying each item of a list by a step more than the previous one gives a
+/// staggered reveal:
+///
+/// ```rust
+/// use floem::animate::Animation;
+/// use floem_tailwind::duration;
+/// use floem_tailwind::prelude::*;
+///
+/// let first = Animation::new().delay_0();
+/// let third = Animation::new().delay(duration::DURATION_75 * 2);
+/// ```
+///
+/// Floem style transitions start immediately and have no delay.
+pub trait TailwindAnimationExt: Sized {
+    /// delay-0 (0ms)
+    fn delay_0(self) -> Self;
+    /// delay-75 (75ms)
+    fn delay_75(self) -> Self;
+    /// delay-100 (100ms)
+    fn delay_100(self) -> Self;
+    /// delay-150 (150ms)
+    fn delay_150(self) -> Self;
+    /// delay-200 (200ms)
+    fn delay_200(self) -> Self;
+    /// delay-300 (300ms)
+    fn delay_300(self) -> Self;
+    /// delay-500 (500ms)
+    fn delay_500(self) -> Self;
+    /// delay-700 (700ms)
+    fn delay_700(self) -> Self;
+    /// delay-1000 (1000ms)
+    fn delay_1000(self) -> Self;
 }
 
-/// Macro to implement vertical margin methods
-macro_rules! impl_margin_y_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.margin_vert($value)
-            }
-        )*
-    };
+impl TailwindAnimationExt for floem::animate::Animation {
+    fn delay_0(self) -> Self {
+        self.delay(duration::DURATION_0)
+    }
+    fn delay_75(self) -> Self {
+        self.delay(duration::DURATION_75)
+    }
+    fn delay_100(self) -> Self {
+        self.delay(duration::DURATION_100)
+    }
+    fn delay_150(self) -> Self {
+        self.delay(duration::DURATION_150)
+    }
+    fn delay_200(self) -> Self {
+        self.delay(duration::DURATION_200)
+    }
+    fn delay_300(self) -> Self {
+        self.delay(duration::DURATION_300)
+    }
+    fn delay_500(self) -> Self {
+        self.delay(duration::DURATION_500)
+    }
+    fn delay_700(self) -> Self {
+        self.delay(duration::DURATION_700)
+    }
+    fn delay_1000(self) -> Self {
+        self.delay(duration::DURATION_1000)
+    }
 }
 
-/// Macro to generate gap methods
-macro_rules! gap_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+utilities! {
+    /// Extension trait that adds Tailwind-style utility methods to Floem's Style
+    /// and widget custom styles.
+    ///
+    /// Holds the border, effect, and interactivity utilities; the remaining groups
+    /// are in the feature-gated sub-traits below, all re-exported by [`prelude`].
+    pub trait TailwindExt => CORE_UTILITIES, prefixed TwExt {
+        // === Border Radius Methods ===
+        rounded_none "rounded-none": border_radius(radius::scale(radius::ROUNDED_NONE));
+        rounded_sm "rounded-sm": border_radius(radius::scale(radius::ROUNDED_SM));
+        rounded "rounded": border_radius(radius::scale(radius::ROUNDED));
+        rounded_md "rounded-md": border_radius(radius::scale(radius::ROUNDED_MD));
+        rounded_lg "rounded-lg": border_radius(radius::scale(radius::ROUNDED_LG));
+        rounded_xl "rounded-xl": border_radius(radius::scale(radius::ROUNDED_XL));
+        rounded_2xl "rounded-2xl": border_radius(radius::scale(radius::ROUNDED_2XL));
+        rounded_3xl "rounded-3xl": border_radius(radius::scale(radius::ROUNDED_3XL));
+        rounded_full "rounded-full": border_radius(radius::scale(radius::ROUNDED_FULL));
+
+        // Logical corners, resolved by [`direction`]
+        rounded_s_none "rounded-s-none": apply(direction::rounded_start(radius::scale(radius::ROUNDED_NONE)));
+        rounded_s_sm "rounded-s-sm": apply(direction::rounded_start(radius::scale(radius::ROUNDED_SM)));
+        rounded_s "rounded-s": apply(direction::rounded_start(radius::scale(radius::ROUNDED)));
+        rounded_s_md "rounded-s-md": apply(direction::rounded_start(radius::scale(radius::ROUNDED_MD)));
+        rounded_s_lg "rounded-s-lg": apply(direction::rounded_start(radius::scale(radius::ROUNDED_LG)));
+        rounded_s_xl "rounded-s-xl": apply(direction::rounded_start(radius::scale(radius::ROUNDED_XL)));
+        rounded_s_2xl "rounded-s-2xl": apply(direction::rounded_start(radius::scale(radius::ROUNDED_2XL)));
+        rounded_s_3xl "rounded-s-3xl": apply(direction::rounded_start(radius::scale(radius::ROUNDED_3XL)));
+        rounded_s_full "rounded-s-full": apply(direction::rounded_start(radius::scale(radius::ROUNDED_FULL)));
+
+        rounded_e_none "rounded-e-none": apply(direction::rounded_end(radius::scale(radius::ROUNDED_NONE)));
+        rounded_e_sm "rounded-e-sm": apply(direction::rounded_end(radius::scale(radius::ROUNDED_SM)));
+        rounded_e "rounded-e": apply(direction::rounded_end(radius::scale(radius::ROUNDED)));
+        rounded_e_md "rounded-e-md": apply(direction::rounded_end(radius::scale(radius::ROUNDED_MD)));
+        rounded_e_lg "rounded-e-lg": apply(direction::rounded_end(radius::scale(radius::ROUNDED_LG)));
+        rounded_e_xl "rounded-e-xl": apply(direction::rounded_end(radius::scale(radius::ROUNDED_XL)));
+        rounded_e_2xl "rounded-e-2xl": apply(direction::rounded_end(radius::scale(radius::ROUNDED_2XL)));
+        rounded_e_3xl "rounded-e-3xl": apply(direction::rounded_end(radius::scale(radius::ROUNDED_3XL)));
+        rounded_e_full "rounded-e-full": apply(direction::rounded_end(radius::scale(radius::ROUNDED_FULL)));
+
+        // === Border Width Methods ===
+        border_0 "border-0": border(0.0);
+        border_1 "border": border(dpi::snap(1.0));
+        border_2 "border-2": border(dpi::snap(2.0));
+        border_4 "border-4": border(dpi::snap(4.0));
+        border_8 "border-8": border(dpi::snap(8.0));
+
+        // === Shadow Methods ===
+        shadow_sm "shadow-sm": apply_box_shadows([shadow::shadow_sm()].as_slice());
+        shadow "shadow": apply_box_shadows([shadow::shadow_default()].as_slice());
+        shadow_md "shadow-md": apply_box_shadows([shadow::shadow_md()].as_slice());
+        shadow_lg "shadow-lg": apply_box_shadows([shadow::shadow_lg()].as_slice());
+        shadow_xl "shadow-xl": apply_box_shadows([shadow::shadow_xl()].as_slice());
+        shadow_2xl "shadow-2xl": apply_box_shadows([shadow::shadow_2xl()].as_slice());
+        shadow_none "shadow-none": apply_box_shadows(shadow::NONE);
+
+        // === Drop Shadow Methods ===
+        // Approximated with box shadows; see `shadow::drop_shadow_sm`
+        drop_shadow_sm "drop-shadow-sm": apply_box_shadows(shadow::drop_shadow_sm().as_slice());
+        drop_shadow "drop-shadow": apply_box_shadows(shadow::drop_shadow_default().as_slice());
+        drop_shadow_md "drop-shadow-md": apply_box_shadows(shadow::drop_shadow_md().as_slice());
+        drop_shadow_lg "drop-shadow-lg": apply_box_shadows(shadow::drop_shadow_lg().as_slice());
+        drop_shadow_xl "drop-shadow-xl": apply_box_shadows(shadow::drop_shadow_xl().as_slice());
+        drop_shadow_2xl "drop-shadow-2xl": apply_box_shadows(shadow::drop_shadow_2xl().as_slice());
+        drop_shadow_none "drop-shadow-none": apply_box_shadows(shadow::NONE);
+
+        // === Elevation Methods ===
+        /// elevation-0: flat surface (gray-50, hairline border)
+        elevation_0 "elevation-0": apply(presets::elevation(0));
+        /// elevation-1: white surface, shadow-sm, hairline border
+        elevation_1 "elevation-1": apply(presets::elevation(1));
+        /// elevation-2: white surface, shadow, faint border
+        elevation_2 "elevation-2": apply(presets::elevation(2));
+        /// elevation-3: white surface, shadow-md
+        elevation_3 "elevation-3": apply(presets::elevation(3));
+        /// elevation-4: white surface, shadow-lg
+        elevation_4 "elevation-4": apply(presets::elevation(4));
+        /// elevation-5: white surface, shadow-xl
+        elevation_5 "elevation-5": apply(presets::elevation(5));
+
+        // === Cursor Methods ===
+        cursor_pointer "cursor-pointer": cursor(floem::style::CursorStyle::Pointer);
+        cursor_default "cursor-default": cursor(floem::style::CursorStyle::Default);
+        cursor_text "cursor-text": cursor(floem::style::CursorStyle::Text);
+        cursor_move "cursor-move": cursor(floem::style::CursorStyle::Move);
+        cursor_grab "cursor-grab": cursor(floem::style::CursorStyle::Grab);
+        cursor_grabbing "cursor-grabbing": cursor(floem::style::CursorStyle::Grabbing);
+
+        // === Opacity Methods ===
+        /// opacity-0 (0%)
+        opacity_0 "opacity-0": set(floem::style::Opacity, 0.0);
+        /// opacity-5 (5%)
+        opacity_5 "opacity-5": set(floem::style::Opacity, 0.05);
+        /// opacity-10 (10%)
+        opacity_10 "opacity-10": set(floem::style::Opacity, 0.10);
+        /// opacity-15 (15%)
+        opacity_15 "opacity-15": set(floem::style::Opacity, 0.15);
+        /// opacity-20 (20%)
+        opacity_20 "opacity-20": set(floem::style::Opacity, 0.20);
+        /// opacity-25 (25%)
+        opacity_25 "opacity-25": set(floem::style::Opacity, 0.25);
+        /// opacity-30 (30%)
+        opacity_30 "opacity-30": set(floem::style::Opacity, 0.30);
+        /// opacity-35 (35%)
+        opacity_35 "opacity-35": set(floem::style::Opacity, 0.35);
+        /// opacity-40 (40%)
+        opacity_40 "opacity-40": set(floem::style::Opacity, 0.40);
+        /// opacity-45 (45%)
+        opacity_45 "opacity-45": set(floem::style::Opacity, 0.45);
+        /// opacity-50 (50%)
+        opacity_50 "opacity-50": set(floem::style::Opacity, 0.50);
+        /// opacity-55 (55%)
+        opacity_55 "opacity-55": set(floem::style::Opacity, 0.55);
+        /// opacity-60 (60%)
+        opacity_60 "opacity-60": set(floem::style::Opacity, 0.60);
+        /// opacity-65 (65%)
+        opacity_65 "opacity-65": set(floem::style::Opacity, 0.65);
+        /// opacity-70 (70%)
+        opacity_70 "opacity-70": set(floem::style::Opacity, 0.70);
+        /// opacity-75 (75%)
+        opacity_75 "opacity-75": set(floem::style::Opacity, 0.75);
+        /// opacity-80 (80%)
+        opacity_80 "opacity-80": set(floem::style::Opacity, 0.80);
+        /// opacity-85 (85%)
+        opacity_85 "opacity-85": set(floem::style::Opacity, 0.85);
+        /// opacity-90 (90%)
+        opacity_90 "opacity-90": set(floem::style::Opacity, 0.90);
+        /// opacity-95 (95%)
+        opacity_95 "opacity-95": set(floem::style::Opacity, 0.95);
+        /// opacity-100 (100%)
+        opacity_100 "opacity-100": set(floem::style::Opacity, 1.0);
+
+        // === Pointer Events Methods ===
+        /// pointer-events-none
+        pointer_events_none "pointer-events-none":
+            set(floem::style::PointerEventsProp,Some(floem::style::PointerEvents::None));
+        /// pointer-events-auto
+        pointer_events_auto "pointer-events-auto":
+            set(floem::style::PointerEventsProp,Some(floem::style::PointerEvents::Auto));
+
+        // === Outline Methods ===
+        /// outline-none (removes outline)
+        outline_none "outline-none": outline(0.0);
+        /// outline-offset-0 (0px)
+        outline_offset_0 "outline-offset-0": outline_offset(0.0);
+        /// outline-offset-1 (1px)
+        outline_offset_1 "outline-offset-1": outline_offset(1.0);
+        /// outline-offset-2 (2px)
+        outline_offset_2 "outline-offset-2": outline_offset(2.0);
+        /// outline-offset-4 (4px)
+        outline_offset_4 "outline-offset-4": outline_offset(4.0);
+        /// outline-offset-8 (8px)
+        outline_offset_8 "outline-offset-8": outline_offset(8.0);
+    }
+    extra {
+        // === Class Value Methods ===
+        // Named `apply_tw_*` because Floem's `Style::apply_class` takes a `StyleClass`.
+        /// Apply a single `TwClass` utility
+        fn apply_tw_class(self, class: TwClass) -> Self;
+        /// Apply `TwClass` utilities in order
+        fn apply_tw_classes(self, classes: &[TwClass]) -> Self;
+        /// Apply `style` only while the window is at least `breakpoint` wide,
+        /// like the `md:` prefix in Tailwind class names
+        ///
+        /// Reacts to resizes of the window tracked with
+        /// [`TailwindViewExt::track_window_size`]; see [`window`]. (Floem's
+        /// own `Style::responsive` uses different breakpoint widths.)
+        fn breakpoint(self, breakpoint: Breakpoint, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Apply `style` only in right-to-left layouts, like the `rtl:` prefix
+        /// in Tailwind class names; see [`direction`]
+        fn rtl(self, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Apply `style` only in dark mode, like the `dark:` prefix in
+        /// Tailwind class names; see [`theme`]
+        fn dark(self, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Apply `style` only in high-contrast mode, like the `contrast-more:`
+        /// prefix in Tailwind class names; see [`contrast`]
+        fn contrast_more(self, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Hover background one shade from the palette background already
+        /// set: darker in light mode, lighter in dark mode
+        ///
+        /// Replaces the usual `hover` pairing, so set the background first:
+        ///
+        /// ```rust
+        /// use floem::style::Style;
+        /// use floem_tailwind::prelude::*;
+        ///
+        /// // Same as .bg_blue_500().hover(|s| s.bg_blue_600())
+        /// let button = Style::new().bg_blue_500().auto_hover();
+        /// ```
+        ///
+        /// Backgrounds that aren't palette shades, and 950 (50 in dark mode),
+        /// get no hover style; [`presets::interactive`] works for any color.
+        fn auto_hover(self) -> Self;
+        /// Apply `style` only to odd rows, like the `odd:` prefix in Tailwind
+        /// class names
+        ///
+        /// Floem has no index-based selectors, so the view passes its index
+        /// in its list. Indices count from 0 and rows from 1, so the row at
+        /// index 0 is odd:
+        ///
+        /// ```rust
+        /// use floem::prelude::*;
+        /// use floem_tailwind::prelude::*;
+        ///
+        /// let rows = ["Alice", "Bob", "Carol"].into_iter().enumerate().map(|(i, name)| {
+        ///     label(move || name).style(move |s| s.px_4().py_2().even(i, |s| s.bg_gray_50()))
+        /// });
+        /// let table = v_stack_from_iter(rows);
+        /// ```
+        fn odd(self, index: usize, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Apply `style` only to even rows, like the `even:` prefix in
+        /// Tailwind class names; see [`odd`](TailwindExt::odd)
+        fn even(self, index: usize, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Apply `style` only to the first item of a group, like the `first:`
+        /// prefix in Tailwind class names; see [`group`]
+        fn first(self, index: usize, style: impl FnOnce(Style) -> Style) -> Self;
+        /// Apply `style` only to the last of `len` items, like the `last:`
+        /// prefix in Tailwind class names; see [`group`]
+        fn last(self, index: usize, len: usize, style: impl FnOnce(Style) -> Style) -> Self;
+
+        // === Focus Ring Methods ===
+        /// focus-visible:ring-2 focus-visible:ring-offset-2 focus-visible:ring-blue-500
+        ///
+        /// Shown only for keyboard focus, not when the view is clicked.
+        fn focus_ring(self) -> Self;
+        /// [`focus_ring`](TailwindExt::focus_ring) in another color
+        ///
+        /// The ring is a 2px box shadow around a white outline that fills the
+        /// [`outline_offset`](TailwindExt::outline_offset), 2px by default, so
+        /// it replaces the view's shadow while focused.
+        fn focus_ring_color(self, color: impl Into<Color>) -> Self;
+        /// Removes a focus ring, e.g. the one of [`presets::interactive`] and
+        /// the [`tw`] button and input classes
+        ///
+        /// While focused, the view keeps the shadows it has when this is
+        /// called.
+        fn no_focus_ring(self) -> Self;
+        /// Sets the gap between the view and its focus ring, in pixels
+        /// (`outline-offset-*`), whether the ring is added before or after
+        ///
+        /// Floem draws outlines against the view's edge, so the offset moves
+        /// the rings of [`focus_ring`](TailwindExt::focus_ring) and
+        /// [`focus_ring_color`](TailwindExt::focus_ring_color), not outlines
+        /// set directly.
+        fn outline_offset(self, offset: f64) -> Self;
+
+        // === Custom Shadow Methods ===
+        /// Adds a box shadow (`x` `y` `blur` `spread` `color`, in pixels) on
+        /// top of the view's shadows, so calls stack:
+        ///
+        /// ```rust
+        /// use floem::style::Style;
+        /// use floem_tailwind::colors;
+        /// use floem_tailwind::prelude::*;
+        ///
+        /// let raised = Style::new()
+        ///     .shadow_custom(0.0, 1.0, 2.0, 0.0, colors::alpha(colors::BLACK, 0.06))
+        ///     .shadow_custom(0.0, 8.0, 24.0, -4.0, colors::alpha(colors::BLACK, 0.12));
+        /// ```
+        ///
+        /// Start from `shadow_none()` to replace shadows set earlier.
+        fn shadow_custom(self, x: f64, y: f64, blur: f64, spread: f64, color: impl Into<Color>) -> Self;
+    } impl {
+        fn apply_tw_class(self, class: TwClass) -> Self {
+            self.map_style(|s| {
+                #[cfg(feature = "diagnostics")]
+                diagnostics::check(&s, class);
+                #[cfg(feature = "tracing")]
+                tracing::trace!(target: trace::TARGET, %class, "apply_tw_class");
+                class.apply(s)
+            })
+        }
+        fn apply_tw_classes(self, classes: &[TwClass]) -> Self {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!(
+                target: trace::TARGET,
+                "apply_tw_classes",
+                classes = %trace::ClassList(classes),
+            )
+            .entered();
+            self.map_style(|s| classes.iter().fold(s, |s, class| class.apply(s)))
+        }
+        fn breakpoint(self, breakpoint: Breakpoint, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if window::width() >= breakpoint.min_width() {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn rtl(self, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if direction::direction().is_rtl() {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn dark(self, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if theme::is_dark() {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn auto_hover(self) -> Self {
+            self.map_style(|s| {
+                let Some(peniko::Brush::Solid(background)) = s.get(floem::style::Background) else {
+                    return s;
+                };
+                match hover_shade(background, theme::is_dark()) {
+                    Some(hover) => s.hover(|s| s.background(hover)),
+                    None => s,
+                }
+            })
+        }
+        fn contrast_more(self, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if contrast::is_high_contrast() {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn odd(self, index: usize, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if index.is_multiple_of(2) {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn even(self, index: usize, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if !index.is_multiple_of(2) {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn first(self, index: usize, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if index == 0 {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn last(self, index: usize, len: usize, style: impl FnOnce(Style) -> Style) -> Self {
+            self.map_style(|s| {
+                if index + 1 == len {
+                    s.apply(style(Style::new()))
+                } else {
+                    s
+                }
+            })
+        }
+        fn focus_ring(self) -> Self {
+            self.focus_ring_color(colors::blue::C500)
+        }
+        fn focus_ring_color(self, color: impl Into<Color>) -> Self {
+            let color = color.into();
+            self.map_style(|s| focus_ring(s, color))
+        }
+        fn no_focus_ring(self) -> Self {
+            self.map_style(|s| {
+                let shadows = s.get(floem::style::BoxShadowProp);
+                s.set(FocusRingColor, None)
+                    .focus_visible(|s| s.outline(0.0).apply_box_shadows(shadows))
+            })
+        }
+        fn outline_offset(self, offset: f64) -> Self {
+            self.map_style(|s| outline_offset(s, offset))
+        }
+        fn shadow_custom(self, x: f64, y: f64, blur: f64, spread: f64, color: impl Into<Color>) -> Self {
+            let shadow = shadow::custom(x, y, blur, spread, color.into());
+            self.map_style(|s| {
+                let mut shadows = s.get(floem::style::BoxShadowProp);
+                shadows.push(shadow);
+                s.apply_box_shadows(shadows)
+            })
+        }
+    }
 }
 
-/// Macro to implement gap methods
-macro_rules! impl_gap_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.gap($value)
-            }
-        )*
-    };
+/// ring-offset-2: the gap between a focus ring and the view, in pixels
+const FOCUS_RING_OFFSET: f64 = 2.0;
+/// ring-2: the width of a focus ring, in pixels
+const FOCUS_RING_WIDTH: f64 = 2.0;
+
+floem::prop!(
+    /// The `outline-offset-*` of a view, if set; focus rings default to
+    /// [`FOCUS_RING_OFFSET`]
+    pub(crate) OutlineOffset: Option<f64> {} = None
+);
+
+floem::prop!(
+    /// The color of the view's focus ring, kept so a later offset can move it
+    FocusRingColor: Option<Color> {} = None
+);
+
+/// Draws a focus ring in `color` while the view has keyboard focus
+///
+/// The ring is a box shadow around a white outline as wide as the offset,
+/// which fills the gap.
+fn focus_ring(s: Style, color: Color) -> Style {
+    let offset = s.get(OutlineOffset).unwrap_or(FOCUS_RING_OFFSET);
+    let ring = shadow::ring(offset + FOCUS_RING_WIDTH, color);
+    s.set(FocusRingColor, Some(color)).focus_visible(|s| {
+        s.outline(offset)
+            .outline_color(colors::WHITE)
+            .apply_box_shadows([ring].as_slice())
+    })
 }
 
-/// Macro to generate border-radius methods
-macro_rules! rounded_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+/// The [`auto_hover`](TailwindExt::auto_hover) background for a palette
+/// background: one shade darker, or lighter in dark mode
+fn hover_shade(background: Color, dark: bool) -> Option<Color> {
+    if dark {
+        colors::one_lighter(background)
+    } else {
+        colors::one_darker(background)
+    }
 }
 
-/// Macro to implement border-radius methods
-macro_rules! impl_rounded_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.border_radius($value)
-            }
-        )*
-    };
+/// Sets the focus ring offset, moving a ring set earlier
+pub(crate) fn outline_offset(s: Style, offset: f64) -> Style {
+    let s = s.set(OutlineOffset, Some(offset));
+    match s.get(FocusRingColor) {
+        Some(color) => focus_ring(s, color),
+        None => s,
+    }
 }
 
-/// Macro to generate font-size methods
-macro_rules! font_size_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+#[cfg(feature = "spacing")]
+utilities! {
+    /// Sizing, padding, margin, and gap utilities (`spacing` feature).
+    pub trait TailwindSpacingExt => SPACING_UTILITIES, prefixed TwSpacingExt {
+        // === Width Methods ===
+        w_0 "w-0": width(0.0);
+        w_px "w-px": width(dpi::snap(1.0));
+        w_0p5 "w-0.5": width(rem::scale(spacing::SPACING_0_5));
+        w_1 "w-1": width(rem::scale(spacing::SPACING_1));
+        w_1p5 "w-1.5": width(rem::scale(spacing::SPACING_1_5));
+        w_2 "w-2": width(rem::scale(spacing::SPACING_2));
+        w_2p5 "w-2.5": width(rem::scale(spacing::SPACING_2_5));
+        w_3 "w-3": width(rem::scale(spacing::SPACING_3));
+        w_3p5 "w-3.5": width(rem::scale(spacing::SPACING_3_5));
+        w_4 "w-4": width(rem::scale(spacing::SPACING_4));
+        w_5 "w-5": width(rem::scale(spacing::SPACING_5));
+        w_6 "w-6": width(rem::scale(spacing::SPACING_6));
+        w_7 "w-7": width(rem::scale(spacing::SPACING_7));
+        w_8 "w-8": width(rem::scale(spacing::SPACING_8));
+        w_9 "w-9": width(rem::scale(spacing::SPACING_9));
+        w_10 "w-10": width(rem::scale(spacing::SPACING_10));
+        w_11 "w-11": width(rem::scale(spacing::SPACING_11));
+        w_12 "w-12": width(rem::scale(spacing::SPACING_12));
+        w_14 "w-14": width(rem::scale(spacing::SPACING_14));
+        w_16 "w-16": width(rem::scale(spacing::SPACING_16));
+        w_20 "w-20": width(rem::scale(spacing::SPACING_20));
+        w_24 "w-24": width(rem::scale(spacing::SPACING_24));
+        w_28 "w-28": width(rem::scale(spacing::SPACING_28));
+        w_32 "w-32": width(rem::scale(spacing::SPACING_32));
+        w_36 "w-36": width(rem::scale(spacing::SPACING_36));
+        w_40 "w-40": width(rem::scale(spacing::SPACING_40));
+        w_44 "w-44": width(rem::scale(spacing::SPACING_44));
+        w_48 "w-48": width(rem::scale(spacing::SPACING_48));
+        w_52 "w-52": width(rem::scale(spacing::SPACING_52));
+        w_56 "w-56": width(rem::scale(spacing::SPACING_56));
+        w_60 "w-60": width(rem::scale(spacing::SPACING_60));
+        w_64 "w-64": width(rem::scale(spacing::SPACING_64));
+        w_72 "w-72": width(rem::scale(spacing::SPACING_72));
+        w_80 "w-80": width(rem::scale(spacing::SPACING_80));
+        w_96 "w-96": width(rem::scale(spacing::SPACING_96));
+
+        // Named width sizes
+        w_xs "w-xs": width(rem::scale(spacing::SIZE_XS));
+        w_sm "w-sm": width(rem::scale(spacing::SIZE_SM));
+        w_md "w-md": width(rem::scale(spacing::SIZE_MD));
+        w_lg "w-lg": width(rem::scale(spacing::SIZE_LG));
+        w_xl "w-xl": width(rem::scale(spacing::SIZE_XL));
+        w_2xl "w-2xl": width(rem::scale(spacing::SIZE_2XL));
+        w_3xl "w-3xl": width(rem::scale(spacing::SIZE_3XL));
+        w_4xl "w-4xl": width(rem::scale(spacing::SIZE_4XL));
+        w_5xl "w-5xl": width(rem::scale(spacing::SIZE_5XL));
+        w_6xl "w-6xl": width(rem::scale(spacing::SIZE_6XL));
+        w_7xl "w-7xl": width(rem::scale(spacing::SIZE_7XL));
+
+        // Percentage widths
+        w_full "w-full": width(Pct(100.0));
+        w_auto "w-auto": width(PxPctAuto::Auto);
+        // Fractional widths (w_frac_1_2 = 1/2 = 50%; see TailwindFractionExt for aliases)
+        w_frac_1_2 "w-1/2": width(Pct(50.0)); // 1/2 = 50%
+        w_frac_1_3 "w-1/3": width(Pct(33.333333)); // 1/3 = 33.33%
+        w_frac_2_3 "w-2/3": width(Pct(66.666667)); // 2/3 = 66.67%
+        w_frac_1_4 "w-1/4": width(Pct(25.0)); // 1/4 = 25%
+        w_frac_3_4 "w-3/4": width(Pct(75.0)); // 3/4 = 75%
+        w_frac_1_5 "w-1/5": width(Pct(20.0)); // 1/5 = 20%
+        w_frac_2_5 "w-2/5": width(Pct(40.0)); // 2/5 = 40%
+        w_frac_3_5 "w-3/5": width(Pct(60.0)); // 3/5 = 60%
+        w_frac_4_5 "w-4/5": width(Pct(80.0)); // 4/5 = 80%
+        w_frac_1_6 "w-1/6": width(Pct(16.666667)); // 1/6 = 16.67%
+        w_frac_5_6 "w-5/6": width(Pct(83.333333)); // 5/6 = 83.33%
+        w_frac_1_12 "w-1/12": width(Pct(8.333333)); // 1/12 = 8.33%
+
+        // === Height Methods ===
+        h_0 "h-0": height(0.0);
+        h_px "h-px": height(dpi::snap(1.0));
+        h_0p5 "h-0.5": height(rem::scale(spacing::SPACING_0_5));
+        h_1 "h-1": height(rem::scale(spacing::SPACING_1));
+        h_1p5 "h-1.5": height(rem::scale(spacing::SPACING_1_5));
+        h_2 "h-2": height(rem::scale(spacing::SPACING_2));
+        h_2p5 "h-2.5": height(rem::scale(spacing::SPACING_2_5));
+        h_3 "h-3": height(rem::scale(spacing::SPACING_3));
+        h_3p5 "h-3.5": height(rem::scale(spacing::SPACING_3_5));
+        h_4 "h-4": height(rem::scale(spacing::SPACING_4));
+        h_5 "h-5": height(rem::scale(spacing::SPACING_5));
+        h_6 "h-6": height(rem::scale(spacing::SPACING_6));
+        h_7 "h-7": height(rem::scale(spacing::SPACING_7));
+        h_8 "h-8": height(rem::scale(spacing::SPACING_8));
+        h_9 "h-9": height(rem::scale(spacing::SPACING_9));
+        h_10 "h-10": height(rem::scale(spacing::SPACING_10));
+        h_11 "h-11": height(rem::scale(spacing::SPACING_11));
+        h_12 "h-12": height(rem::scale(spacing::SPACING_12));
+        h_14 "h-14": height(rem::scale(spacing::SPACING_14));
+        h_16 "h-16": height(rem::scale(spacing::SPACING_16));
+        h_20 "h-20": height(rem::scale(spacing::SPACING_20));
+        h_24 "h-24": height(rem::scale(spacing::SPACING_24));
+        h_28 "h-28": height(rem::scale(spacing::SPACING_28));
+        h_32 "h-32": height(rem::scale(spacing::SPACING_32));
+        h_36 "h-36": height(rem::scale(spacing::SPACING_36));
+        h_40 "h-40": height(rem::scale(spacing::SPACING_40));
+        h_44 "h-44": height(rem::scale(spacing::SPACING_44));
+        h_48 "h-48": height(rem::scale(spacing::SPACING_48));
+        h_52 "h-52": height(rem::scale(spacing::SPACING_52));
+        h_56 "h-56": height(rem::scale(spacing::SPACING_56));
+        h_60 "h-60": height(rem::scale(spacing::SPACING_60));
+        h_64 "h-64": height(rem::scale(spacing::SPACING_64));
+        h_72 "h-72": height(rem::scale(spacing::SPACING_72));
+        h_80 "h-80": height(rem::scale(spacing::SPACING_80));
+        h_96 "h-96": height(rem::scale(spacing::SPACING_96));
+
+        // Named height sizes
+        h_xs "h-xs": height(rem::scale(spacing::SIZE_XS));
+        h_sm "h-sm": height(rem::scale(spacing::SIZE_SM));
+        h_md "h-md": height(rem::scale(spacing::SIZE_MD));
+        h_lg "h-lg": height(rem::scale(spacing::SIZE_LG));
+        h_xl "h-xl": height(rem::scale(spacing::SIZE_XL));
+        h_2xl "h-2xl": height(rem::scale(spacing::SIZE_2XL));
+        h_3xl "h-3xl": height(rem::scale(spacing::SIZE_3XL));
+        h_4xl "h-4xl": height(rem::scale(spacing::SIZE_4XL));
+        h_5xl "h-5xl": height(rem::scale(spacing::SIZE_5XL));
+        h_6xl "h-6xl": height(rem::scale(spacing::SIZE_6XL));
+        h_7xl "h-7xl": height(rem::scale(spacing::SIZE_7XL));
+
+        // Percentage heights
+        h_full "h-full": height(Pct(100.0));
+        h_auto "h-auto": height(PxPctAuto::Auto);
+        // Fractional heights (h_frac_1_2 = 1/2 = 50%; see TailwindFractionExt for aliases)
+        h_frac_1_2 "h-1/2": height(Pct(50.0)); // 1/2 = 50%
+        h_frac_1_3 "h-1/3": height(Pct(33.333333)); // 1/3 = 33.33%
+        h_frac_2_3 "h-2/3": height(Pct(66.666667)); // 2/3 = 66.67%
+        h_frac_1_4 "h-1/4": height(Pct(25.0)); // 1/4 = 25%
+        h_frac_3_4 "h-3/4": height(Pct(75.0)); // 3/4 = 75%
+        h_frac_1_5 "h-1/5": height(Pct(20.0)); // 1/5 = 20%
+        h_frac_2_5 "h-2/5": height(Pct(40.0)); // 2/5 = 40%
+        h_frac_3_5 "h-3/5": height(Pct(60.0)); // 3/5 = 60%
+        h_frac_4_5 "h-4/5": height(Pct(80.0)); // 4/5 = 80%
+        h_frac_1_6 "h-1/6": height(Pct(16.666667)); // 1/6 = 16.67%
+        h_frac_5_6 "h-5/6": height(Pct(83.333333)); // 5/6 = 83.33%
+        h_frac_1_12 "h-1/12": height(Pct(8.333333)); // 1/12 = 8.33%
+
+        // === Size Methods (width + height) ===
+        size_0 "size-0": width(0.0).height(0.0);
+        size_px "size-px": width(dpi::snap(1.0)).height(dpi::snap(1.0));
+        size_0p5 "size-0.5": width(rem::scale(spacing::SPACING_0_5)).height(rem::scale(spacing::SPACING_0_5));
+        size_1 "size-1": width(rem::scale(spacing::SPACING_1)).height(rem::scale(spacing::SPACING_1));
+        size_1p5 "size-1.5": width(rem::scale(spacing::SPACING_1_5)).height(rem::scale(spacing::SPACING_1_5));
+        size_2 "size-2": width(rem::scale(spacing::SPACING_2)).height(rem::scale(spacing::SPACING_2));
+        size_2p5 "size-2.5": width(rem::scale(spacing::SPACING_2_5)).height(rem::scale(spacing::SPACING_2_5));
+        size_3 "size-3": width(rem::scale(spacing::SPACING_3)).height(rem::scale(spacing::SPACING_3));
+        size_3p5 "size-3.5": width(rem::scale(spacing::SPACING_3_5)).height(rem::scale(spacing::SPACING_3_5));
+        size_4 "size-4": width(rem::scale(spacing::SPACING_4)).height(rem::scale(spacing::SPACING_4));
+        size_5 "size-5": width(rem::scale(spacing::SPACING_5)).height(rem::scale(spacing::SPACING_5));
+        size_6 "size-6": width(rem::scale(spacing::SPACING_6)).height(rem::scale(spacing::SPACING_6));
+        size_7 "size-7": width(rem::scale(spacing::SPACING_7)).height(rem::scale(spacing::SPACING_7));
+        size_8 "size-8": width(rem::scale(spacing::SPACING_8)).height(rem::scale(spacing::SPACING_8));
+        size_9 "size-9": width(rem::scale(spacing::SPACING_9)).height(rem::scale(spacing::SPACING_9));
+        size_10 "size-10": width(rem::scale(spacing::SPACING_10)).height(rem::scale(spacing::SPACING_10));
+        size_11 "size-11": width(rem::scale(spacing::SPACING_11)).height(rem::scale(spacing::SPACING_11));
+        size_12 "size-12": width(rem::scale(spacing::SPACING_12)).height(rem::scale(spacing::SPACING_12));
+        size_14 "size-14": width(rem::scale(spacing::SPACING_14)).height(rem::scale(spacing::SPACING_14));
+        size_16 "size-16": width(rem::scale(spacing::SPACING_16)).height(rem::scale(spacing::SPACING_16));
+        size_20 "size-20": width(rem::scale(spacing::SPACING_20)).height(rem::scale(spacing::SPACING_20));
+        size_24 "size-24": width(rem::scale(spacing::SPACING_24)).height(rem::scale(spacing::SPACING_24));
+        size_28 "size-28": width(rem::scale(spacing::SPACING_28)).height(rem::scale(spacing::SPACING_28));
+        size_32 "size-32": width(rem::scale(spacing::SPACING_32)).height(rem::scale(spacing::SPACING_32));
+        size_36 "size-36": width(rem::scale(spacing::SPACING_36)).height(rem::scale(spacing::SPACING_36));
+        size_40 "size-40": width(rem::scale(spacing::SPACING_40)).height(rem::scale(spacing::SPACING_40));
+        size_44 "size-44": width(rem::scale(spacing::SPACING_44)).height(rem::scale(spacing::SPACING_44));
+        size_48 "size-48": width(rem::scale(spacing::SPACING_48)).height(rem::scale(spacing::SPACING_48));
+        size_52 "size-52": width(rem::scale(spacing::SPACING_52)).height(rem::scale(spacing::SPACING_52));
+        size_56 "size-56": width(rem::scale(spacing::SPACING_56)).height(rem::scale(spacing::SPACING_56));
+        size_60 "size-60": width(rem::scale(spacing::SPACING_60)).height(rem::scale(spacing::SPACING_60));
+        size_64 "size-64": width(rem::scale(spacing::SPACING_64)).height(rem::scale(spacing::SPACING_64));
+        size_72 "size-72": width(rem::scale(spacing::SPACING_72)).height(rem::scale(spacing::SPACING_72));
+        size_80 "size-80": width(rem::scale(spacing::SPACING_80)).height(rem::scale(spacing::SPACING_80));
+        size_96 "size-96": width(rem::scale(spacing::SPACING_96)).height(rem::scale(spacing::SPACING_96));
+
+        // Named sizes
+        size_xs "size-xs": width(rem::scale(spacing::SIZE_XS)).height(rem::scale(spacing::SIZE_XS));
+        size_sm "size-sm": width(rem::scale(spacing::SIZE_SM)).height(rem::scale(spacing::SIZE_SM));
+        size_md "size-md": width(rem::scale(spacing::SIZE_MD)).height(rem::scale(spacing::SIZE_MD));
+        size_lg "size-lg": width(rem::scale(spacing::SIZE_LG)).height(rem::scale(spacing::SIZE_LG));
+        size_xl "size-xl": width(rem::scale(spacing::SIZE_XL)).height(rem::scale(spacing::SIZE_XL));
+        size_2xl "size-2xl": width(rem::scale(spacing::SIZE_2XL)).height(rem::scale(spacing::SIZE_2XL));
+        size_3xl "size-3xl": width(rem::scale(spacing::SIZE_3XL)).height(rem::scale(spacing::SIZE_3XL));
+        size_4xl "size-4xl": width(rem::scale(spacing::SIZE_4XL)).height(rem::scale(spacing::SIZE_4XL));
+        size_5xl "size-5xl": width(rem::scale(spacing::SIZE_5XL)).height(rem::scale(spacing::SIZE_5XL));
+        size_6xl "size-6xl": width(rem::scale(spacing::SIZE_6XL)).height(rem::scale(spacing::SIZE_6XL));
+        size_7xl "size-7xl": width(rem::scale(spacing::SIZE_7XL)).height(rem::scale(spacing::SIZE_7XL));
+
+        // Percentage sizes
+        size_full "size-full": width(Pct(100.0)).height(Pct(100.0));
+        size_auto "size-auto": width(PxPctAuto::Auto).height(PxPctAuto::Auto);
+        // Fractional sizes (size_frac_1_2 = 1/2 = 50%; see TailwindFractionExt for aliases)
+        size_frac_1_2 "size-1/2": width(Pct(50.0)).height(Pct(50.0)); // 1/2 = 50%
+        size_frac_1_3 "size-1/3": width(Pct(33.333333)).height(Pct(33.333333)); // 1/3 = 33.33%
+        size_frac_2_3 "size-2/3": width(Pct(66.666667)).height(Pct(66.666667)); // 2/3 = 66.67%
+        size_frac_1_4 "size-1/4": width(Pct(25.0)).height(Pct(25.0)); // 1/4 = 25%
+        size_frac_3_4 "size-3/4": width(Pct(75.0)).height(Pct(75.0)); // 3/4 = 75%
+        size_frac_1_5 "size-1/5": width(Pct(20.0)).height(Pct(20.0)); // 1/5 = 20%
+        size_frac_2_5 "size-2/5": width(Pct(40.0)).height(Pct(40.0)); // 2/5 = 40%
+        size_frac_3_5 "size-3/5": width(Pct(60.0)).height(Pct(60.0)); // 3/5 = 60%
+        size_frac_4_5 "size-4/5": width(Pct(80.0)).height(Pct(80.0)); // 4/5 = 80%
+        size_frac_1_6 "size-1/6": width(Pct(16.666667)).height(Pct(16.666667)); // 1/6 = 16.67%
+        size_frac_5_6 "size-5/6": width(Pct(83.333333)).height(Pct(83.333333)); // 5/6 = 83.33%
+        size_frac_1_12 "size-1/12": width(Pct(8.333333)).height(Pct(8.333333)); // 1/12 = 8.33%
+
+        // === Min-Width Methods ===
+        min_w_0 "min-w-0": min_width(0.0);
+        min_w_px "min-w-px": min_width(dpi::snap(1.0));
+        min_w_0p5 "min-w-0.5": min_width(rem::scale(spacing::SPACING_0_5));
+        min_w_1 "min-w-1": min_width(rem::scale(spacing::SPACING_1));
+        min_w_1p5 "min-w-1.5": min_width(rem::scale(spacing::SPACING_1_5));
+        min_w_2 "min-w-2": min_width(rem::scale(spacing::SPACING_2));
+        min_w_2p5 "min-w-2.5": min_width(rem::scale(spacing::SPACING_2_5));
+        min_w_3 "min-w-3": min_width(rem::scale(spacing::SPACING_3));
+        min_w_3p5 "min-w-3.5": min_width(rem::scale(spacing::SPACING_3_5));
+        min_w_4 "min-w-4": min_width(rem::scale(spacing::SPACING_4));
+        min_w_5 "min-w-5": min_width(rem::scale(spacing::SPACING_5));
+        min_w_6 "min-w-6": min_width(rem::scale(spacing::SPACING_6));
+        min_w_7 "min-w-7": min_width(rem::scale(spacing::SPACING_7));
+        min_w_8 "min-w-8": min_width(rem::scale(spacing::SPACING_8));
+        min_w_9 "min-w-9": min_width(rem::scale(spacing::SPACING_9));
+        min_w_10 "min-w-10": min_width(rem::scale(spacing::SPACING_10));
+        min_w_11 "min-w-11": min_width(rem::scale(spacing::SPACING_11));
+        min_w_12 "min-w-12": min_width(rem::scale(spacing::SPACING_12));
+        min_w_14 "min-w-14": min_width(rem::scale(spacing::SPACING_14));
+        min_w_16 "min-w-16": min_width(rem::scale(spacing::SPACING_16));
+        min_w_20 "min-w-20": min_width(rem::scale(spacing::SPACING_20));
+        min_w_24 "min-w-24": min_width(rem::scale(spacing::SPACING_24));
+        min_w_28 "min-w-28": min_width(rem::scale(spacing::SPACING_28));
+        min_w_32 "min-w-32": min_width(rem::scale(spacing::SPACING_32));
+        min_w_36 "min-w-36": min_width(rem::scale(spacing::SPACING_36));
+        min_w_40 "min-w-40": min_width(rem::scale(spacing::SPACING_40));
+        min_w_44 "min-w-44": min_width(rem::scale(spacing::SPACING_44));
+        min_w_48 "min-w-48": min_width(rem::scale(spacing::SPACING_48));
+        min_w_52 "min-w-52": min_width(rem::scale(spacing::SPACING_52));
+        min_w_56 "min-w-56": min_width(rem::scale(spacing::SPACING_56));
+        min_w_60 "min-w-60": min_width(rem::scale(spacing::SPACING_60));
+        min_w_64 "min-w-64": min_width(rem::scale(spacing::SPACING_64));
+        min_w_72 "min-w-72": min_width(rem::scale(spacing::SPACING_72));
+        min_w_80 "min-w-80": min_width(rem::scale(spacing::SPACING_80));
+        min_w_96 "min-w-96": min_width(rem::scale(spacing::SPACING_96));
+        min_w_full "min-w-full": min_width(Pct(100.0));
+        min_w_xs "min-w-xs": min_width(rem::scale(spacing::SIZE_XS));
+        min_w_sm "min-w-sm": min_width(rem::scale(spacing::SIZE_SM));
+        min_w_md "min-w-md": min_width(rem::scale(spacing::SIZE_MD));
+        min_w_lg "min-w-lg": min_width(rem::scale(spacing::SIZE_LG));
+        min_w_xl "min-w-xl": min_width(rem::scale(spacing::SIZE_XL));
+        min_w_2xl "min-w-2xl": min_width(rem::scale(spacing::SIZE_2XL));
+        min_w_3xl "min-w-3xl": min_width(rem::scale(spacing::SIZE_3XL));
+        min_w_4xl "min-w-4xl": min_width(rem::scale(spacing::SIZE_4XL));
+        min_w_5xl "min-w-5xl": min_width(rem::scale(spacing::SIZE_5XL));
+        min_w_6xl "min-w-6xl": min_width(rem::scale(spacing::SIZE_6XL));
+        min_w_7xl "min-w-7xl": min_width(rem::scale(spacing::SIZE_7XL));
+        // min-w-min/max/fit need intrinsic sizing, which Taffy dimensions lack
+
+        // === Max-Width Methods ===
+        max_w_0 "max-w-0": max_width(0.0);
+        max_w_px "max-w-px": max_width(dpi::snap(1.0));
+        max_w_1 "max-w-1": max_width(rem::scale(spacing::SPACING_1));
+        max_w_2 "max-w-2": max_width(rem::scale(spacing::SPACING_2));
+        max_w_4 "max-w-4": max_width(rem::scale(spacing::SPACING_4));
+        max_w_8 "max-w-8": max_width(rem::scale(spacing::SPACING_8));
+        max_w_16 "max-w-16": max_width(rem::scale(spacing::SPACING_16));
+        max_w_32 "max-w-32": max_width(rem::scale(spacing::SPACING_32));
+        max_w_64 "max-w-64": max_width(rem::scale(spacing::SPACING_64));
+        max_w_96 "max-w-96": max_width(rem::scale(spacing::SPACING_96));
+        max_w_full "max-w-full": max_width(Pct(100.0));
+        max_w_xs "max-w-xs": max_width(rem::scale(spacing::SIZE_XS));
+        max_w_sm "max-w-sm": max_width(rem::scale(spacing::SIZE_SM));
+        max_w_md "max-w-md": max_width(rem::scale(spacing::SIZE_MD));
+        max_w_lg "max-w-lg": max_width(rem::scale(spacing::SIZE_LG));
+        max_w_xl "max-w-xl": max_width(rem::scale(spacing::SIZE_XL));
+        max_w_2xl "max-w-2xl": max_width(rem::scale(spacing::SIZE_2XL));
+        max_w_3xl "max-w-3xl": max_width(rem::scale(spacing::SIZE_3XL));
+        max_w_4xl "max-w-4xl": max_width(rem::scale(spacing::SIZE_4XL));
+        max_w_5xl "max-w-5xl": max_width(rem::scale(spacing::SIZE_5XL));
+        max_w_6xl "max-w-6xl": max_width(rem::scale(spacing::SIZE_6XL));
+        max_w_7xl "max-w-7xl": max_width(rem::scale(spacing::SIZE_7XL));
+        // Breakpoint widths
+        max_w_screen_sm "max-w-screen-sm": max_width(screen::SM);
+        max_w_screen_md "max-w-screen-md": max_width(screen::MD);
+        max_w_screen_lg "max-w-screen-lg": max_width(screen::LG);
+        max_w_screen_xl "max-w-screen-xl": max_width(screen::XL);
+        max_w_screen_2xl "max-w-screen-2xl": max_width(screen::XL2);
+
+        // === Min-Height Methods ===
+        min_h_xs "min-h-xs": min_height(rem::scale(spacing::SIZE_XS));
+        min_h_sm "min-h-sm": min_height(rem::scale(spacing::SIZE_SM));
+        min_h_md "min-h-md": min_height(rem::scale(spacing::SIZE_MD));
+        min_h_lg "min-h-lg": min_height(rem::scale(spacing::SIZE_LG));
+        min_h_xl "min-h-xl": min_height(rem::scale(spacing::SIZE_XL));
+        min_h_2xl "min-h-2xl": min_height(rem::scale(spacing::SIZE_2XL));
+        min_h_3xl "min-h-3xl": min_height(rem::scale(spacing::SIZE_3XL));
+        min_h_4xl "min-h-4xl": min_height(rem::scale(spacing::SIZE_4XL));
+        min_h_5xl "min-h-5xl": min_height(rem::scale(spacing::SIZE_5XL));
+        min_h_6xl "min-h-6xl": min_height(rem::scale(spacing::SIZE_6XL));
+        min_h_7xl "min-h-7xl": min_height(rem::scale(spacing::SIZE_7XL));
+
+        // === Max-Height Methods ===
+        max_h_xs "max-h-xs": max_height(rem::scale(spacing::SIZE_XS));
+        max_h_sm "max-h-sm": max_height(rem::scale(spacing::SIZE_SM));
+        max_h_md "max-h-md": max_height(rem::scale(spacing::SIZE_MD));
+        max_h_lg "max-h-lg": max_height(rem::scale(spacing::SIZE_LG));
+        max_h_xl "max-h-xl": max_height(rem::scale(spacing::SIZE_XL));
+        max_h_2xl "max-h-2xl": max_height(rem::scale(spacing::SIZE_2XL));
+        max_h_3xl "max-h-3xl": max_height(rem::scale(spacing::SIZE_3XL));
+        max_h_4xl "max-h-4xl": max_height(rem::scale(spacing::SIZE_4XL));
+        max_h_5xl "max-h-5xl": max_height(rem::scale(spacing::SIZE_5XL));
+        max_h_6xl "max-h-6xl": max_height(rem::scale(spacing::SIZE_6XL));
+        max_h_7xl "max-h-7xl": max_height(rem::scale(spacing::SIZE_7XL));
+
+        // === Padding Methods ===
+        p_0 "p-0": padding(0.0);
+        p_px "p-px": padding(dpi::snap(1.0));
+        p_0p5 "p-0.5": padding(rem::scale(spacing::SPACING_0_5));
+        p_1 "p-1": padding(rem::scale(spacing::SPACING_1));
+        p_1p5 "p-1.5": padding(rem::scale(spacing::SPACING_1_5));
+        p_2 "p-2": padding(rem::scale(spacing::SPACING_2));
+        p_2p5 "p-2.5": padding(rem::scale(spacing::SPACING_2_5));
+        p_3 "p-3": padding(rem::scale(spacing::SPACING_3));
+        p_3p5 "p-3.5": padding(rem::scale(spacing::SPACING_3_5));
+        p_4 "p-4": padding(rem::scale(spacing::SPACING_4));
+        p_5 "p-5": padding(rem::scale(spacing::SPACING_5));
+        p_6 "p-6": padding(rem::scale(spacing::SPACING_6));
+        p_7 "p-7": padding(rem::scale(spacing::SPACING_7));
+        p_8 "p-8": padding(rem::scale(spacing::SPACING_8));
+        p_9 "p-9": padding(rem::scale(spacing::SPACING_9));
+        p_10 "p-10": padding(rem::scale(spacing::SPACING_10));
+        p_11 "p-11": padding(rem::scale(spacing::SPACING_11));
+        p_12 "p-12": padding(rem::scale(spacing::SPACING_12));
+        p_14 "p-14": padding(rem::scale(spacing::SPACING_14));
+        p_16 "p-16": padding(rem::scale(spacing::SPACING_16));
+        p_20 "p-20": padding(rem::scale(spacing::SPACING_20));
+        p_24 "p-24": padding(rem::scale(spacing::SPACING_24));
+
+        // Horizontal padding (px-*)
+        px_0 "px-0": padding_horiz(0.0);
+        px_px "px-px": padding_horiz(dpi::snap(1.0));
+        px_0p5 "px-0.5": padding_horiz(rem::scale(spacing::SPACING_0_5));
+        px_1 "px-1": padding_horiz(rem::scale(spacing::SPACING_1));
+        px_1p5 "px-1.5": padding_horiz(rem::scale(spacing::SPACING_1_5));
+        px_2 "px-2": padding_horiz(rem::scale(spacing::SPACING_2));
+        px_2p5 "px-2.5": padding_horiz(rem::scale(spacing::SPACING_2_5));
+        px_3 "px-3": padding_horiz(rem::scale(spacing::SPACING_3));
+        px_3p5 "px-3.5": padding_horiz(rem::scale(spacing::SPACING_3_5));
+        px_4 "px-4": padding_horiz(rem::scale(spacing::SPACING_4));
+        px_5 "px-5": padding_horiz(rem::scale(spacing::SPACING_5));
+        px_6 "px-6": padding_horiz(rem::scale(spacing::SPACING_6));
+        px_7 "px-7": padding_horiz(rem::scale(spacing::SPACING_7));
+        px_8 "px-8": padding_horiz(rem::scale(spacing::SPACING_8));
+        px_9 "px-9": padding_horiz(rem::scale(spacing::SPACING_9));
+        px_10 "px-10": padding_horiz(rem::scale(spacing::SPACING_10));
+        px_12 "px-12": padding_horiz(rem::scale(spacing::SPACING_12));
+        px_16 "px-16": padding_horiz(rem::scale(spacing::SPACING_16));
+        px_20 "px-20": padding_horiz(rem::scale(spacing::SPACING_20));
+        px_24 "px-24": padding_horiz(rem::scale(spacing::SPACING_24));
+
+        // Vertical padding (py-*)
+        py_0 "py-0": padding_vert(0.0);
+        py_px "py-px": padding_vert(dpi::snap(1.0));
+        py_0p5 "py-0.5": padding_vert(rem::scale(spacing::SPACING_0_5));
+        py_1 "py-1": padding_vert(rem::scale(spacing::SPACING_1));
+        py_1p5 "py-1.5": padding_vert(rem::scale(spacing::SPACING_1_5));
+        py_2 "py-2": padding_vert(rem::scale(spacing::SPACING_2));
+        py_2p5 "py-2.5": padding_vert(rem::scale(spacing::SPACING_2_5));
+        py_3 "py-3": padding_vert(rem::scale(spacing::SPACING_3));
+        py_3p5 "py-3.5": padding_vert(rem::scale(spacing::SPACING_3_5));
+        py_4 "py-4": padding_vert(rem::scale(spacing::SPACING_4));
+        py_5 "py-5": padding_vert(rem::scale(spacing::SPACING_5));
+        py_6 "py-6": padding_vert(rem::scale(spacing::SPACING_6));
+        py_7 "py-7": padding_vert(rem::scale(spacing::SPACING_7));
+        py_8 "py-8": padding_vert(rem::scale(spacing::SPACING_8));
+        py_9 "py-9": padding_vert(rem::scale(spacing::SPACING_9));
+        py_10 "py-10": padding_vert(rem::scale(spacing::SPACING_10));
+        py_12 "py-12": padding_vert(rem::scale(spacing::SPACING_12));
+        py_16 "py-16": padding_vert(rem::scale(spacing::SPACING_16));
+        py_20 "py-20": padding_vert(rem::scale(spacing::SPACING_20));
+        py_24 "py-24": padding_vert(rem::scale(spacing::SPACING_24));
+
+        // Individual padding sides
+        pt_0 "pt-0": padding_top(0.0);
+        pt_1 "pt-1": padding_top(rem::scale(spacing::SPACING_1));
+        pt_2 "pt-2": padding_top(rem::scale(spacing::SPACING_2));
+        pt_3 "pt-3": padding_top(rem::scale(spacing::SPACING_3));
+        pt_4 "pt-4": padding_top(rem::scale(spacing::SPACING_4));
+        pt_5 "pt-5": padding_top(rem::scale(spacing::SPACING_5));
+        pt_6 "pt-6": padding_top(rem::scale(spacing::SPACING_6));
+        pt_8 "pt-8": padding_top(rem::scale(spacing::SPACING_8));
+
+        pb_0 "pb-0": padding_bottom(0.0);
+        pb_1 "pb-1": padding_bottom(rem::scale(spacing::SPACING_1));
+        pb_2 "pb-2": padding_bottom(rem::scale(spacing::SPACING_2));
+        pb_3 "pb-3": padding_bottom(rem::scale(spacing::SPACING_3));
+        pb_4 "pb-4": padding_bottom(rem::scale(spacing::SPACING_4));
+        pb_5 "pb-5": padding_bottom(rem::scale(spacing::SPACING_5));
+        pb_6 "pb-6": padding_bottom(rem::scale(spacing::SPACING_6));
+        pb_8 "pb-8": padding_bottom(rem::scale(spacing::SPACING_8));
+
+        pl_0 "pl-0": padding_left(0.0);
+        pl_1 "pl-1": padding_left(rem::scale(spacing::SPACING_1));
+        pl_2 "pl-2": padding_left(rem::scale(spacing::SPACING_2));
+        pl_3 "pl-3": padding_left(rem::scale(spacing::SPACING_3));
+        pl_4 "pl-4": padding_left(rem::scale(spacing::SPACING_4));
+        pl_5 "pl-5": padding_left(rem::scale(spacing::SPACING_5));
+        pl_6 "pl-6": padding_left(rem::scale(spacing::SPACING_6));
+        pl_8 "pl-8": padding_left(rem::scale(spacing::SPACING_8));
+
+        pr_0 "pr-0": padding_right(0.0);
+        pr_1 "pr-1": padding_right(rem::scale(spacing::SPACING_1));
+        pr_2 "pr-2": padding_right(rem::scale(spacing::SPACING_2));
+        pr_3 "pr-3": padding_right(rem::scale(spacing::SPACING_3));
+        pr_4 "pr-4": padding_right(rem::scale(spacing::SPACING_4));
+        pr_5 "pr-5": padding_right(rem::scale(spacing::SPACING_5));
+        pr_6 "pr-6": padding_right(rem::scale(spacing::SPACING_6));
+        pr_8 "pr-8": padding_right(rem::scale(spacing::SPACING_8));
+
+        // Logical padding sides, resolved by [`direction`]
+        ps_0 "ps-0": apply(direction::padding_start(0.0));
+        ps_1 "ps-1": apply(direction::padding_start(rem::scale(spacing::SPACING_1)));
+        ps_2 "ps-2": apply(direction::padding_start(rem::scale(spacing::SPACING_2)));
+        ps_3 "ps-3": apply(direction::padding_start(rem::scale(spacing::SPACING_3)));
+        ps_4 "ps-4": apply(direction::padding_start(rem::scale(spacing::SPACING_4)));
+        ps_5 "ps-5": apply(direction::padding_start(rem::scale(spacing::SPACING_5)));
+        ps_6 "ps-6": apply(direction::padding_start(rem::scale(spacing::SPACING_6)));
+        ps_8 "ps-8": apply(direction::padding_start(rem::scale(spacing::SPACING_8)));
+
+        pe_0 "pe-0": apply(direction::padding_end(0.0));
+        pe_1 "pe-1": apply(direction::padding_end(rem::scale(spacing::SPACING_1)));
+        pe_2 "pe-2": apply(direction::padding_end(rem::scale(spacing::SPACING_2)));
+        pe_3 "pe-3": apply(direction::padding_end(rem::scale(spacing::SPACING_3)));
+        pe_4 "pe-4": apply(direction::padding_end(rem::scale(spacing::SPACING_4)));
+        pe_5 "pe-5": apply(direction::padding_end(rem::scale(spacing::SPACING_5)));
+        pe_6 "pe-6": apply(direction::padding_end(rem::scale(spacing::SPACING_6)));
+        pe_8 "pe-8": apply(direction::padding_end(rem::scale(spacing::SPACING_8)));
+
+        // Percentage padding (of the container width, as in CSS)
+        p_1_2 "p-1/2": padding(Pct(50.0)); // 1/2 = 50%
+        p_1_3 "p-1/3": padding(Pct(33.333333)); // 1/3 = 33.33%
+        p_1_4 "p-1/4": padding(Pct(25.0)); // 1/4 = 25%
+        px_1_2 "px-1/2": padding_horiz(Pct(50.0)); // 1/2 = 50%
+        px_1_3 "px-1/3": padding_horiz(Pct(33.333333)); // 1/3 = 33.33%
+        px_1_4 "px-1/4": padding_horiz(Pct(25.0)); // 1/4 = 25%
+        py_1_2 "py-1/2": padding_vert(Pct(50.0)); // 1/2 = 50%
+        py_1_3 "py-1/3": padding_vert(Pct(33.333333)); // 1/3 = 33.33%
+        py_1_4 "py-1/4": padding_vert(Pct(25.0)); // 1/4 = 25%
+
+        // === Margin Methods ===
+        m_0 "m-0": margin(0.0);
+        m_px "m-px": margin(dpi::snap(1.0));
+        m_0p5 "m-0.5": margin(rem::scale(spacing::SPACING_0_5));
+        m_1 "m-1": margin(rem::scale(spacing::SPACING_1));
+        m_1p5 "m-1.5": margin(rem::scale(spacing::SPACING_1_5));
+        m_2 "m-2": margin(rem::scale(spacing::SPACING_2));
+        m_2p5 "m-2.5": margin(rem::scale(spacing::SPACING_2_5));
+        m_3 "m-3": margin(rem::scale(spacing::SPACING_3));
+        m_3p5 "m-3.5": margin(rem::scale(spacing::SPACING_3_5));
+        m_4 "m-4": margin(rem::scale(spacing::SPACING_4));
+        m_5 "m-5": margin(rem::scale(spacing::SPACING_5));
+        m_6 "m-6": margin(rem::scale(spacing::SPACING_6));
+        m_7 "m-7": margin(rem::scale(spacing::SPACING_7));
+        m_8 "m-8": margin(rem::scale(spacing::SPACING_8));
+        m_9 "m-9": margin(rem::scale(spacing::SPACING_9));
+        m_10 "m-10": margin(rem::scale(spacing::SPACING_10));
+        m_11 "m-11": margin(rem::scale(spacing::SPACING_11));
+        m_12 "m-12": margin(rem::scale(spacing::SPACING_12));
+        m_14 "m-14": margin(rem::scale(spacing::SPACING_14));
+        m_16 "m-16": margin(rem::scale(spacing::SPACING_16));
+        m_20 "m-20": margin(rem::scale(spacing::SPACING_20));
+        m_24 "m-24": margin(rem::scale(spacing::SPACING_24));
+
+        m_auto "m-auto": margin(PxPctAuto::Auto);
+
+        // Horizontal margin (mx-*)
+        mx_0 "mx-0": margin_horiz(0.0);
+        mx_px "mx-px": margin_horiz(dpi::snap(1.0));
+        mx_0p5 "mx-0.5": margin_horiz(rem::scale(spacing::SPACING_0_5));
+        mx_1 "mx-1": margin_horiz(rem::scale(spacing::SPACING_1));
+        mx_1p5 "mx-1.5": margin_horiz(rem::scale(spacing::SPACING_1_5));
+        mx_2 "mx-2": margin_horiz(rem::scale(spacing::SPACING_2));
+        mx_2p5 "mx-2.5": margin_horiz(rem::scale(spacing::SPACING_2_5));
+        mx_3 "mx-3": margin_horiz(rem::scale(spacing::SPACING_3));
+        mx_3p5 "mx-3.5": margin_horiz(rem::scale(spacing::SPACING_3_5));
+        mx_4 "mx-4": margin_horiz(rem::scale(spacing::SPACING_4));
+        mx_5 "mx-5": margin_horiz(rem::scale(spacing::SPACING_5));
+        mx_6 "mx-6": margin_horiz(rem::scale(spacing::SPACING_6));
+        mx_8 "mx-8": margin_horiz(rem::scale(spacing::SPACING_8));
+        mx_10 "mx-10": margin_horiz(rem::scale(spacing::SPACING_10));
+        mx_12 "mx-12": margin_horiz(rem::scale(spacing::SPACING_12));
+        mx_16 "mx-16": margin_horiz(rem::scale(spacing::SPACING_16));
+        mx_20 "mx-20": margin_horiz(rem::scale(spacing::SPACING_20));
+        mx_24 "mx-24": margin_horiz(rem::scale(spacing::SPACING_24));
+
+        mx_auto "mx-auto": margin_horiz(PxPctAuto::Auto);
+
+        // Vertical margin (my-*)
+        my_0 "my-0": margin_vert(0.0);
+        my_px "my-px": margin_vert(dpi::snap(1.0));
+        my_0p5 "my-0.5": margin_vert(rem::scale(spacing::SPACING_0_5));
+        my_1 "my-1": margin_vert(rem::scale(spacing::SPACING_1));
+        my_1p5 "my-1.5": margin_vert(rem::scale(spacing::SPACING_1_5));
+        my_2 "my-2": margin_vert(rem::scale(spacing::SPACING_2));
+        my_2p5 "my-2.5": margin_vert(rem::scale(spacing::SPACING_2_5));
+        my_3 "my-3": margin_vert(rem::scale(spacing::SPACING_3));
+        my_3p5 "my-3.5": margin_vert(rem::scale(spacing::SPACING_3_5));
+        my_4 "my-4": margin_vert(rem::scale(spacing::SPACING_4));
+        my_5 "my-5": margin_vert(rem::scale(spacing::SPACING_5));
+        my_6 "my-6": margin_vert(rem::scale(spacing::SPACING_6));
+        my_8 "my-8": margin_vert(rem::scale(spacing::SPACING_8));
+        my_10 "my-10": margin_vert(rem::scale(spacing::SPACING_10));
+        my_12 "my-12": margin_vert(rem::scale(spacing::SPACING_12));
+        my_16 "my-16": margin_vert(rem::scale(spacing::SPACING_16));
+        my_20 "my-20": margin_vert(rem::scale(spacing::SPACING_20));
+        my_24 "my-24": margin_vert(rem::scale(spacing::SPACING_24));
+
+        my_auto "my-auto": margin_vert(PxPctAuto::Auto);
+
+        // Individual margin sides
+        mt_0 "mt-0": margin_top(0.0);
+        mt_1 "mt-1": margin_top(rem::scale(spacing::SPACING_1));
+        mt_2 "mt-2": margin_top(rem::scale(spacing::SPACING_2));
+        mt_3 "mt-3": margin_top(rem::scale(spacing::SPACING_3));
+        mt_4 "mt-4": margin_top(rem::scale(spacing::SPACING_4));
+        mt_5 "mt-5": margin_top(rem::scale(spacing::SPACING_5));
+        mt_6 "mt-6": margin_top(rem::scale(spacing::SPACING_6));
+        mt_8 "mt-8": margin_top(rem::scale(spacing::SPACING_8));
+        mt_auto "mt-auto": margin_top(PxPctAuto::Auto);
+
+        mb_0 "mb-0": margin_bottom(0.0);
+        mb_1 "mb-1": margin_bottom(rem::scale(spacing::SPACING_1));
+        mb_2 "mb-2": margin_bottom(rem::scale(spacing::SPACING_2));
+        mb_3 "mb-3": margin_bottom(rem::scale(spacing::SPACING_3));
+        mb_4 "mb-4": margin_bottom(rem::scale(spacing::SPACING_4));
+        mb_5 "mb-5": margin_bottom(rem::scale(spacing::SPACING_5));
+        mb_6 "mb-6": margin_bottom(rem::scale(spacing::SPACING_6));
+        mb_8 "mb-8": margin_bottom(rem::scale(spacing::SPACING_8));
+        mb_auto "mb-auto": margin_bottom(PxPctAuto::Auto);
+
+        ml_0 "ml-0": margin_left(0.0);
+        ml_1 "ml-1": margin_left(rem::scale(spacing::SPACING_1));
+        ml_2 "ml-2": margin_left(rem::scale(spacing::SPACING_2));
+        ml_3 "ml-3": margin_left(rem::scale(spacing::SPACING_3));
+        ml_4 "ml-4": margin_left(rem::scale(spacing::SPACING_4));
+        ml_5 "ml-5": margin_left(rem::scale(spacing::SPACING_5));
+        ml_6 "ml-6": margin_left(rem::scale(spacing::SPACING_6));
+        ml_8 "ml-8": margin_left(rem::scale(spacing::SPACING_8));
+        ml_auto "ml-auto": margin_left(PxPctAuto::Auto);
+
+        mr_0 "mr-0": margin_right(0.0);
+        mr_1 "mr-1": margin_right(rem::scale(spacing::SPACING_1));
+        mr_2 "mr-2": margin_right(rem::scale(spacing::SPACING_2));
+        mr_3 "mr-3": margin_right(rem::scale(spacing::SPACING_3));
+        mr_4 "mr-4": margin_right(rem::scale(spacing::SPACING_4));
+        mr_5 "mr-5": margin_right(rem::scale(spacing::SPACING_5));
+        mr_6 "mr-6": margin_right(rem::scale(spacing::SPACING_6));
+        mr_8 "mr-8": margin_right(rem::scale(spacing::SPACING_8));
+        mr_auto "mr-auto": margin_right(PxPctAuto::Auto);
+
+        // Logical margin sides, resolved by [`direction`]
+        ms_0 "ms-0": apply(direction::margin_start(0.0));
+        ms_1 "ms-1": apply(direction::margin_start(rem::scale(spacing::SPACING_1)));
+        ms_2 "ms-2": apply(direction::margin_start(rem::scale(spacing::SPACING_2)));
+        ms_3 "ms-3": apply(direction::margin_start(rem::scale(spacing::SPACING_3)));
+        ms_4 "ms-4": apply(direction::margin_start(rem::scale(spacing::SPACING_4)));
+        ms_5 "ms-5": apply(direction::margin_start(rem::scale(spacing::SPACING_5)));
+        ms_6 "ms-6": apply(direction::margin_start(rem::scale(spacing::SPACING_6)));
+        ms_8 "ms-8": apply(direction::margin_start(rem::scale(spacing::SPACING_8)));
+        ms_auto "ms-auto": apply(direction::margin_start(PxPctAuto::Auto));
+
+        me_0 "me-0": apply(direction::margin_end(0.0));
+        me_1 "me-1": apply(direction::margin_end(rem::scale(spacing::SPACING_1)));
+        me_2 "me-2": apply(direction::margin_end(rem::scale(spacing::SPACING_2)));
+        me_3 "me-3": apply(direction::margin_end(rem::scale(spacing::SPACING_3)));
+        me_4 "me-4": apply(direction::margin_end(rem::scale(spacing::SPACING_4)));
+        me_5 "me-5": apply(direction::margin_end(rem::scale(spacing::SPACING_5)));
+        me_6 "me-6": apply(direction::margin_end(rem::scale(spacing::SPACING_6)));
+        me_8 "me-8": apply(direction::margin_end(rem::scale(spacing::SPACING_8)));
+        me_auto "me-auto": apply(direction::margin_end(PxPctAuto::Auto));
+
+        // Percentage margin (of the container width, as in CSS)
+        m_1_2 "m-1/2": margin(Pct(50.0)); // 1/2 = 50%
+        m_1_3 "m-1/3": margin(Pct(33.333333)); // 1/3 = 33.33%
+        m_1_4 "m-1/4": margin(Pct(25.0)); // 1/4 = 25%
+        mx_1_2 "mx-1/2": margin_horiz(Pct(50.0)); // 1/2 = 50%
+        mx_1_3 "mx-1/3": margin_horiz(Pct(33.333333)); // 1/3 = 33.33%
+        mx_1_4 "mx-1/4": margin_horiz(Pct(25.0)); // 1/4 = 25%
+        my_1_2 "my-1/2": margin_vert(Pct(50.0)); // 1/2 = 50%
+        my_1_3 "my-1/3": margin_vert(Pct(33.333333)); // 1/3 = 33.33%
+        my_1_4 "my-1/4": margin_vert(Pct(25.0)); // 1/4 = 25%
+
+        // === Gap Methods ===
+        gap_0 "gap-0": gap(0.0);
+        gap_px "gap-px": gap(dpi::snap(1.0));
+        gap_0p5 "gap-0.5": gap(rem::scale(spacing::SPACING_0_5));
+        gap_1 "gap-1": gap(rem::scale(spacing::SPACING_1));
+        gap_1p5 "gap-1.5": gap(rem::scale(spacing::SPACING_1_5));
+        gap_2 "gap-2": gap(rem::scale(spacing::SPACING_2));
+        gap_2p5 "gap-2.5": gap(rem::scale(spacing::SPACING_2_5));
+        gap_3 "gap-3": gap(rem::scale(spacing::SPACING_3));
+        gap_3p5 "gap-3.5": gap(rem::scale(spacing::SPACING_3_5));
+        gap_4 "gap-4": gap(rem::scale(spacing::SPACING_4));
+        gap_5 "gap-5": gap(rem::scale(spacing::SPACING_5));
+        gap_6 "gap-6": gap(rem::scale(spacing::SPACING_6));
+        gap_7 "gap-7": gap(rem::scale(spacing::SPACING_7));
+        gap_8 "gap-8": gap(rem::scale(spacing::SPACING_8));
+        gap_9 "gap-9": gap(rem::scale(spacing::SPACING_9));
+        gap_10 "gap-10": gap(rem::scale(spacing::SPACING_10));
+        gap_11 "gap-11": gap(rem::scale(spacing::SPACING_11));
+        gap_12 "gap-12": gap(rem::scale(spacing::SPACING_12));
+        gap_14 "gap-14": gap(rem::scale(spacing::SPACING_14));
+        gap_16 "gap-16": gap(rem::scale(spacing::SPACING_16));
+        gap_20 "gap-20": gap(rem::scale(spacing::SPACING_20));
+        gap_24 "gap-24": gap(rem::scale(spacing::SPACING_24));
+    }
+    extra {
+        // === Spacing Scale Methods ===
+        // `gap`, `row_gap` and `column_gap` are Floem `Style` methods that accept
+        // `Spacing` directly, e.g. `.gap(Spacing::S2)`.
+        /// width from the spacing scale
+        fn w(self, spacing: Spacing) -> Self;
+        /// height from the spacing scale
+        fn h(self, spacing: Spacing) -> Self;
+        /// min-width from the spacing scale
+        fn min_w(self, spacing: Spacing) -> Self;
+        /// max-width from the spacing scale
+        fn max_w(self, spacing: Spacing) -> Self;
+        /// min-height from the spacing scale
+        fn min_h(self, spacing: Spacing) -> Self;
+        /// max-height from the spacing scale
+        fn max_h(self, spacing: Spacing) -> Self;
+        /// padding from the spacing scale
+        fn p(self, spacing: Spacing) -> Self;
+        /// horizontal padding from the spacing scale
+        fn px(self, spacing: Spacing) -> Self;
+        /// vertical padding from the spacing scale
+        fn py(self, spacing: Spacing) -> Self;
+        /// padding-top from the spacing scale
+        fn pt(self, spacing: Spacing) -> Self;
+        /// padding-right from the spacing scale
+        fn pr(self, spacing: Spacing) -> Self;
+        /// padding-bottom from the spacing scale
+        fn pb(self, spacing: Spacing) -> Self;
+        /// padding-left from the spacing scale
+        fn pl(self, spacing: Spacing) -> Self;
+        /// margin from the spacing scale
+        fn m(self, spacing: Spacing) -> Self;
+        /// horizontal margin from the spacing scale
+        fn mx(self, spacing: Spacing) -> Self;
+        /// vertical margin from the spacing scale
+        fn my(self, spacing: Spacing) -> Self;
+        /// margin-top from the spacing scale
+        fn mt(self, spacing: Spacing) -> Self;
+        /// margin-right from the spacing scale
+        fn mr(self, spacing: Spacing) -> Self;
+        /// margin-bottom from the spacing scale
+        fn mb(self, spacing: Spacing) -> Self;
+        /// margin-left from the spacing scale
+        fn ml(self, spacing: Spacing) -> Self;
+        // === Unit Methods ===
+        // Any multiple of the 4px scale, for values the scale doesn't have;
+        // see `spacing::units`.
+        /// width in 4px units
+        fn w_units(self, n: f64) -> Self;
+        /// height in 4px units
+        fn h_units(self, n: f64) -> Self;
+        /// padding in 4px units
+        fn p_units(self, n: f64) -> Self;
+        /// horizontal padding in 4px units
+        fn px_units(self, n: f64) -> Self;
+        /// vertical padding in 4px units
+        fn py_units(self, n: f64) -> Self;
+        /// margin in 4px units
+        fn m_units(self, n: f64) -> Self;
+        /// horizontal margin in 4px units
+        fn mx_units(self, n: f64) -> Self;
+        /// vertical margin in 4px units
+        fn my_units(self, n: f64) -> Self;
+        /// gap in 4px units
+        fn gap_units(self, n: f64) -> Self;
+        // === Viewport Methods ===
+        // The window size comes from `TailwindViewExt::track_window_size`, and
+        // styles using it rerun on resize.
+        /// width as a percentage of the window width
+        fn w_vw(self, pct: f64) -> Self;
+        /// height as a percentage of the window height
+        fn h_vh(self, pct: f64) -> Self;
+        /// max-width as a percentage of the window width
+        fn max_w_vw(self, pct: f64) -> Self;
+        /// max-height as a percentage of the window height
+        fn max_h_vh(self, pct: f64) -> Self;
+        // === Percentage Methods ===
+        /// width as a percentage of the container width
+        fn w_pct(self, pct: f64) -> Self;
+        /// height as a percentage of the container height
+        fn h_pct(self, pct: f64) -> Self;
+        /// min-width as a percentage of the container width
+        fn min_w_pct(self, pct: f64) -> Self;
+        /// max-width as a percentage of the container width
+        fn max_w_pct(self, pct: f64) -> Self;
+        /// min-height as a percentage of the container height
+        fn min_h_pct(self, pct: f64) -> Self;
+        /// max-height as a percentage of the container height
+        fn max_h_pct(self, pct: f64) -> Self;
+        /// flex-basis as a percentage of the container's main size
+        fn basis_pct(self, pct: f64) -> Self;
+        /// padding as a percentage of the container width
+        fn p_pct(self, pct: f64) -> Self;
+        /// horizontal padding as a percentage of the container width
+        fn px_pct(self, pct: f64) -> Self;
+        /// vertical padding as a percentage of the container width
+        fn py_pct(self, pct: f64) -> Self;
+        /// padding-top as a percentage of the container width
+        fn pt_pct(self, pct: f64) -> Self;
+        /// padding-right as a percentage of the container width
+        fn pr_pct(self, pct: f64) -> Self;
+        /// padding-bottom as a percentage of the container width
+        fn pb_pct(self, pct: f64) -> Self;
+        /// padding-left as a percentage of the container width
+        fn pl_pct(self, pct: f64) -> Self;
+        /// margin as a percentage of the container width
+        fn m_pct(self, pct: f64) -> Self;
+        /// horizontal margin as a percentage of the container width
+        fn mx_pct(self, pct: f64) -> Self;
+        /// vertical margin as a percentage of the container width
+        fn my_pct(self, pct: f64) -> Self;
+        /// margin-top as a percentage of the container width
+        fn mt_pct(self, pct: f64) -> Self;
+        /// margin-right as a percentage of the container width
+        fn mr_pct(self, pct: f64) -> Self;
+        /// margin-bottom as a percentage of the container width
+        fn mb_pct(self, pct: f64) -> Self;
+        /// margin-left as a percentage of the container width
+        fn ml_pct(self, pct: f64) -> Self;
+    } impl {
+        fn w(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.width(spacing))
+        }
+        fn h(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.height(spacing))
+        }
+        fn min_w(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.min_width(spacing))
+        }
+        fn max_w(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.max_width(spacing))
+        }
+        fn min_h(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.min_height(spacing))
+        }
+        fn max_h(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.max_height(spacing))
+        }
+        fn p(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding(spacing))
+        }
+        fn px(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding_horiz(spacing))
+        }
+        fn py(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding_vert(spacing))
+        }
+        fn pt(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding_top(spacing))
+        }
+        fn pr(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding_right(spacing))
+        }
+        fn pb(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding_bottom(spacing))
+        }
+        fn pl(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.padding_left(spacing))
+        }
+        fn m(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin(spacing))
+        }
+        fn mx(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin_horiz(spacing))
+        }
+        fn my(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin_vert(spacing))
+        }
+        fn mt(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin_top(spacing))
+        }
+        fn mr(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin_right(spacing))
+        }
+        fn mb(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin_bottom(spacing))
+        }
+        fn ml(self, spacing: Spacing) -> Self {
+            self.map_style(|s| s.margin_left(spacing))
+        }
+        fn w_units(self, n: f64) -> Self {
+            self.map_style(|s| s.width(spacing::units(n)))
+        }
+        fn h_units(self, n: f64) -> Self {
+            self.map_style(|s| s.height(spacing::units(n)))
+        }
+        fn p_units(self, n: f64) -> Self {
+            self.map_style(|s| s.padding(spacing::units(n)))
+        }
+        fn px_units(self, n: f64) -> Self {
+            self.map_style(|s| s.padding_horiz(spacing::units(n)))
+        }
+        fn py_units(self, n: f64) -> Self {
+            self.map_style(|s| s.padding_vert(spacing::units(n)))
+        }
+        fn m_units(self, n: f64) -> Self {
+            self.map_style(|s| s.margin(spacing::units(n)))
+        }
+        fn mx_units(self, n: f64) -> Self {
+            self.map_style(|s| s.margin_horiz(spacing::units(n)))
+        }
+        fn my_units(self, n: f64) -> Self {
+            self.map_style(|s| s.margin_vert(spacing::units(n)))
+        }
+        fn gap_units(self, n: f64) -> Self {
+            self.map_style(|s| s.gap(spacing::units(n)))
+        }
+        fn w_vw(self, pct: f64) -> Self {
+            self.map_style(|s| s.width(window::width() * pct / 100.0))
+        }
+        fn h_vh(self, pct: f64) -> Self {
+            self.map_style(|s| s.height(window::height() * pct / 100.0))
+        }
+        fn max_w_vw(self, pct: f64) -> Self {
+            self.map_style(|s| s.max_width(window::width() * pct / 100.0))
+        }
+        fn max_h_vh(self, pct: f64) -> Self {
+            self.map_style(|s| s.max_height(window::height() * pct / 100.0))
+        }
+        fn w_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.width(Pct(pct)))
+        }
+        fn h_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.height(Pct(pct)))
+        }
+        fn min_w_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.min_width(Pct(pct)))
+        }
+        fn max_w_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.max_width(Pct(pct)))
+        }
+        fn min_h_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.min_height(Pct(pct)))
+        }
+        fn max_h_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.max_height(Pct(pct)))
+        }
+        fn basis_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.flex_basis(Pct(pct)))
+        }
+        fn p_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding(Pct(pct)))
+        }
+        fn px_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding_horiz(Pct(pct)))
+        }
+        fn py_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding_vert(Pct(pct)))
+        }
+        fn pt_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding_top(Pct(pct)))
+        }
+        fn pr_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding_right(Pct(pct)))
+        }
+        fn pb_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding_bottom(Pct(pct)))
+        }
+        fn pl_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.padding_left(Pct(pct)))
+        }
+        fn m_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin(Pct(pct)))
+        }
+        fn mx_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin_horiz(Pct(pct)))
+        }
+        fn my_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin_vert(Pct(pct)))
+        }
+        fn mt_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin_top(Pct(pct)))
+        }
+        fn mr_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin_right(Pct(pct)))
+        }
+        fn mb_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin_bottom(Pct(pct)))
+        }
+        fn ml_pct(self, pct: f64) -> Self {
+            self.map_style(|s| s.margin_left(Pct(pct)))
+        }
+    }
 }
 
-/// Macro to implement font-size methods
-macro_rules! impl_font_size_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.font_size($value)
-            }
-        )*
-    };
+#[cfg(feature = "spacing")]
+aliases! {
+    /// Fraction methods by name (`spacing` feature).
+    ///
+    /// `w_0p5()` is a step of the spacing scale (2px) while the fraction
+    /// methods read `w_frac_1_2()` (50%), so the two can't be confused. The
+    /// common fractions also have word names, and flex basis gets the same set:
+    ///
+    /// ```rust
+    /// use floem::style::Style;
+    /// use floem_tailwind::prelude::*;
+    ///
+    /// let sidebar = Style::new().w_quarter().h_full();
+    /// let column = Style::new().basis_frac_1_3();
+    /// ```
+    ///
+    /// The earlier names without `frac` (`w_1_2()`) still work, and are
+    /// deprecated with a note naming their replacement.
+    pub trait TailwindFractionExt, prefixed TwFractionExt {
+        // Flex basis fractions
+        basis_frac_1_2 => basis_pct(50.0);
+        basis_frac_1_3 => basis_pct(33.333333);
+        basis_frac_2_3 => basis_pct(66.666667);
+        basis_frac_1_4 => basis_pct(25.0);
+        basis_frac_3_4 => basis_pct(75.0);
+        basis_frac_1_5 => basis_pct(20.0);
+        basis_frac_2_5 => basis_pct(40.0);
+        basis_frac_3_5 => basis_pct(60.0);
+        basis_frac_4_5 => basis_pct(80.0);
+        basis_frac_1_6 => basis_pct(16.666667);
+        basis_frac_5_6 => basis_pct(83.333333);
+        basis_frac_1_12 => basis_pct(8.333333);
+
+        // Named widths
+        w_half => w_frac_1_2();
+        w_third => w_frac_1_3();
+        w_two_thirds => w_frac_2_3();
+        w_quarter => w_frac_1_4();
+        w_three_quarters => w_frac_3_4();
+
+        // Named heights
+        h_half => h_frac_1_2();
+        h_third => h_frac_1_3();
+        h_two_thirds => h_frac_2_3();
+        h_quarter => h_frac_1_4();
+        h_three_quarters => h_frac_3_4();
+
+        // Named sizes
+        size_half => size_frac_1_2();
+        size_third => size_frac_1_3();
+        size_two_thirds => size_frac_2_3();
+        size_quarter => size_frac_1_4();
+        size_three_quarters => size_frac_3_4();
+
+        // Named flex basis fractions
+        basis_half => basis_frac_1_2();
+        basis_third => basis_frac_1_3();
+        basis_two_thirds => basis_frac_2_3();
+        basis_quarter => basis_frac_1_4();
+        basis_three_quarters => basis_frac_3_4();
+    }
+    deprecated {
+        w_1_2 => w_frac_1_2;
+        w_1_3 => w_frac_1_3;
+        w_2_3 => w_frac_2_3;
+        w_1_4 => w_frac_1_4;
+        w_3_4 => w_frac_3_4;
+        w_1_5 => w_frac_1_5;
+        w_2_5 => w_frac_2_5;
+        w_3_5 => w_frac_3_5;
+        w_4_5 => w_frac_4_5;
+        w_1_6 => w_frac_1_6;
+        w_5_6 => w_frac_5_6;
+        w_1_12 => w_frac_1_12;
+
+        h_1_2 => h_frac_1_2;
+        h_1_3 => h_frac_1_3;
+        h_2_3 => h_frac_2_3;
+        h_1_4 => h_frac_1_4;
+        h_3_4 => h_frac_3_4;
+        h_1_5 => h_frac_1_5;
+        h_2_5 => h_frac_2_5;
+        h_3_5 => h_frac_3_5;
+        h_4_5 => h_frac_4_5;
+        h_1_6 => h_frac_1_6;
+        h_5_6 => h_frac_5_6;
+        h_1_12 => h_frac_1_12;
+
+        size_1_2 => size_frac_1_2;
+        size_1_3 => size_frac_1_3;
+        size_2_3 => size_frac_2_3;
+        size_1_4 => size_frac_1_4;
+        size_3_4 => size_frac_3_4;
+        size_1_5 => size_frac_1_5;
+        size_2_5 => size_frac_2_5;
+        size_3_5 => size_frac_3_5;
+        size_4_5 => size_frac_4_5;
+        size_1_6 => size_frac_1_6;
+        size_5_6 => size_frac_5_6;
+        size_1_12 => size_frac_1_12;
+    }
 }
 
-/// Macro to generate font-weight methods
-macro_rules! font_weight_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+#[cfg(feature = "color")]
+utilities! {
+    /// Background, text, and border color utilities (`color` feature).
+    pub trait TailwindColorExt => COLOR_UTILITIES, prefixed TwColorExt {
+        // === Background Color Methods ===
+        bg_transparent "bg-transparent": background(colors::TRANSPARENT);
+        bg_black "bg-black": background(colors::BLACK);
+        bg_white "bg-white": background(colors::WHITE);
+        // Slate
+        bg_slate_50 "bg-slate-50": background(colors::slate::C50);
+        bg_slate_100 "bg-slate-100": background(colors::slate::C100);
+        bg_slate_200 "bg-slate-200": background(colors::slate::C200);
+        bg_slate_300 "bg-slate-300": background(colors::slate::C300);
+        bg_slate_400 "bg-slate-400": background(colors::slate::C400);
+        bg_slate_500 "bg-slate-500": background(colors::slate::C500);
+        bg_slate_600 "bg-slate-600": background(colors::slate::C600);
+        bg_slate_700 "bg-slate-700": background(colors::slate::C700);
+        bg_slate_800 "bg-slate-800": background(colors::slate::C800);
+        bg_slate_900 "bg-slate-900": background(colors::slate::C900);
+        bg_slate_950 "bg-slate-950": background(colors::slate::C950);
+        // Gray
+        bg_gray_50 "bg-gray-50": background(colors::gray::C50);
+        bg_gray_100 "bg-gray-100": background(colors::gray::C100);
+        bg_gray_200 "bg-gray-200": background(colors::gray::C200);
+        bg_gray_300 "bg-gray-300": background(colors::gray::C300);
+        bg_gray_400 "bg-gray-400": background(colors::gray::C400);
+        bg_gray_500 "bg-gray-500": background(colors::gray::C500);
+        bg_gray_600 "bg-gray-600": background(colors::gray::C600);
+        bg_gray_700 "bg-gray-700": background(colors::gray::C700);
+        bg_gray_800 "bg-gray-800": background(colors::gray::C800);
+        bg_gray_900 "bg-gray-900": background(colors::gray::C900);
+        bg_gray_950 "bg-gray-950": background(colors::gray::C950);
+        // Zinc
+        bg_zinc_50 "bg-zinc-50": background(colors::zinc::C50);
+        bg_zinc_100 "bg-zinc-100": background(colors::zinc::C100);
+        bg_zinc_200 "bg-zinc-200": background(colors::zinc::C200);
+        bg_zinc_300 "bg-zinc-300": background(colors::zinc::C300);
+        bg_zinc_400 "bg-zinc-400": background(colors::zinc::C400);
+        bg_zinc_500 "bg-zinc-500": background(colors::zinc::C500);
+        bg_zinc_600 "bg-zinc-600": background(colors::zinc::C600);
+        bg_zinc_700 "bg-zinc-700": background(colors::zinc::C700);
+        bg_zinc_800 "bg-zinc-800": background(colors::zinc::C800);
+        bg_zinc_900 "bg-zinc-900": background(colors::zinc::C900);
+        bg_zinc_950 "bg-zinc-950": background(colors::zinc::C950);
+        // Red
+        bg_red_50 "bg-red-50": background(colors::red::C50);
+        bg_red_100 "bg-red-100": background(colors::red::C100);
+        bg_red_200 "bg-red-200": background(colors::red::C200);
+        bg_red_300 "bg-red-300": background(colors::red::C300);
+        bg_red_400 "bg-red-400": background(colors::red::C400);
+        bg_red_500 "bg-red-500": background(colors::red::C500);
+        bg_red_600 "bg-red-600": background(colors::red::C600);
+        bg_red_700 "bg-red-700": background(colors::red::C700);
+        bg_red_800 "bg-red-800": background(colors::red::C800);
+        bg_red_900 "bg-red-900": background(colors::red::C900);
+        bg_red_950 "bg-red-950": background(colors::red::C950);
+        // Orange
+        bg_orange_50 "bg-orange-50": background(colors::orange::C50);
+        bg_orange_100 "bg-orange-100": background(colors::orange::C100);
+        bg_orange_200 "bg-orange-200": background(colors::orange::C200);
+        bg_orange_300 "bg-orange-300": background(colors::orange::C300);
+        bg_orange_400 "bg-orange-400": background(colors::orange::C400);
+        bg_orange_500 "bg-orange-500": background(colors::orange::C500);
+        bg_orange_600 "bg-orange-600": background(colors::orange::C600);
+        bg_orange_700 "bg-orange-700": background(colors::orange::C700);
+        bg_orange_800 "bg-orange-800": background(colors::orange::C800);
+        bg_orange_900 "bg-orange-900": background(colors::orange::C900);
+        bg_orange_950 "bg-orange-950": background(colors::orange::C950);
+        // Yellow
+        bg_yellow_50 "bg-yellow-50": background(colors::yellow::C50);
+        bg_yellow_100 "bg-yellow-100": background(colors::yellow::C100);
+        bg_yellow_200 "bg-yellow-200": background(colors::yellow::C200);
+        bg_yellow_300 "bg-yellow-300": background(colors::yellow::C300);
+        bg_yellow_400 "bg-yellow-400": background(colors::yellow::C400);
+        bg_yellow_500 "bg-yellow-500": background(colors::yellow::C500);
+        bg_yellow_600 "bg-yellow-600": background(colors::yellow::C600);
+        bg_yellow_700 "bg-yellow-700": background(colors::yellow::C700);
+        bg_yellow_800 "bg-yellow-800": background(colors::yellow::C800);
+        bg_yellow_900 "bg-yellow-900": background(colors::yellow::C900);
+        bg_yellow_950 "bg-yellow-950": background(colors::yellow::C950);
+        // Green
+        bg_green_50 "bg-green-50": background(colors::green::C50);
+        bg_green_100 "bg-green-100": background(colors::green::C100);
+        bg_green_200 "bg-green-200": background(colors::green::C200);
+        bg_green_300 "bg-green-300": background(colors::green::C300);
+        bg_green_400 "bg-green-400": background(colors::green::C400);
+        bg_green_500 "bg-green-500": background(colors::green::C500);
+        bg_green_600 "bg-green-600": background(colors::green::C600);
+        bg_green_700 "bg-green-700": background(colors::green::C700);
+        bg_green_800 "bg-green-800": background(colors::green::C800);
+        bg_green_900 "bg-green-900": background(colors::green::C900);
+        bg_green_950 "bg-green-950": background(colors::green::C950);
+        // Blue
+        bg_blue_50 "bg-blue-50": background(colors::blue::C50);
+        bg_blue_100 "bg-blue-100": background(colors::blue::C100);
+        bg_blue_200 "bg-blue-200": background(colors::blue::C200);
+        bg_blue_300 "bg-blue-300": background(colors::blue::C300);
+        bg_blue_400 "bg-blue-400": background(colors::blue::C400);
+        bg_blue_500 "bg-blue-500": background(colors::blue::C500);
+        bg_blue_600 "bg-blue-600": background(colors::blue::C600);
+        bg_blue_700 "bg-blue-700": background(colors::blue::C700);
+        bg_blue_800 "bg-blue-800": background(colors::blue::C800);
+        bg_blue_900 "bg-blue-900": background(colors::blue::C900);
+        bg_blue_950 "bg-blue-950": background(colors::blue::C950);
+        // Indigo
+        bg_indigo_50 "bg-indigo-50": background(colors::indigo::C50);
+        bg_indigo_100 "bg-indigo-100": background(colors::indigo::C100);
+        bg_indigo_200 "bg-indigo-200": background(colors::indigo::C200);
+        bg_indigo_300 "bg-indigo-300": background(colors::indigo::C300);
+        bg_indigo_400 "bg-indigo-400": background(colors::indigo::C400);
+        bg_indigo_500 "bg-indigo-500": background(colors::indigo::C500);
+        bg_indigo_600 "bg-indigo-600": background(colors::indigo::C600);
+        bg_indigo_700 "bg-indigo-700": background(colors::indigo::C700);
+        bg_indigo_800 "bg-indigo-800": background(colors::indigo::C800);
+        bg_indigo_900 "bg-indigo-900": background(colors::indigo::C900);
+        bg_indigo_950 "bg-indigo-950": background(colors::indigo::C950);
+        // Purple
+        bg_purple_50 "bg-purple-50": background(colors::purple::C50);
+        bg_purple_100 "bg-purple-100": background(colors::purple::C100);
+        bg_purple_200 "bg-purple-200": background(colors::purple::C200);
+        bg_purple_300 "bg-purple-300": background(colors::purple::C300);
+        bg_purple_400 "bg-purple-400": background(colors::purple::C400);
+        bg_purple_500 "bg-purple-500": background(colors::purple::C500);
+        bg_purple_600 "bg-purple-600": background(colors::purple::C600);
+        bg_purple_700 "bg-purple-700": background(colors::purple::C700);
+        bg_purple_800 "bg-purple-800": background(colors::purple::C800);
+        bg_purple_900 "bg-purple-900": background(colors::purple::C900);
+        bg_purple_950 "bg-purple-950": background(colors::purple::C950);
+        // Pink
+        bg_pink_50 "bg-pink-50": background(colors::pink::C50);
+        bg_pink_100 "bg-pink-100": background(colors::pink::C100);
+        bg_pink_200 "bg-pink-200": background(colors::pink::C200);
+        bg_pink_300 "bg-pink-300": background(colors::pink::C300);
+        bg_pink_400 "bg-pink-400": background(colors::pink::C400);
+        bg_pink_500 "bg-pink-500": background(colors::pink::C500);
+        bg_pink_600 "bg-pink-600": background(colors::pink::C600);
+        bg_pink_700 "bg-pink-700": background(colors::pink::C700);
+        bg_pink_800 "bg-pink-800": background(colors::pink::C800);
+        bg_pink_900 "bg-pink-900": background(colors::pink::C900);
+        bg_pink_950 "bg-pink-950": background(colors::pink::C950);
+
+        // === Text Color Methods ===
+        text_transparent "text-transparent": color(colors::TRANSPARENT);
+        text_black "text-black": color(colors::BLACK);
+        text_white "text-white": color(colors::WHITE);
+        // Slate
+        text_slate_50 "text-slate-50": color(colors::slate::C50);
+        text_slate_100 "text-slate-100": color(colors::slate::C100);
+        text_slate_200 "text-slate-200": color(colors::slate::C200);
+        text_slate_300 "text-slate-300": color(colors::slate::C300);
+        text_slate_400 "text-slate-400": color(colors::slate::C400);
+        text_slate_500 "text-slate-500": color(colors::slate::C500);
+        text_slate_600 "text-slate-600": color(colors::slate::C600);
+        text_slate_700 "text-slate-700": color(colors::slate::C700);
+        text_slate_800 "text-slate-800": color(colors::slate::C800);
+        text_slate_900 "text-slate-900": color(colors::slate::C900);
+        text_slate_950 "text-slate-950": color(colors::slate::C950);
+        // Gray
+        text_gray_50 "text-gray-50": color(colors::gray::C50);
+        text_gray_100 "text-gray-100": color(colors::gray::C100);
+        text_gray_200 "text-gray-200": color(colors::gray::C200);
+        text_gray_300 "text-gray-300": color(colors::gray::C300);
+        text_gray_400 "text-gray-400": color(colors::gray::C400);
+        text_gray_500 "text-gray-500": color(colors::gray::C500);
+        text_gray_600 "text-gray-600": color(colors::gray::C600);
+        text_gray_700 "text-gray-700": color(colors::gray::C700);
+        text_gray_800 "text-gray-800": color(colors::gray::C800);
+        text_gray_900 "text-gray-900": color(colors::gray::C900);
+        text_gray_950 "text-gray-950": color(colors::gray::C950);
+        // Red
+        text_red_50 "text-red-50": color(colors::red::C50);
+        text_red_100 "text-red-100": color(colors::red::C100);
+        text_red_200 "text-red-200": color(colors::red::C200);
+        text_red_300 "text-red-300": color(colors::red::C300);
+        text_red_400 "text-red-400": color(colors::red::C400);
+        text_red_500 "text-red-500": color(colors::red::C500);
+        text_red_600 "text-red-600": color(colors::red::C600);
+        text_red_700 "text-red-700": color(colors::red::C700);
+        text_red_800 "text-red-800": color(colors::red::C800);
+        text_red_900 "text-red-900": color(colors::red::C900);
+        text_red_950 "text-red-950": color(colors::red::C950);
+        // Green
+        text_green_50 "text-green-50": color(colors::green::C50);
+        text_green_100 "text-green-100": color(colors::green::C100);
+        text_green_200 "text-green-200": color(colors::green::C200);
+        text_green_300 "text-green-300": color(colors::green::C300);
+        text_green_400 "text-green-400": color(colors::green::C400);
+        text_green_500 "text-green-500": color(colors::green::C500);
+        text_green_600 "text-green-600": color(colors::green::C600);
+        text_green_700 "text-green-700": color(colors::green::C700);
+        text_green_800 "text-green-800": color(colors::green::C800);
+        text_green_900 "text-green-900": color(colors::green::C900);
+        text_green_950 "text-green-950": color(colors::green::C950);
+        // Blue
+        text_blue_50 "text-blue-50": color(colors::blue::C50);
+        text_blue_100 "text-blue-100": color(colors::blue::C100);
+        text_blue_200 "text-blue-200": color(colors::blue::C200);
+        text_blue_300 "text-blue-300": color(colors::blue::C300);
+        text_blue_400 "text-blue-400": color(colors::blue::C400);
+        text_blue_500 "text-blue-500": color(colors::blue::C500);
+        text_blue_600 "text-blue-600": color(colors::blue::C600);
+        text_blue_700 "text-blue-700": color(colors::blue::C700);
+        text_blue_800 "text-blue-800": color(colors::blue::C800);
+        text_blue_900 "text-blue-900": color(colors::blue::C900);
+        text_blue_950 "text-blue-950": color(colors::blue::C950);
+
+        // === Border Color Methods ===
+        border_transparent "border-transparent": border_color(colors::TRANSPARENT);
+        border_black "border-black": border_color(colors::BLACK);
+        border_white "border-white": border_color(colors::WHITE);
+        border_gray_200 "border-gray-200": border_color(colors::gray::C200);
+        border_gray_300 "border-gray-300": border_color(colors::gray::C300);
+        border_gray_400 "border-gray-400": border_color(colors::gray::C400);
+        border_gray_500 "border-gray-500": border_color(colors::gray::C500);
+        border_gray_600 "border-gray-600": border_color(colors::gray::C600);
+        border_red_500 "border-red-500": border_color(colors::red::C500);
+        border_blue_500 "border-blue-500": border_color(colors::blue::C500);
+        border_green_500 "border-green-500": border_color(colors::green::C500);
+
+        // === Placeholder Color Methods ===
+        // Colors of text input placeholders, styled through Floem's `PlaceholderTextClass`
+        placeholder_transparent "placeholder-transparent": class(PlaceholderTextClass, |s| s.color(colors::TRANSPARENT));
+        placeholder_black "placeholder-black": class(PlaceholderTextClass, |s| s.color(colors::BLACK));
+        placeholder_white "placeholder-white": class(PlaceholderTextClass, |s| s.color(colors::WHITE));
+        placeholder_gray_300 "placeholder-gray-300": class(PlaceholderTextClass, |s| s.color(colors::gray::C300));
+        placeholder_gray_400 "placeholder-gray-400": class(PlaceholderTextClass, |s| s.color(colors::gray::C400));
+        placeholder_gray_500 "placeholder-gray-500": class(PlaceholderTextClass, |s| s.color(colors::gray::C500));
+        placeholder_gray_600 "placeholder-gray-600": class(PlaceholderTextClass, |s| s.color(colors::gray::C600));
+    }
+    extra {
+        /// background color
+        fn bg(self, color: impl Into<Color>) -> Self;
+        /// text color
+        fn text(self, color: impl Into<Color>) -> Self;
+        /// placeholder text color of text inputs
+        fn placeholder(self, color: impl Into<Color>) -> Self;
+    } impl {
+        fn bg(self, color: impl Into<Color>) -> Self {
+            self.map_style(|s| s.background(color.into()))
+        }
+        fn text(self, color: impl Into<Color>) -> Self {
+            self.map_style(|s| s.color(color.into()))
+        }
+        fn placeholder(self, color: impl Into<Color>) -> Self {
+            let color = color.into();
+            self.map_style(|s| s.class(PlaceholderTextClass, |s| s.color(color)))
+        }
+    }
 }
 
-/// Macro to implement font-weight methods
-macro_rules! impl_font_weight_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.font_weight($value)
-            }
-        )*
-    };
+#[cfg(feature = "color")]
+state_shortcuts! {
+    /// Pressed-state colors, shortcuts for `Style::active` (`color` feature).
+    ///
+    /// `s.active_bg_blue_700()` is `s.active(|s| s.bg_blue_700())`. Every
+    /// family and shade of the palette has a background and a text shortcut,
+    /// and the class strings take them as `active:bg-blue-700`.
+    pub trait TailwindActiveExt => active, ACTIVE_UTILITIES, prefixed TwActiveExt {
+        active_bg_transparent "active:bg-transparent": background(colors::TRANSPARENT);
+        active_bg_black "active:bg-black": background(colors::BLACK);
+        active_bg_white "active:bg-white": background(colors::WHITE);
+        active_text_transparent "active:text-transparent": color(colors::TRANSPARENT);
+        active_text_black "active:text-black": color(colors::BLACK);
+        active_text_white "active:text-white": color(colors::WHITE);
+    }
+    palette { bg: background, text: color } for [
+        slate gray zinc neutral stone red orange amber yellow lime green emerald teal cyan sky
+        blue indigo violet purple fuchsia pink rose
+    ] [50 100 200 300 400 500 600 700 800 900 950]
+    extra {
+        /// pressed-state background color
+        fn active_bg(self, color: impl Into<Color>) -> Self;
+        /// pressed-state text color
+        fn active_text(self, color: impl Into<Color>) -> Self;
+    } impl {
+        fn active_bg(self, color: impl Into<Color>) -> Self {
+            let color = color.into();
+            self.map_style(|s| s.active(|s| s.background(color)))
+        }
+        fn active_text(self, color: impl Into<Color>) -> Self {
+            let color = color.into();
+            self.map_style(|s| s.active(|s| s.color(color)))
+        }
+    }
 }
 
-/// Macro to generate line-height methods
-macro_rules! line_height_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self;
-        )*
-    };
+#[cfg(feature = "typography")]
+utilities! {
+    /// Font size, font weight, and line height utilities (`typography` feature).
+    pub trait TailwindTypographyExt => TYPOGRAPHY_UTILITIES, prefixed TwTypographyExt {
+        // === Font Size Methods ===
+        text_xs "text-xs": font_size(rem::scale_f32(font_size::TEXT_XS));
+        text_sm "text-sm": font_size(rem::scale_f32(font_size::TEXT_SM));
+        text_base "text-base": font_size(rem::scale_f32(font_size::TEXT_BASE));
+        text_lg "text-lg": font_size(rem::scale_f32(font_size::TEXT_LG));
+        text_xl "text-xl": font_size(rem::scale_f32(font_size::TEXT_XL));
+        text_2xl "text-2xl": font_size(rem::scale_f32(font_size::TEXT_2XL));
+        text_3xl "text-3xl": font_size(rem::scale_f32(font_size::TEXT_3XL));
+        text_4xl "text-4xl": font_size(rem::scale_f32(font_size::TEXT_4XL));
+        text_5xl "text-5xl": font_size(rem::scale_f32(font_size::TEXT_5XL));
+        text_6xl "text-6xl": font_size(rem::scale_f32(font_size::TEXT_6XL));
+        text_7xl "text-7xl": font_size(rem::scale_f32(font_size::TEXT_7XL));
+        text_8xl "text-8xl": font_size(rem::scale_f32(font_size::TEXT_8XL));
+        text_9xl "text-9xl": font_size(rem::scale_f32(font_size::TEXT_9XL));
+
+        // Font size with a fixed line height (text-sm/6)
+        text_xs_leading_4 "text-xs/4":
+            font_size(rem::scale_f32(font_size::TEXT_XS))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_4))));
+        text_xs_leading_5 "text-xs/5":
+            font_size(rem::scale_f32(font_size::TEXT_XS))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_5))));
+        text_sm_leading_5 "text-sm/5":
+            font_size(rem::scale_f32(font_size::TEXT_SM))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_5))));
+        text_sm_leading_6 "text-sm/6":
+            font_size(rem::scale_f32(font_size::TEXT_SM))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_6))));
+        text_base_leading_6 "text-base/6":
+            font_size(rem::scale_f32(font_size::TEXT_BASE))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_6))));
+        text_base_leading_7 "text-base/7":
+            font_size(rem::scale_f32(font_size::TEXT_BASE))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_7))));
+        text_lg_leading_7 "text-lg/7":
+            font_size(rem::scale_f32(font_size::TEXT_LG))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_7))));
+        text_lg_leading_8 "text-lg/8":
+            font_size(rem::scale_f32(font_size::TEXT_LG))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_8))));
+        text_xl_leading_7 "text-xl/7":
+            font_size(rem::scale_f32(font_size::TEXT_XL))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_7))));
+        text_xl_leading_8 "text-xl/8":
+            font_size(rem::scale_f32(font_size::TEXT_XL))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_8))));
+        text_2xl_leading_8 "text-2xl/8":
+            font_size(rem::scale_f32(font_size::TEXT_2XL))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_8))));
+        text_2xl_leading_9 "text-2xl/9":
+            font_size(rem::scale_f32(font_size::TEXT_2XL))
+            .set(floem::style::LineHeight, Some(floem::text::LineHeightValue::Px(rem::scale(spacing::SPACING_9))));
+
+        // === Font Weight Methods ===
+        font_thin "font-thin": font_weight(font_weight::THIN);
+        font_extralight "font-extralight": font_weight(font_weight::EXTRALIGHT);
+        font_light "font-light": font_weight(font_weight::LIGHT);
+        font_normal "font-normal": font_weight(font_weight::NORMAL);
+        font_medium "font-medium": font_weight(font_weight::MEDIUM);
+        font_semibold "font-semibold": font_weight(font_weight::SEMIBOLD);
+        font_bold "font-bold": font_weight(font_weight::BOLD);
+        font_extrabold "font-extrabold": font_weight(font_weight::EXTRABOLD);
+        font_black "font-black": font_weight(font_weight::BLACK);
+
+        // === Font Stretch Methods ===
+        font_ultra_condensed "font-stretch-ultra-condensed": font_stretch(floem::text::Stretch::UltraCondensed);
+        font_extra_condensed "font-stretch-extra-condensed": font_stretch(floem::text::Stretch::ExtraCondensed);
+        font_condensed "font-stretch-condensed": font_stretch(floem::text::Stretch::Condensed);
+        font_semi_condensed "font-stretch-semi-condensed": font_stretch(floem::text::Stretch::SemiCondensed);
+        font_stretch_normal "font-stretch-normal": font_stretch(floem::text::Stretch::Normal);
+        font_semi_expanded "font-stretch-semi-expanded": font_stretch(floem::text::Stretch::SemiExpanded);
+        font_expanded "font-stretch-expanded": font_stretch(floem::text::Stretch::Expanded);
+        font_extra_expanded "font-stretch-extra-expanded": font_stretch(floem::text::Stretch::ExtraExpanded);
+        font_ultra_expanded "font-stretch-ultra-expanded": font_stretch(floem::text::Stretch::UltraExpanded);
+        // tabular-nums, slashed-zero, and ordinal need OpenType feature
+        // settings, which Floem's text attributes don't expose yet
+        // underline-offset-* and decoration-* need text decorations, which
+        // Floem doesn't draw; links can use a bottom border instead
+
+        // === Line Height Methods ===
+        leading_none "leading-none": line_height(line_height::LEADING_NONE);
+        leading_tight "leading-tight": line_height(line_height::LEADING_TIGHT);
+        leading_snug "leading-snug": line_height(line_height::LEADING_SNUG);
+        leading_normal "leading-normal": line_height(line_height::LEADING_NORMAL);
+        leading_relaxed "leading-relaxed": line_height(line_height::LEADING_RELAXED);
+        leading_loose "leading-loose": line_height(line_height::LEADING_LOOSE);
+    }
 }
 
-/// Macro to implement line-height methods
-macro_rules! impl_line_height_methods {
-    ($($name:ident => $value:expr),* $(,)?) => {
-        $(
-            fn $name(self) -> Self {
-                self.line_height($value)
-            }
-        )*
-    };
+#[cfg(feature = "layout")]
+utilities! {
+    /// Display, flexbox, positioning, alignment, and overflow utilities (`layout` feature).
+    pub trait TailwindLayoutExt => LAYOUT_UTILITIES, prefixed TwLayoutExt {
+        // === Display Methods ===
+        flex "flex": display(floem::style::Display::Flex);
+        block "block": display(floem::style::Display::Block);
+        grid "grid": display(floem::style::Display::Grid);
+        hidden "hidden": display(floem::style::Display::None);
+
+        // === Flex Direction Methods ===
+        flex_row "flex-row": flex_direction(direction::row(false));
+        flex_col "flex-col": flex_direction(floem::style::FlexDirection::Column);
+        flex_row_reverse "flex-row-reverse": flex_direction(direction::row(true));
+        flex_col_reverse "flex-col-reverse":
+            flex_direction(floem::style::FlexDirection::ColumnReverse);
+
+        // === Flex Wrap Methods ===
+        wrap "flex-wrap": flex_wrap(floem::style::FlexWrap::Wrap);
+        nowrap "flex-nowrap": flex_wrap(floem::style::FlexWrap::NoWrap);
+        wrap_reverse "flex-wrap-reverse": flex_wrap(floem::style::FlexWrap::WrapReverse);
+        // order-* needs flex item ordering, which Taffy doesn't implement;
+        // reorder the children or use *-reverse directions instead
+
+        // === Position Methods ===
+        absolute "absolute": position(floem::style::Position::Absolute);
+        relative "relative": position(floem::style::Position::Relative);
+        fixed "fixed": set(floem::style::IsFixed, true);
+        inset_0 "inset-0": inset(0.0);
+        /// left-0
+        left_0 "left-0": inset_left(0.0);
+        /// left-1/2 (50%)
+        left_1_2 "left-1/2": inset_left(Pct(50.0));
+        /// left-full (100%)
+        left_full "left-full": inset_left(Pct(100.0));
+        /// top-0
+        top_0 "top-0": inset_top(0.0);
+        /// top-1/2 (50%)
+        top_1_2 "top-1/2": inset_top(Pct(50.0));
+        /// top-full (100%)
+        top_full "top-full": inset_top(Pct(100.0));
+        /// right-0
+        right_0 "right-0": inset_right(0.0);
+        /// right-1/2 (50%)
+        right_1_2 "right-1/2": inset_right(Pct(50.0));
+        /// right-full (100%)
+        right_full "right-full": inset_right(Pct(100.0));
+        /// bottom-0
+        bottom_0 "bottom-0": inset_bottom(0.0);
+        /// bottom-1/2 (50%)
+        bottom_1_2 "bottom-1/2": inset_bottom(Pct(50.0));
+        /// bottom-full (100%)
+        bottom_full "bottom-full": inset_bottom(Pct(100.0));
+
+        // === Negative Inset Methods ===
+        neg_top_px "-top-px": inset_top(-dpi::snap(1.0));
+        neg_top_0p5 "-top-0.5": inset_top(-rem::scale(spacing::SPACING_0_5));
+        neg_top_1 "-top-1": inset_top(-rem::scale(spacing::SPACING_1));
+        neg_top_1p5 "-top-1.5": inset_top(-rem::scale(spacing::SPACING_1_5));
+        neg_top_2 "-top-2": inset_top(-rem::scale(spacing::SPACING_2));
+        neg_top_2p5 "-top-2.5": inset_top(-rem::scale(spacing::SPACING_2_5));
+        neg_top_3 "-top-3": inset_top(-rem::scale(spacing::SPACING_3));
+        neg_top_3p5 "-top-3.5": inset_top(-rem::scale(spacing::SPACING_3_5));
+        neg_top_4 "-top-4": inset_top(-rem::scale(spacing::SPACING_4));
+        neg_top_5 "-top-5": inset_top(-rem::scale(spacing::SPACING_5));
+        neg_top_6 "-top-6": inset_top(-rem::scale(spacing::SPACING_6));
+        neg_top_7 "-top-7": inset_top(-rem::scale(spacing::SPACING_7));
+        neg_top_8 "-top-8": inset_top(-rem::scale(spacing::SPACING_8));
+        neg_top_9 "-top-9": inset_top(-rem::scale(spacing::SPACING_9));
+        neg_top_10 "-top-10": inset_top(-rem::scale(spacing::SPACING_10));
+        neg_top_11 "-top-11": inset_top(-rem::scale(spacing::SPACING_11));
+        neg_top_12 "-top-12": inset_top(-rem::scale(spacing::SPACING_12));
+        neg_top_14 "-top-14": inset_top(-rem::scale(spacing::SPACING_14));
+        neg_top_16 "-top-16": inset_top(-rem::scale(spacing::SPACING_16));
+        neg_top_20 "-top-20": inset_top(-rem::scale(spacing::SPACING_20));
+        neg_top_24 "-top-24": inset_top(-rem::scale(spacing::SPACING_24));
+        neg_top_28 "-top-28": inset_top(-rem::scale(spacing::SPACING_28));
+        neg_top_32 "-top-32": inset_top(-rem::scale(spacing::SPACING_32));
+        neg_top_36 "-top-36": inset_top(-rem::scale(spacing::SPACING_36));
+        neg_top_40 "-top-40": inset_top(-rem::scale(spacing::SPACING_40));
+        neg_top_44 "-top-44": inset_top(-rem::scale(spacing::SPACING_44));
+        neg_top_48 "-top-48": inset_top(-rem::scale(spacing::SPACING_48));
+        neg_top_52 "-top-52": inset_top(-rem::scale(spacing::SPACING_52));
+        neg_top_56 "-top-56": inset_top(-rem::scale(spacing::SPACING_56));
+        neg_top_60 "-top-60": inset_top(-rem::scale(spacing::SPACING_60));
+        neg_top_64 "-top-64": inset_top(-rem::scale(spacing::SPACING_64));
+        neg_top_72 "-top-72": inset_top(-rem::scale(spacing::SPACING_72));
+        neg_top_80 "-top-80": inset_top(-rem::scale(spacing::SPACING_80));
+        neg_top_96 "-top-96": inset_top(-rem::scale(spacing::SPACING_96));
+        neg_right_px "-right-px": inset_right(-dpi::snap(1.0));
+        neg_right_0p5 "-right-0.5": inset_right(-rem::scale(spacing::SPACING_0_5));
+        neg_right_1 "-right-1": inset_right(-rem::scale(spacing::SPACING_1));
+        neg_right_1p5 "-right-1.5": inset_right(-rem::scale(spacing::SPACING_1_5));
+        neg_right_2 "-right-2": inset_right(-rem::scale(spacing::SPACING_2));
+        neg_right_2p5 "-right-2.5": inset_right(-rem::scale(spacing::SPACING_2_5));
+        neg_right_3 "-right-3": inset_right(-rem::scale(spacing::SPACING_3));
+        neg_right_3p5 "-right-3.5": inset_right(-rem::scale(spacing::SPACING_3_5));
+        neg_right_4 "-right-4": inset_right(-rem::scale(spacing::SPACING_4));
+        neg_right_5 "-right-5": inset_right(-rem::scale(spacing::SPACING_5));
+        neg_right_6 "-right-6": inset_right(-rem::scale(spacing::SPACING_6));
+        neg_right_7 "-right-7": inset_right(-rem::scale(spacing::SPACING_7));
+        neg_right_8 "-right-8": inset_right(-rem::scale(spacing::SPACING_8));
+        neg_right_9 "-right-9": inset_right(-rem::scale(spacing::SPACING_9));
+        neg_right_10 "-right-10": inset_right(-rem::scale(spacing::SPACING_10));
+        neg_right_11 "-right-11": inset_right(-rem::scale(spacing::SPACING_11));
+        neg_right_12 "-right-12": inset_right(-rem::scale(spacing::SPACING_12));
+        neg_right_14 "-right-14": inset_right(-rem::scale(spacing::SPACING_14));
+        neg_right_16 "-right-16": inset_right(-rem::scale(spacing::SPACING_16));
+        neg_right_20 "-right-20": inset_right(-rem::scale(spacing::SPACING_20));
+        neg_right_24 "-right-24": inset_right(-rem::scale(spacing::SPACING_24));
+        neg_right_28 "-right-28": inset_right(-rem::scale(spacing::SPACING_28));
+        neg_right_32 "-right-32": inset_right(-rem::scale(spacing::SPACING_32));
+        neg_right_36 "-right-36": inset_right(-rem::scale(spacing::SPACING_36));
+        neg_right_40 "-right-40": inset_right(-rem::scale(spacing::SPACING_40));
+        neg_right_44 "-right-44": inset_right(-rem::scale(spacing::SPACING_44));
+        neg_right_48 "-right-48": inset_right(-rem::scale(spacing::SPACING_48));
+        neg_right_52 "-right-52": inset_right(-rem::scale(spacing::SPACING_52));
+        neg_right_56 "-right-56": inset_right(-rem::scale(spacing::SPACING_56));
+        neg_right_60 "-right-60": inset_right(-rem::scale(spacing::SPACING_60));
+        neg_right_64 "-right-64": inset_right(-rem::scale(spacing::SPACING_64));
+        neg_right_72 "-right-72": inset_right(-rem::scale(spacing::SPACING_72));
+        neg_right_80 "-right-80": inset_right(-rem::scale(spacing::SPACING_80));
+        neg_right_96 "-right-96": inset_right(-rem::scale(spacing::SPACING_96));
+        neg_bottom_px "-bottom-px": inset_bottom(-dpi::snap(1.0));
+        neg_bottom_0p5 "-bottom-0.5": inset_bottom(-rem::scale(spacing::SPACING_0_5));
+        neg_bottom_1 "-bottom-1": inset_bottom(-rem::scale(spacing::SPACING_1));
+        neg_bottom_1p5 "-bottom-1.5": inset_bottom(-rem::scale(spacing::SPACING_1_5));
+        neg_bottom_2 "-bottom-2": inset_bottom(-rem::scale(spacing::SPACING_2));
+        neg_bottom_2p5 "-bottom-2.5": inset_bottom(-rem::scale(spacing::SPACING_2_5));
+        neg_bottom_3 "-bottom-3": inset_bottom(-rem::scale(spacing::SPACING_3));
+        neg_bottom_3p5 "-bottom-3.5": inset_bottom(-rem::scale(spacing::SPACING_3_5));
+        neg_bottom_4 "-bottom-4": inset_bottom(-rem::scale(spacing::SPACING_4));
+        neg_bottom_5 "-bottom-5": inset_bottom(-rem::scale(spacing::SPACING_5));
+        neg_bottom_6 "-bottom-6": inset_bottom(-rem::scale(spacing::SPACING_6));
+        neg_bottom_7 "-bottom-7": inset_bottom(-rem::scale(spacing::SPACING_7));
+        neg_bottom_8 "-bottom-8": inset_bottom(-rem::scale(spacing::SPACING_8));
+        neg_bottom_9 "-bottom-9": inset_bottom(-rem::scale(spacing::SPACING_9));
+        neg_bottom_10 "-bottom-10": inset_bottom(-rem::scale(spacing::SPACING_10));
+        neg_bottom_11 "-bottom-11": inset_bottom(-rem::scale(spacing::SPACING_11));
+        neg_bottom_12 "-bottom-12": inset_bottom(-rem::scale(spacing::SPACING_12));
+        neg_bottom_14 "-bottom-14": inset_bottom(-rem::scale(spacing::SPACING_14));
+        neg_bottom_16 "-bottom-16": inset_bottom(-rem::scale(spacing::SPACING_16));
+        neg_bottom_20 "-bottom-20": inset_bottom(-rem::scale(spacing::SPACING_20));
+        neg_bottom_24 "-bottom-24": inset_bottom(-rem::scale(spacing::SPACING_24));
+        neg_bottom_28 "-bottom-28": inset_bottom(-rem::scale(spacing::SPACING_28));
+        neg_bottom_32 "-bottom-32": inset_bottom(-rem::scale(spacing::SPACING_32));
+        neg_bottom_36 "-bottom-36": inset_bottom(-rem::scale(spacing::SPACING_36));
+        neg_bottom_40 "-bottom-40": inset_bottom(-rem::scale(spacing::SPACING_40));
+        neg_bottom_44 "-bottom-44": inset_bottom(-rem::scale(spacing::SPACING_44));
+        neg_bottom_48 "-bottom-48": inset_bottom(-rem::scale(spacing::SPACING_48));
+        neg_bottom_52 "-bottom-52": inset_bottom(-rem::scale(spacing::SPACING_52));
+        neg_bottom_56 "-bottom-56": inset_bottom(-rem::scale(spacing::SPACING_56));
+        neg_bottom_60 "-bottom-60": inset_bottom(-rem::scale(spacing::SPACING_60));
+        neg_bottom_64 "-bottom-64": inset_bottom(-rem::scale(spacing::SPACING_64));
+        neg_bottom_72 "-bottom-72": inset_bottom(-rem::scale(spacing::SPACING_72));
+        neg_bottom_80 "-bottom-80": inset_bottom(-rem::scale(spacing::SPACING_80));
+        neg_bottom_96 "-bottom-96": inset_bottom(-rem::scale(spacing::SPACING_96));
+        neg_left_px "-left-px": inset_left(-dpi::snap(1.0));
+        neg_left_0p5 "-left-0.5": inset_left(-rem::scale(spacing::SPACING_0_5));
+        neg_left_1 "-left-1": inset_left(-rem::scale(spacing::SPACING_1));
+        neg_left_1p5 "-left-1.5": inset_left(-rem::scale(spacing::SPACING_1_5));
+        neg_left_2 "-left-2": inset_left(-rem::scale(spacing::SPACING_2));
+        neg_left_2p5 "-left-2.5": inset_left(-rem::scale(spacing::SPACING_2_5));
+        neg_left_3 "-left-3": inset_left(-rem::scale(spacing::SPACING_3));
+        neg_left_3p5 "-left-3.5": inset_left(-rem::scale(spacing::SPACING_3_5));
+        neg_left_4 "-left-4": inset_left(-rem::scale(spacing::SPACING_4));
+        neg_left_5 "-left-5": inset_left(-rem::scale(spacing::SPACING_5));
+        neg_left_6 "-left-6": inset_left(-rem::scale(spacing::SPACING_6));
+        neg_left_7 "-left-7": inset_left(-rem::scale(spacing::SPACING_7));
+        neg_left_8 "-left-8": inset_left(-rem::scale(spacing::SPACING_8));
+        neg_left_9 "-left-9": inset_left(-rem::scale(spacing::SPACING_9));
+        neg_left_10 "-left-10": inset_left(-rem::scale(spacing::SPACING_10));
+        neg_left_11 "-left-11": inset_left(-rem::scale(spacing::SPACING_11));
+        neg_left_12 "-left-12": inset_left(-rem::scale(spacing::SPACING_12));
+        neg_left_14 "-left-14": inset_left(-rem::scale(spacing::SPACING_14));
+        neg_left_16 "-left-16": inset_left(-rem::scale(spacing::SPACING_16));
+        neg_left_20 "-left-20": inset_left(-rem::scale(spacing::SPACING_20));
+        neg_left_24 "-left-24": inset_left(-rem::scale(spacing::SPACING_24));
+        neg_left_28 "-left-28": inset_left(-rem::scale(spacing::SPACING_28));
+        neg_left_32 "-left-32": inset_left(-rem::scale(spacing::SPACING_32));
+        neg_left_36 "-left-36": inset_left(-rem::scale(spacing::SPACING_36));
+        neg_left_40 "-left-40": inset_left(-rem::scale(spacing::SPACING_40));
+        neg_left_44 "-left-44": inset_left(-rem::scale(spacing::SPACING_44));
+        neg_left_48 "-left-48": inset_left(-rem::scale(spacing::SPACING_48));
+        neg_left_52 "-left-52": inset_left(-rem::scale(spacing::SPACING_52));
+        neg_left_56 "-left-56": inset_left(-rem::scale(spacing::SPACING_56));
+        neg_left_60 "-left-60": inset_left(-rem::scale(spacing::SPACING_60));
+        neg_left_64 "-left-64": inset_left(-rem::scale(spacing::SPACING_64));
+        neg_left_72 "-left-72": inset_left(-rem::scale(spacing::SPACING_72));
+        neg_left_80 "-left-80": inset_left(-rem::scale(spacing::SPACING_80));
+        neg_left_96 "-left-96": inset_left(-rem::scale(spacing::SPACING_96));
+
+        // === Justify Content Methods ===
+        justify_start "justify-start":
+            justify_content(Some(floem::style::JustifyContent::FlexStart));
+        justify_center "justify-center":
+            justify_content(Some(floem::style::JustifyContent::Center));
+        justify_end "justify-end": justify_content(Some(floem::style::JustifyContent::FlexEnd));
+        justify_between "justify-between":
+            justify_content(Some(floem::style::JustifyContent::SpaceBetween));
+        justify_around "justify-around":
+            justify_content(Some(floem::style::JustifyContent::SpaceAround));
+        justify_evenly "justify-evenly":
+            justify_content(Some(floem::style::JustifyContent::SpaceEvenly));
+
+        // === Align Items Methods ===
+        items_start "items-start": align_items(Some(floem::style::AlignItems::FlexStart));
+        items_center "items-center": align_items(Some(floem::style::AlignItems::Center));
+        items_end "items-end": align_items(Some(floem::style::AlignItems::FlexEnd));
+        items_stretch "items-stretch": align_items(Some(floem::style::AlignItems::Stretch));
+        items_baseline "items-baseline": align_items(Some(floem::style::AlignItems::Baseline));
+
+        // === Align Self Methods ===
+        /// align-self: auto (inherit from parent's align-items)
+        self_auto "self-auto": align_self(None);
+        /// align-self: flex-start
+        self_start "self-start": align_self(Some(floem::style::AlignItems::FlexStart));
+        /// align-self: center
+        self_center "self-center": align_self(Some(floem::style::AlignItems::Center));
+        /// align-self: flex-end
+        self_end "self-end": align_self(Some(floem::style::AlignItems::FlexEnd));
+        /// align-self: stretch
+        self_stretch "self-stretch": align_self(Some(floem::style::AlignItems::Stretch));
+        /// align-self: baseline
+        self_baseline "self-baseline": align_self(Some(floem::style::AlignItems::Baseline));
+
+        // === Vertical Align Methods ===
+        // Floem has no vertical-align; these align the item itself in its row,
+        // e.g. an icon next to a label
+        /// align-baseline (align-self: baseline)
+        align_baseline "align-baseline": align_self(Some(floem::style::AlignItems::Baseline));
+        /// align-middle (align-self: center)
+        align_middle "align-middle": align_self(Some(floem::style::AlignItems::Center));
+        /// align-top (align-self: flex-start)
+        align_top "align-top": align_self(Some(floem::style::AlignItems::FlexStart));
+        /// align-bottom (align-self: flex-end)
+        align_bottom "align-bottom": align_self(Some(floem::style::AlignItems::FlexEnd));
+
+        // === Translate Methods ===
+        /// translate-x-1/2 (50%)
+        translate_x_1_2 "translate-x-1/2": translate_x(Pct(50.0));
+        /// -translate-x-1/2 (-50%)
+        translate_x_neg_1_2 "-translate-x-1/2": translate_x(Pct(-50.0));
+        /// translate-y-1/2 (50%)
+        translate_y_1_2 "translate-y-1/2": translate_y(Pct(50.0));
+        /// -translate-y-1/2 (-50%)
+        translate_y_neg_1_2 "-translate-y-1/2": translate_y(Pct(-50.0));
+        /// translate-x-full (100%)
+        translate_x_full "translate-x-full": translate_x(Pct(100.0));
+        /// -translate-x-full (-100%)
+        translate_x_neg_full "-translate-x-full": translate_x(Pct(-100.0));
+        /// translate-y-full (100%)
+        translate_y_full "translate-y-full": translate_y(Pct(100.0));
+        /// -translate-y-full (-100%)
+        translate_y_neg_full "-translate-y-full": translate_y(Pct(-100.0));
+
+        // === Negative Translate Methods ===
+        neg_translate_x_px "-translate-x-px": translate_x(-dpi::snap(1.0));
+        neg_translate_x_0p5 "-translate-x-0.5": translate_x(-rem::scale(spacing::SPACING_0_5));
+        neg_translate_x_1 "-translate-x-1": translate_x(-rem::scale(spacing::SPACING_1));
+        neg_translate_x_1p5 "-translate-x-1.5": translate_x(-rem::scale(spacing::SPACING_1_5));
+        neg_translate_x_2 "-translate-x-2": translate_x(-rem::scale(spacing::SPACING_2));
+        neg_translate_x_2p5 "-translate-x-2.5": translate_x(-rem::scale(spacing::SPACING_2_5));
+        neg_translate_x_3 "-translate-x-3": translate_x(-rem::scale(spacing::SPACING_3));
+        neg_translate_x_3p5 "-translate-x-3.5": translate_x(-rem::scale(spacing::SPACING_3_5));
+        neg_translate_x_4 "-translate-x-4": translate_x(-rem::scale(spacing::SPACING_4));
+        neg_translate_x_5 "-translate-x-5": translate_x(-rem::scale(spacing::SPACING_5));
+        neg_translate_x_6 "-translate-x-6": translate_x(-rem::scale(spacing::SPACING_6));
+        neg_translate_x_7 "-translate-x-7": translate_x(-rem::scale(spacing::SPACING_7));
+        neg_translate_x_8 "-translate-x-8": translate_x(-rem::scale(spacing::SPACING_8));
+        neg_translate_x_9 "-translate-x-9": translate_x(-rem::scale(spacing::SPACING_9));
+        neg_translate_x_10 "-translate-x-10": translate_x(-rem::scale(spacing::SPACING_10));
+        neg_translate_x_11 "-translate-x-11": translate_x(-rem::scale(spacing::SPACING_11));
+        neg_translate_x_12 "-translate-x-12": translate_x(-rem::scale(spacing::SPACING_12));
+        neg_translate_x_14 "-translate-x-14": translate_x(-rem::scale(spacing::SPACING_14));
+        neg_translate_x_16 "-translate-x-16": translate_x(-rem::scale(spacing::SPACING_16));
+        neg_translate_x_20 "-translate-x-20": translate_x(-rem::scale(spacing::SPACING_20));
+        neg_translate_x_24 "-translate-x-24": translate_x(-rem::scale(spacing::SPACING_24));
+        neg_translate_x_28 "-translate-x-28": translate_x(-rem::scale(spacing::SPACING_28));
+        neg_translate_x_32 "-translate-x-32": translate_x(-rem::scale(spacing::SPACING_32));
+        neg_translate_x_36 "-translate-x-36": translate_x(-rem::scale(spacing::SPACING_36));
+        neg_translate_x_40 "-translate-x-40": translate_x(-rem::scale(spacing::SPACING_40));
+        neg_translate_x_44 "-translate-x-44": translate_x(-rem::scale(spacing::SPACING_44));
+        neg_translate_x_48 "-translate-x-48": translate_x(-rem::scale(spacing::SPACING_48));
+        neg_translate_x_52 "-translate-x-52": translate_x(-rem::scale(spacing::SPACING_52));
+        neg_translate_x_56 "-translate-x-56": translate_x(-rem::scale(spacing::SPACING_56));
+        neg_translate_x_60 "-translate-x-60": translate_x(-rem::scale(spacing::SPACING_60));
+        neg_translate_x_64 "-translate-x-64": translate_x(-rem::scale(spacing::SPACING_64));
+        neg_translate_x_72 "-translate-x-72": translate_x(-rem::scale(spacing::SPACING_72));
+        neg_translate_x_80 "-translate-x-80": translate_x(-rem::scale(spacing::SPACING_80));
+        neg_translate_x_96 "-translate-x-96": translate_x(-rem::scale(spacing::SPACING_96));
+        neg_translate_y_px "-translate-y-px": translate_y(-dpi::snap(1.0));
+        neg_translate_y_0p5 "-translate-y-0.5": translate_y(-rem::scale(spacing::SPACING_0_5));
+        neg_translate_y_1 "-translate-y-1": translate_y(-rem::scale(spacing::SPACING_1));
+        neg_translate_y_1p5 "-translate-y-1.5": translate_y(-rem::scale(spacing::SPACING_1_5));
+        neg_translate_y_2 "-translate-y-2": translate_y(-rem::scale(spacing::SPACING_2));
+        neg_translate_y_2p5 "-translate-y-2.5": translate_y(-rem::scale(spacing::SPACING_2_5));
+        neg_translate_y_3 "-translate-y-3": translate_y(-rem::scale(spacing::SPACING_3));
+        neg_translate_y_3p5 "-translate-y-3.5": translate_y(-rem::scale(spacing::SPACING_3_5));
+        neg_translate_y_4 "-translate-y-4": translate_y(-rem::scale(spacing::SPACING_4));
+        neg_translate_y_5 "-translate-y-5": translate_y(-rem::scale(spacing::SPACING_5));
+        neg_translate_y_6 "-translate-y-6": translate_y(-rem::scale(spacing::SPACING_6));
+        neg_translate_y_7 "-translate-y-7": translate_y(-rem::scale(spacing::SPACING_7));
+        neg_translate_y_8 "-translate-y-8": translate_y(-rem::scale(spacing::SPACING_8));
+        neg_translate_y_9 "-translate-y-9": translate_y(-rem::scale(spacing::SPACING_9));
+        neg_translate_y_10 "-translate-y-10": translate_y(-rem::scale(spacing::SPACING_10));
+        neg_translate_y_11 "-translate-y-11": translate_y(-rem::scale(spacing::SPACING_11));
+        neg_translate_y_12 "-translate-y-12": translate_y(-rem::scale(spacing::SPACING_12));
+        neg_translate_y_14 "-translate-y-14": translate_y(-rem::scale(spacing::SPACING_14));
+        neg_translate_y_16 "-translate-y-16": translate_y(-rem::scale(spacing::SPACING_16));
+        neg_translate_y_20 "-translate-y-20": translate_y(-rem::scale(spacing::SPACING_20));
+        neg_translate_y_24 "-translate-y-24": translate_y(-rem::scale(spacing::SPACING_24));
+        neg_translate_y_28 "-translate-y-28": translate_y(-rem::scale(spacing::SPACING_28));
+        neg_translate_y_32 "-translate-y-32": translate_y(-rem::scale(spacing::SPACING_32));
+        neg_translate_y_36 "-translate-y-36": translate_y(-rem::scale(spacing::SPACING_36));
+        neg_translate_y_40 "-translate-y-40": translate_y(-rem::scale(spacing::SPACING_40));
+        neg_translate_y_44 "-translate-y-44": translate_y(-rem::scale(spacing::SPACING_44));
+        neg_translate_y_48 "-translate-y-48": translate_y(-rem::scale(spacing::SPACING_48));
+        neg_translate_y_52 "-translate-y-52": translate_y(-rem::scale(spacing::SPACING_52));
+        neg_translate_y_56 "-translate-y-56": translate_y(-rem::scale(spacing::SPACING_56));
+        neg_translate_y_60 "-translate-y-60": translate_y(-rem::scale(spacing::SPACING_60));
+        neg_translate_y_64 "-translate-y-64": translate_y(-rem::scale(spacing::SPACING_64));
+        neg_translate_y_72 "-translate-y-72": translate_y(-rem::scale(spacing::SPACING_72));
+        neg_translate_y_80 "-translate-y-80": translate_y(-rem::scale(spacing::SPACING_80));
+        neg_translate_y_96 "-translate-y-96": translate_y(-rem::scale(spacing::SPACING_96));
+
+        // === Z-Index Methods ===
+        z_0 "z-0": z_index(0);
+        z_10 "z-10": z_index(10);
+        z_20 "z-20": z_index(20);
+        z_30 "z-30": z_index(30);
+        z_40 "z-40": z_index(40);
+        z_50 "z-50": z_index(50);
+        z_auto "z-auto": set(floem::style::ZIndex, None);
+        neg_z_10 "-z-10": z_index(-10);
+        neg_z_20 "-z-20": z_index(-20);
+        neg_z_30 "-z-30": z_index(-30);
+        neg_z_40 "-z-40": z_index(-40);
+        neg_z_50 "-z-50": z_index(-50);
+
+        // === Overflow Methods ===
+        /// overflow-hidden
+        overflow_hidden "overflow-hidden":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Hidden).set(floem::style::OverflowY, floem::taffy::Overflow::Hidden);
+        /// overflow-visible
+        overflow_visible "overflow-visible":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Visible).set(floem::style::OverflowY, floem::taffy::Overflow::Visible);
+        /// overflow-scroll
+        overflow_scroll "overflow-scroll":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Scroll).set(floem::style::OverflowY, floem::taffy::Overflow::Scroll);
+        /// overflow-clip
+        overflow_clip "overflow-clip":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Clip).set(floem::style::OverflowY, floem::taffy::Overflow::Clip);
+        /// overflow-auto
+        overflow_auto "overflow-auto":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Scroll).set(floem::style::OverflowY, floem::taffy::Overflow::Scroll);
+        /// overflow-x-hidden
+        overflow_x_hidden "overflow-x-hidden":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Hidden);
+        /// overflow-x-visible
+        overflow_x_visible "overflow-x-visible":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Visible);
+        /// overflow-x-scroll
+        overflow_x_scroll "overflow-x-scroll":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Scroll);
+        /// overflow-x-clip
+        overflow_x_clip "overflow-x-clip":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Clip);
+        /// overflow-x-auto
+        overflow_x_auto "overflow-x-auto":
+            set(floem::style::OverflowX, floem::taffy::Overflow::Scroll);
+        /// overflow-y-hidden
+        overflow_y_hidden "overflow-y-hidden":
+            set(floem::style::OverflowY, floem::taffy::Overflow::Hidden);
+        /// overflow-y-visible
+        overflow_y_visible "overflow-y-visible":
+            set(floem::style::OverflowY, floem::taffy::Overflow::Visible);
+        /// overflow-y-scroll
+        overflow_y_scroll "overflow-y-scroll":
+            set(floem::style::OverflowY, floem::taffy::Overflow::Scroll);
+        /// overflow-y-clip
+        overflow_y_clip "overflow-y-clip":
+            set(floem::style::OverflowY, floem::taffy::Overflow::Clip);
+        /// overflow-y-auto
+        overflow_y_auto "overflow-y-auto":
+            set(floem::style::OverflowY, floem::taffy::Overflow::Scroll);
+    }
 }
 
-/// Extension trait that adds Tailwind-style utility methods to Floem's Style.
-pub trait TailwindExt: Sized {
-    // === Width Methods ===
-    width_methods! {
-        w_0 => 0.0,
-        w_px => 1.0,
-        w_0p5 => spacing::SPACING_0_5,
-        w_1 => spacing::SPACING_1,
-        w_1p5 => spacing::SPACING_1_5,
-        w_2 => spacing::SPACING_2,
-        w_2p5 => spacing::SPACING_2_5,
-        w_3 => spacing::SPACING_3,
-        w_3p5 => spacing::SPACING_3_5,
-        w_4 => spacing::SPACING_4,
-        w_5 => spacing::SPACING_5,
-        w_6 => spacing::SPACING_6,
-        w_7 => spacing::SPACING_7,
-        w_8 => spacing::SPACING_8,
-        w_9 => spacing::SPACING_9,
-        w_10 => spacing::SPACING_10,
-        w_11 => spacing::SPACING_11,
-        w_12 => spacing::SPACING_12,
-        w_14 => spacing::SPACING_14,
-        w_16 => spacing::SPACING_16,
-        w_20 => spacing::SPACING_20,
-        w_24 => spacing::SPACING_24,
-        w_28 => spacing::SPACING_28,
-        w_32 => spacing::SPACING_32,
-        w_36 => spacing::SPACING_36,
-        w_40 => spacing::SPACING_40,
-        w_44 => spacing::SPACING_44,
-        w_48 => spacing::SPACING_48,
-        w_52 => spacing::SPACING_52,
-        w_56 => spacing::SPACING_56,
-        w_60 => spacing::SPACING_60,
-        w_64 => spacing::SPACING_64,
-        w_72 => spacing::SPACING_72,
-        w_80 => spacing::SPACING_80,
-        w_96 => spacing::SPACING_96,
-    }
-
-    // Named width sizes
-    fn w_xs(self) -> Self;
-    fn w_sm(self) -> Self;
-    fn w_md(self) -> Self;
-    fn w_lg(self) -> Self;
-    fn w_xl(self) -> Self;
-    fn w_2xl(self) -> Self;
-    fn w_3xl(self) -> Self;
-    fn w_4xl(self) -> Self;
-    fn w_5xl(self) -> Self;
-    fn w_6xl(self) -> Self;
-    fn w_7xl(self) -> Self;
-
-    // Percentage widths
-    fn w_full(self) -> Self;
-    fn w_auto(self) -> Self;
-    // Fractional widths (GPUI-style: underscore = fraction, e.g., w_1_2 = 1/2 = 50%)
-    fn w_1_2(self) -> Self; // 1/2 = 50%
-    fn w_1_3(self) -> Self; // 1/3 = 33.33%
-    fn w_2_3(self) -> Self; // 2/3 = 66.67%
-    fn w_1_4(self) -> Self; // 1/4 = 25%
-    fn w_3_4(self) -> Self; // 3/4 = 75%
-    fn w_1_5(self) -> Self; // 1/5 = 20%
-    fn w_2_5(self) -> Self; // 2/5 = 40%
-    fn w_3_5(self) -> Self; // 3/5 = 60%
-    fn w_4_5(self) -> Self; // 4/5 = 80%
-    fn w_1_6(self) -> Self; // 1/6 = 16.67%
-    fn w_5_6(self) -> Self; // 5/6 = 83.33%
-    fn w_1_12(self) -> Self; // 1/12 = 8.33%
-
-    // === Height Methods ===
-    height_methods! {
-        h_0 => 0.0,
-        h_px => 1.0,
-        h_0p5 => spacing::SPACING_0_5,
-        h_1 => spacing::SPACING_1,
-        h_1p5 => spacing::SPACING_1_5,
-        h_2 => spacing::SPACING_2,
-        h_2p5 => spacing::SPACING_2_5,
-        h_3 => spacing::SPACING_3,
-        h_3p5 => spacing::SPACING_3_5,
-        h_4 => spacing::SPACING_4,
-        h_5 => spacing::SPACING_5,
-        h_6 => spacing::SPACING_6,
-        h_7 => spacing::SPACING_7,
-        h_8 => spacing::SPACING_8,
-        h_9 => spacing::SPACING_9,
-        h_10 => spacing::SPACING_10,
-        h_11 => spacing::SPACING_11,
-        h_12 => spacing::SPACING_12,
-        h_14 => spacing::SPACING_14,
-        h_16 => spacing::SPACING_16,
-        h_20 => spacing::SPACING_20,
-        h_24 => spacing::SPACING_24,
-        h_28 => spacing::SPACING_28,
-        h_32 => spacing::SPACING_32,
-        h_36 => spacing::SPACING_36,
-        h_40 => spacing::SPACING_40,
-        h_44 => spacing::SPACING_44,
-        h_48 => spacing::SPACING_48,
-        h_52 => spacing::SPACING_52,
-        h_56 => spacing::SPACING_56,
-        h_60 => spacing::SPACING_60,
-        h_64 => spacing::SPACING_64,
-        h_72 => spacing::SPACING_72,
-        h_80 => spacing::SPACING_80,
-        h_96 => spacing::SPACING_96,
-    }
-
-    // Named height sizes
-    fn h_xs(self) -> Self;
-    fn h_sm(self) -> Self;
-    fn h_md(self) -> Self;
-    fn h_lg(self) -> Self;
-    fn h_xl(self) -> Self;
-    fn h_2xl(self) -> Self;
-    fn h_3xl(self) -> Self;
-    fn h_4xl(self) -> Self;
-    fn h_5xl(self) -> Self;
-    fn h_6xl(self) -> Self;
-    fn h_7xl(self) -> Self;
-
-    // Percentage heights
-    fn h_full(self) -> Self;
-    fn h_auto(self) -> Self;
-    // Fractional heights (GPUI-style: underscore = fraction, e.g., h_1_2 = 1/2 = 50%)
-    fn h_1_2(self) -> Self; // 1/2 = 50%
-    fn h_1_3(self) -> Self; // 1/3 = 33.33%
-    fn h_2_3(self) -> Self; // 2/3 = 66.67%
-    fn h_1_4(self) -> Self; // 1/4 = 25%
-    fn h_3_4(self) -> Self; // 3/4 = 75%
-    fn h_1_5(self) -> Self; // 1/5 = 20%
-    fn h_2_5(self) -> Self; // 2/5 = 40%
-    fn h_3_5(self) -> Self; // 3/5 = 60%
-    fn h_4_5(self) -> Self; // 4/5 = 80%
-    fn h_1_6(self) -> Self; // 1/6 = 16.67%
-    fn h_5_6(self) -> Self; // 5/6 = 83.33%
-    fn h_1_12(self) -> Self; // 1/12 = 8.33%
-
-    // === Size Methods (width + height) ===
-    size_methods! {
-        size_0 => 0.0,
-        size_px => 1.0,
-        size_0p5 => spacing::SPACING_0_5,
-        size_1 => spacing::SPACING_1,
-        size_1p5 => spacing::SPACING_1_5,
-        size_2 => spacing::SPACING_2,
-        size_2p5 => spacing::SPACING_2_5,
-        size_3 => spacing::SPACING_3,
-        size_3p5 => spacing::SPACING_3_5,
-        size_4 => spacing::SPACING_4,
-        size_5 => spacing::SPACING_5,
-        size_6 => spacing::SPACING_6,
-        size_7 => spacing::SPACING_7,
-        size_8 => spacing::SPACING_8,
-        size_9 => spacing::SPACING_9,
-        size_10 => spacing::SPACING_10,
-        size_11 => spacing::SPACING_11,
-        size_12 => spacing::SPACING_12,
-        size_14 => spacing::SPACING_14,
-        size_16 => spacing::SPACING_16,
-        size_20 => spacing::SPACING_20,
-        size_24 => spacing::SPACING_24,
-        size_28 => spacing::SPACING_28,
-        size_32 => spacing::SPACING_32,
-        size_36 => spacing::SPACING_36,
-        size_40 => spacing::SPACING_40,
-        size_44 => spacing::SPACING_44,
-        size_48 => spacing::SPACING_48,
-        size_52 => spacing::SPACING_52,
-        size_56 => spacing::SPACING_56,
-        size_60 => spacing::SPACING_60,
-        size_64 => spacing::SPACING_64,
-        size_72 => spacing::SPACING_72,
-        size_80 => spacing::SPACING_80,
-        size_96 => spacing::SPACING_96,
-    }
-
-    // === Min-Width Methods ===
-    min_width_methods! {
-        min_w_0 => 0.0,
-        min_w_px => 1.0,
-        min_w_1 => spacing::SPACING_1,
-        min_w_2 => spacing::SPACING_2,
-        min_w_4 => spacing::SPACING_4,
-        min_w_8 => spacing::SPACING_8,
-        min_w_16 => spacing::SPACING_16,
-        min_w_32 => spacing::SPACING_32,
-        min_w_64 => spacing::SPACING_64,
-        min_w_96 => spacing::SPACING_96,
-    }
-    fn min_w_full(self) -> Self;
-    fn min_w_xs(self) -> Self;
-    fn min_w_sm(self) -> Self;
-    fn min_w_md(self) -> Self;
-    fn min_w_lg(self) -> Self;
-    fn min_w_xl(self) -> Self;
-
-    // === Max-Width Methods ===
-    max_width_methods! {
-        max_w_0 => 0.0,
-        max_w_px => 1.0,
-        max_w_1 => spacing::SPACING_1,
-        max_w_2 => spacing::SPACING_2,
-        max_w_4 => spacing::SPACING_4,
-        max_w_8 => spacing::SPACING_8,
-        max_w_16 => spacing::SPACING_16,
-        max_w_32 => spacing::SPACING_32,
-        max_w_64 => spacing::SPACING_64,
-        max_w_96 => spacing::SPACING_96,
-    }
-    fn max_w_full(self) -> Self;
-    fn max_w_xs(self) -> Self;
-    fn max_w_sm(self) -> Self;
-    fn max_w_md(self) -> Self;
-    fn max_w_lg(self) -> Self;
-    fn max_w_xl(self) -> Self;
-    fn max_w_2xl(self) -> Self;
-    fn max_w_3xl(self) -> Self;
-    fn max_w_4xl(self) -> Self;
-    fn max_w_5xl(self) -> Self;
-    fn max_w_6xl(self) -> Self;
-    fn max_w_7xl(self) -> Self;
-
-    // === Padding Methods ===
-    padding_methods! {
-        p_0 => 0.0,
-        p_px => 1.0,
-        p_0p5 => spacing::SPACING_0_5,
-        p_1 => spacing::SPACING_1,
-        p_1p5 => spacing::SPACING_1_5,
-        p_2 => spacing::SPACING_2,
-        p_2p5 => spacing::SPACING_2_5,
-        p_3 => spacing::SPACING_3,
-        p_3p5 => spacing::SPACING_3_5,
-        p_4 => spacing::SPACING_4,
-        p_5 => spacing::SPACING_5,
-        p_6 => spacing::SPACING_6,
-        p_7 => spacing::SPACING_7,
-        p_8 => spacing::SPACING_8,
-        p_9 => spacing::SPACING_9,
-        p_10 => spacing::SPACING_10,
-        p_11 => spacing::SPACING_11,
-        p_12 => spacing::SPACING_12,
-        p_14 => spacing::SPACING_14,
-        p_16 => spacing::SPACING_16,
-        p_20 => spacing::SPACING_20,
-        p_24 => spacing::SPACING_24,
-    }
-
-    // Horizontal padding (px-*)
-    padding_x_methods! {
-        px_0 => 0.0,
-        px_px => 1.0,
-        px_0p5 => spacing::SPACING_0_5,
-        px_1 => spacing::SPACING_1,
-        px_1p5 => spacing::SPACING_1_5,
-        px_2 => spacing::SPACING_2,
-        px_2p5 => spacing::SPACING_2_5,
-        px_3 => spacing::SPACING_3,
-        px_3p5 => spacing::SPACING_3_5,
-        px_4 => spacing::SPACING_4,
-        px_5 => spacing::SPACING_5,
-        px_6 => spacing::SPACING_6,
-        px_7 => spacing::SPACING_7,
-        px_8 => spacing::SPACING_8,
-        px_9 => spacing::SPACING_9,
-        px_10 => spacing::SPACING_10,
-        px_12 => spacing::SPACING_12,
-        px_16 => spacing::SPACING_16,
-        px_20 => spacing::SPACING_20,
-        px_24 => spacing::SPACING_24,
-    }
-
-    // Vertical padding (py-*)
-    padding_y_methods! {
-        py_0 => 0.0,
-        py_px => 1.0,
-        py_0p5 => spacing::SPACING_0_5,
-        py_1 => spacing::SPACING_1,
-        py_1p5 => spacing::SPACING_1_5,
-        py_2 => spacing::SPACING_2,
-        py_2p5 => spacing::SPACING_2_5,
-        py_3 => spacing::SPACING_3,
-        py_3p5 => spacing::SPACING_3_5,
-        py_4 => spacing::SPACING_4,
-        py_5 => spacing::SPACING_5,
-        py_6 => spacing::SPACING_6,
-        py_7 => spacing::SPACING_7,
-        py_8 => spacing::SPACING_8,
-        py_9 => spacing::SPACING_9,
-        py_10 => spacing::SPACING_10,
-        py_12 => spacing::SPACING_12,
-        py_16 => spacing::SPACING_16,
-        py_20 => spacing::SPACING_20,
-        py_24 => spacing::SPACING_24,
-    }
-
-    // Individual padding sides
-    fn pt_0(self) -> Self;
-    fn pt_1(self) -> Self;
-    fn pt_2(self) -> Self;
-    fn pt_3(self) -> Self;
-    fn pt_4(self) -> Self;
-    fn pt_5(self) -> Self;
-    fn pt_6(self) -> Self;
-    fn pt_8(self) -> Self;
-
-    fn pb_0(self) -> Self;
-    fn pb_1(self) -> Self;
-    fn pb_2(self) -> Self;
-    fn pb_3(self) -> Self;
-    fn pb_4(self) -> Self;
-    fn pb_5(self) -> Self;
-    fn pb_6(self) -> Self;
-    fn pb_8(self) -> Self;
-
-    fn pl_0(self) -> Self;
-    fn pl_1(self) -> Self;
-    fn pl_2(self) -> Self;
-    fn pl_3(self) -> Self;
-    fn pl_4(self) -> Self;
-    fn pl_5(self) -> Self;
-    fn pl_6(self) -> Self;
-    fn pl_8(self) -> Self;
-
-    fn pr_0(self) -> Self;
-    fn pr_1(self) -> Self;
-    fn pr_2(self) -> Self;
-    fn pr_3(self) -> Self;
-    fn pr_4(self) -> Self;
-    fn pr_5(self) -> Self;
-    fn pr_6(self) -> Self;
-    fn pr_8(self) -> Self;
-
-    // === Margin Methods ===
-    margin_methods! {
-        m_0 => 0.0,
-        m_px => 1.0,
-        m_0p5 => spacing::SPACING_0_5,
-        m_1 => spacing::SPACING_1,
-        m_1p5 => spacing::SPACING_1_5,
-        m_2 => spacing::SPACING_2,
-        m_2p5 => spacing::SPACING_2_5,
-        m_3 => spacing::SPACING_3,
-        m_3p5 => spacing::SPACING_3_5,
-        m_4 => spacing::SPACING_4,
-        m_5 => spacing::SPACING_5,
-        m_6 => spacing::SPACING_6,
-        m_7 => spacing::SPACING_7,
-        m_8 => spacing::SPACING_8,
-        m_9 => spacing::SPACING_9,
-        m_10 => spacing::SPACING_10,
-        m_11 => spacing::SPACING_11,
-        m_12 => spacing::SPACING_12,
-        m_14 => spacing::SPACING_14,
-        m_16 => spacing::SPACING_16,
-        m_20 => spacing::SPACING_20,
-        m_24 => spacing::SPACING_24,
-    }
-
-    fn m_auto(self) -> Self;
-
-    // Horizontal margin (mx-*)
-    margin_x_methods! {
-        mx_0 => 0.0,
-        mx_px => 1.0,
-        mx_0p5 => spacing::SPACING_0_5,
-        mx_1 => spacing::SPACING_1,
-        mx_1p5 => spacing::SPACING_1_5,
-        mx_2 => spacing::SPACING_2,
-        mx_2p5 => spacing::SPACING_2_5,
-        mx_3 => spacing::SPACING_3,
-        mx_3p5 => spacing::SPACING_3_5,
-        mx_4 => spacing::SPACING_4,
-        mx_5 => spacing::SPACING_5,
-        mx_6 => spacing::SPACING_6,
-        mx_8 => spacing::SPACING_8,
-        mx_10 => spacing::SPACING_10,
-        mx_12 => spacing::SPACING_12,
-        mx_16 => spacing::SPACING_16,
-        mx_20 => spacing::SPACING_20,
-        mx_24 => spacing::SPACING_24,
-    }
-
-    fn mx_auto(self) -> Self;
-
-    // Vertical margin (my-*)
-    margin_y_methods! {
-        my_0 => 0.0,
-        my_px => 1.0,
-        my_0p5 => spacing::SPACING_0_5,
-        my_1 => spacing::SPACING_1,
-        my_1p5 => spacing::SPACING_1_5,
-        my_2 => spacing::SPACING_2,
-        my_2p5 => spacing::SPACING_2_5,
-        my_3 => spacing::SPACING_3,
-        my_3p5 => spacing::SPACING_3_5,
-        my_4 => spacing::SPACING_4,
-        my_5 => spacing::SPACING_5,
-        my_6 => spacing::SPACING_6,
-        my_8 => spacing::SPACING_8,
-        my_10 => spacing::SPACING_10,
-        my_12 => spacing::SPACING_12,
-        my_16 => spacing::SPACING_16,
-        my_20 => spacing::SPACING_20,
-        my_24 => spacing::SPACING_24,
-    }
-
-    fn my_auto(self) -> Self;
-
-    // Individual margin sides
-    fn mt_0(self) -> Self;
-    fn mt_1(self) -> Self;
-    fn mt_2(self) -> Self;
-    fn mt_3(self) -> Self;
-    fn mt_4(self) -> Self;
-    fn mt_5(self) -> Self;
-    fn mt_6(self) -> Self;
-    fn mt_8(self) -> Self;
-    fn mt_auto(self) -> Self;
-
-    fn mb_0(self) -> Self;
-    fn mb_1(self) -> Self;
-    fn mb_2(self) -> Self;
-    fn mb_3(self) -> Self;
-    fn mb_4(self) -> Self;
-    fn mb_5(self) -> Self;
-    fn mb_6(self) -> Self;
-    fn mb_8(self) -> Self;
-    fn mb_auto(self) -> Self;
-
-    fn ml_0(self) -> Self;
-    fn ml_1(self) -> Self;
-    fn ml_2(self) -> Self;
-    fn ml_3(self) -> Self;
-    fn ml_4(self) -> Self;
-    fn ml_5(self) -> Self;
-    fn ml_6(self) -> Self;
-    fn ml_8(self) -> Self;
-    fn ml_auto(self) -> Self;
-
-    fn mr_0(self) -> Self;
-    fn mr_1(self) -> Self;
-    fn mr_2(self) -> Self;
-    fn mr_3(self) -> Self;
-    fn mr_4(self) -> Self;
-    fn mr_5(self) -> Self;
-    fn mr_6(self) -> Self;
-    fn mr_8(self) -> Self;
-    fn mr_auto(self) -> Self;
-
-    // === Gap Methods ===
-    gap_methods! {
-        gap_0 => 0.0,
-        gap_px => 1.0,
-        gap_0p5 => spacing::SPACING_0_5,
-        gap_1 => spacing::SPACING_1,
-        gap_1p5 => spacing::SPACING_1_5,
-        gap_2 => spacing::SPACING_2,
-        gap_2p5 => spacing::SPACING_2_5,
-        gap_3 => spacing::SPACING_3,
-        gap_3p5 => spacing::SPACING_3_5,
-        gap_4 => spacing::SPACING_4,
-        gap_5 => spacing::SPACING_5,
-        gap_6 => spacing::SPACING_6,
-        gap_7 => spacing::SPACING_7,
-        gap_8 => spacing::SPACING_8,
-        gap_9 => spacing::SPACING_9,
-        gap_10 => spacing::SPACING_10,
-        gap_11 => spacing::SPACING_11,
-        gap_12 => spacing::SPACING_12,
-        gap_14 => spacing::SPACING_14,
-        gap_16 => spacing::SPACING_16,
-        gap_20 => spacing::SPACING_20,
-        gap_24 => spacing::SPACING_24,
-    }
-
-    // === Border Radius Methods ===
-    rounded_methods! {
-        rounded_none => radius::ROUNDED_NONE,
-        rounded_sm => radius::ROUNDED_SM,
-        rounded => radius::ROUNDED,
-        rounded_md => radius::ROUNDED_MD,
-        rounded_lg => radius::ROUNDED_LG,
-        rounded_xl => radius::ROUNDED_XL,
-        rounded_2xl => radius::ROUNDED_2XL,
-        rounded_3xl => radius::ROUNDED_3XL,
-        rounded_full => radius::ROUNDED_FULL,
-    }
-
-    // === Border Width Methods ===
-    fn border_0(self) -> Self;
-    fn border_1(self) -> Self;
-    fn border_2(self) -> Self;
-    fn border_4(self) -> Self;
-    fn border_8(self) -> Self;
-
-    // === Shadow Methods ===
-    fn shadow_sm(self) -> Self;
-    fn shadow(self) -> Self;
-    fn shadow_md(self) -> Self;
-    fn shadow_lg(self) -> Self;
-    fn shadow_xl(self) -> Self;
-    fn shadow_2xl(self) -> Self;
-    fn shadow_none(self) -> Self;
-
-    // === Background Color Methods ===
-    fn bg(self, color: impl Into<Color>) -> Self;
-    fn bg_transparent(self) -> Self;
-    fn bg_black(self) -> Self;
-    fn bg_white(self) -> Self;
-    // Slate
-    fn bg_slate_50(self) -> Self;
-    fn bg_slate_100(self) -> Self;
-    fn bg_slate_200(self) -> Self;
-    fn bg_slate_300(self) -> Self;
-    fn bg_slate_400(self) -> Self;
-    fn bg_slate_500(self) -> Self;
-    fn bg_slate_600(self) -> Self;
-    fn bg_slate_700(self) -> Self;
-    fn bg_slate_800(self) -> Self;
-    fn bg_slate_900(self) -> Self;
-    fn bg_slate_950(self) -> Self;
-    // Gray
-    fn bg_gray_50(self) -> Self;
-    fn bg_gray_100(self) -> Self;
-    fn bg_gray_200(self) -> Self;
-    fn bg_gray_300(self) -> Self;
-    fn bg_gray_400(self) -> Self;
-    fn bg_gray_500(self) -> Self;
-    fn bg_gray_600(self) -> Self;
-    fn bg_gray_700(self) -> Self;
-    fn bg_gray_800(self) -> Self;
-    fn bg_gray_900(self) -> Self;
-    fn bg_gray_950(self) -> Self;
-    // Zinc
-    fn bg_zinc_50(self) -> Self;
-    fn bg_zinc_100(self) -> Self;
-    fn bg_zinc_200(self) -> Self;
-    fn bg_zinc_300(self) -> Self;
-    fn bg_zinc_400(self) -> Self;
-    fn bg_zinc_500(self) -> Self;
-    fn bg_zinc_600(self) -> Self;
-    fn bg_zinc_700(self) -> Self;
-    fn bg_zinc_800(self) -> Self;
-    fn bg_zinc_900(self) -> Self;
-    fn bg_zinc_950(self) -> Self;
-    // Red
-    fn bg_red_50(self) -> Self;
-    fn bg_red_100(self) -> Self;
-    fn bg_red_200(self) -> Self;
-    fn bg_red_300(self) -> Self;
-    fn bg_red_400(self) -> Self;
-    fn bg_red_500(self) -> Self;
-    fn bg_red_600(self) -> Self;
-    fn bg_red_700(self) -> Self;
-    fn bg_red_800(self) -> Self;
-    fn bg_red_900(self) -> Self;
-    fn bg_red_950(self) -> Self;
-    // Orange
-    fn bg_orange_50(self) -> Self;
-    fn bg_orange_100(self) -> Self;
-    fn bg_orange_200(self) -> Self;
-    fn bg_orange_300(self) -> Self;
-    fn bg_orange_400(self) -> Self;
-    fn bg_orange_500(self) -> Self;
-    fn bg_orange_600(self) -> Self;
-    fn bg_orange_700(self) -> Self;
-    fn bg_orange_800(self) -> Self;
-    fn bg_orange_900(self) -> Self;
-    fn bg_orange_950(self) -> Self;
-    // Yellow
-    fn bg_yellow_50(self) -> Self;
-    fn bg_yellow_100(self) -> Self;
-    fn bg_yellow_200(self) -> Self;
-    fn bg_yellow_300(self) -> Self;
-    fn bg_yellow_400(self) -> Self;
-    fn bg_yellow_500(self) -> Self;
-    fn bg_yellow_600(self) -> Self;
-    fn bg_yellow_700(self) -> Self;
-    fn bg_yellow_800(self) -> Self;
-    fn bg_yellow_900(self) -> Self;
-    fn bg_yellow_950(self) -> Self;
-    // Green
-    fn bg_green_50(self) -> Self;
-    fn bg_green_100(self) -> Self;
-    fn bg_green_200(self) -> Self;
-    fn bg_green_300(self) -> Self;
-    fn bg_green_400(self) -> Self;
-    fn bg_green_500(self) -> Self;
-    fn bg_green_600(self) -> Self;
-    fn bg_green_700(self) -> Self;
-    fn bg_green_800(self) -> Self;
-    fn bg_green_900(self) -> Self;
-    fn bg_green_950(self) -> Self;
-    // Blue
-    fn bg_blue_50(self) -> Self;
-    fn bg_blue_100(self) -> Self;
-    fn bg_blue_200(self) -> Self;
-    fn bg_blue_300(self) -> Self;
-    fn bg_blue_400(self) -> Self;
-    fn bg_blue_500(self) -> Self;
-    fn bg_blue_600(self) -> Self;
-    fn bg_blue_700(self) -> Self;
-    fn bg_blue_800(self) -> Self;
-    fn bg_blue_900(self) -> Self;
-    fn bg_blue_950(self) -> Self;
-    // Indigo
-    fn bg_indigo_50(self) -> Self;
-    fn bg_indigo_100(self) -> Self;
-    fn bg_indigo_200(self) -> Self;
-    fn bg_indigo_300(self) -> Self;
-    fn bg_indigo_400(self) -> Self;
-    fn bg_indigo_500(self) -> Self;
-    fn bg_indigo_600(self) -> Self;
-    fn bg_indigo_700(self) -> Self;
-    fn bg_indigo_800(self) -> Self;
-    fn bg_indigo_900(self) -> Self;
-    fn bg_indigo_950(self) -> Self;
-    // Purple
-    fn bg_purple_50(self) -> Self;
-    fn bg_purple_100(self) -> Self;
-    fn bg_purple_200(self) -> Self;
-    fn bg_purple_300(self) -> Self;
-    fn bg_purple_400(self) -> Self;
-    fn bg_purple_500(self) -> Self;
-    fn bg_purple_600(self) -> Self;
-    fn bg_purple_700(self) -> Self;
-    fn bg_purple_800(self) -> Self;
-    fn bg_purple_900(self) -> Self;
-    fn bg_purple_950(self) -> Self;
-    // Pink
-    fn bg_pink_50(self) -> Self;
-    fn bg_pink_100(self) -> Self;
-    fn bg_pink_200(self) -> Self;
-    fn bg_pink_300(self) -> Self;
-    fn bg_pink_400(self) -> Self;
-    fn bg_pink_500(self) -> Self;
-    fn bg_pink_600(self) -> Self;
-    fn bg_pink_700(self) -> Self;
-    fn bg_pink_800(self) -> Self;
-    fn bg_pink_900(self) -> Self;
-    fn bg_pink_950(self) -> Self;
-
-    // === Text Color Methods ===
-    fn text(self, color: impl Into<Color>) -> Self;
-    fn text_transparent(self) -> Self;
-    fn text_black(self) -> Self;
-    fn text_white(self) -> Self;
-    // Slate
-    fn text_slate_50(self) -> Self;
-    fn text_slate_100(self) -> Self;
-    fn text_slate_200(self) -> Self;
-    fn text_slate_300(self) -> Self;
-    fn text_slate_400(self) -> Self;
-    fn text_slate_500(self) -> Self;
-    fn text_slate_600(self) -> Self;
-    fn text_slate_700(self) -> Self;
-    fn text_slate_800(self) -> Self;
-    fn text_slate_900(self) -> Self;
-    fn text_slate_950(self) -> Self;
-    // Gray
-    fn text_gray_50(self) -> Self;
-    fn text_gray_100(self) -> Self;
-    fn text_gray_200(self) -> Self;
-    fn text_gray_300(self) -> Self;
-    fn text_gray_400(self) -> Self;
-    fn text_gray_500(self) -> Self;
-    fn text_gray_600(self) -> Self;
-    fn text_gray_700(self) -> Self;
-    fn text_gray_800(self) -> Self;
-    fn text_gray_900(self) -> Self;
-    fn text_gray_950(self) -> Self;
-    // Red
-    fn text_red_50(self) -> Self;
-    fn text_red_100(self) -> Self;
-    fn text_red_200(self) -> Self;
-    fn text_red_300(self) -> Self;
-    fn text_red_400(self) -> Self;
-    fn text_red_500(self) -> Self;
-    fn text_red_600(self) -> Self;
-    fn text_red_700(self) -> Self;
-    fn text_red_800(self) -> Self;
-    fn text_red_900(self) -> Self;
-    fn text_red_950(self) -> Self;
-    // Green
-    fn text_green_50(self) -> Self;
-    fn text_green_100(self) -> Self;
-    fn text_green_200(self) -> Self;
-    fn text_green_300(self) -> Self;
-    fn text_green_400(self) -> Self;
-    fn text_green_500(self) -> Self;
-    fn text_green_600(self) -> Self;
-    fn text_green_700(self) -> Self;
-    fn text_green_800(self) -> Self;
-    fn text_green_900(self) -> Self;
-    fn text_green_950(self) -> Self;
-    // Blue
-    fn text_blue_50(self) -> Self;
-    fn text_blue_100(self) -> Self;
-    fn text_blue_200(self) -> Self;
-    fn text_blue_300(self) -> Self;
-    fn text_blue_400(self) -> Self;
-    fn text_blue_500(self) -> Self;
-    fn text_blue_600(self) -> Self;
-    fn text_blue_700(self) -> Self;
-    fn text_blue_800(self) -> Self;
-    fn text_blue_900(self) -> Self;
-    fn text_blue_950(self) -> Self;
-
-    // === Font Size Methods ===
-    font_size_methods! {
-        text_xs => font_size::TEXT_XS,
-        text_sm => font_size::TEXT_SM,
-        text_base => font_size::TEXT_BASE,
-        text_lg => font_size::TEXT_LG,
-        text_xl => font_size::TEXT_XL,
-        text_2xl => font_size::TEXT_2XL,
-        text_3xl => font_size::TEXT_3XL,
-        text_4xl => font_size::TEXT_4XL,
-        text_5xl => font_size::TEXT_5XL,
-        text_6xl => font_size::TEXT_6XL,
-        text_7xl => font_size::TEXT_7XL,
-        text_8xl => font_size::TEXT_8XL,
-        text_9xl => font_size::TEXT_9XL,
-    }
-
-    // === Font Weight Methods ===
-    font_weight_methods! {
-        font_thin => font_weight::THIN,
-        font_extralight => font_weight::EXTRALIGHT,
-        font_light => font_weight::LIGHT,
-        font_normal => font_weight::NORMAL,
-        font_medium => font_weight::MEDIUM,
-        font_semibold => font_weight::SEMIBOLD,
-        font_bold => font_weight::BOLD,
-        font_extrabold => font_weight::EXTRABOLD,
-        font_black => font_weight::BLACK,
-    }
-
-    // === Line Height Methods ===
-    line_height_methods! {
-        leading_none => line_height::LEADING_NONE,
-        leading_tight => line_height::LEADING_TIGHT,
-        leading_snug => line_height::LEADING_SNUG,
-        leading_normal => line_height::LEADING_NORMAL,
-        leading_relaxed => line_height::LEADING_RELAXED,
-        leading_loose => line_height::LEADING_LOOSE,
-    }
-
-    // === Display Methods ===
-    fn flex(self) -> Self;
-    fn block(self) -> Self;
-    fn grid(self) -> Self;
-    fn hidden(self) -> Self;
-
-    // === Flex Direction Methods ===
-    fn flex_row(self) -> Self;
-    fn flex_col(self) -> Self;
-    fn flex_row_reverse(self) -> Self;
-    fn flex_col_reverse(self) -> Self;
-
-    // === Flex Wrap Methods ===
-    fn wrap(self) -> Self;
-    fn nowrap(self) -> Self;
-    fn wrap_reverse(self) -> Self;
-
-    // === Cursor Methods ===
-    fn cursor_pointer(self) -> Self;
-    fn cursor_default(self) -> Self;
-    fn cursor_text(self) -> Self;
-    fn cursor_move(self) -> Self;
-    fn cursor_grab(self) -> Self;
-    fn cursor_grabbing(self) -> Self;
-
-    // === Position Methods ===
-    fn absolute(self) -> Self;
-    fn relative(self) -> Self;
-    fn fixed(self) -> Self;
-    fn inset_0(self) -> Self;
-    /// left-0
-    fn left_0(self) -> Self;
-    /// left-1/2 (50%)
-    fn left_1_2(self) -> Self;
-    /// left-full (100%)
-    fn left_full(self) -> Self;
-    /// top-0
-    fn top_0(self) -> Self;
-    /// top-1/2 (50%)
-    fn top_1_2(self) -> Self;
-    /// top-full (100%)
-    fn top_full(self) -> Self;
-    /// right-0
-    fn right_0(self) -> Self;
-    /// right-1/2 (50%)
-    fn right_1_2(self) -> Self;
-    /// right-full (100%)
-    fn right_full(self) -> Self;
-    /// bottom-0
-    fn bottom_0(self) -> Self;
-    /// bottom-1/2 (50%)
-    fn bottom_1_2(self) -> Self;
-    /// bottom-full (100%)
-    fn bottom_full(self) -> Self;
-
-    // === Justify Content Methods ===
-    fn justify_start(self) -> Self;
-    fn justify_center(self) -> Self;
-    fn justify_end(self) -> Self;
-    fn justify_between(self) -> Self;
-    fn justify_around(self) -> Self;
-    fn justify_evenly(self) -> Self;
-
-    // === Align Items Methods ===
-    fn items_start(self) -> Self;
-    fn items_center(self) -> Self;
-    fn items_end(self) -> Self;
-    fn items_stretch(self) -> Self;
-    fn items_baseline(self) -> Self;
-
-    // === Align Self Methods ===
-    /// align-self: auto (inherit from parent's align-items)
-    fn self_auto(self) -> Self;
-    /// align-self: flex-start
-    fn self_start(self) -> Self;
-    /// align-self: center
-    fn self_center(self) -> Self;
-    /// align-self: flex-end
-    fn self_end(self) -> Self;
-    /// align-self: stretch
-    fn self_stretch(self) -> Self;
-    /// align-self: baseline
-    fn self_baseline(self) -> Self;
-
-    // === Translate Methods ===
-    /// translate-x-1/2 (50%)
-    fn translate_x_1_2(self) -> Self;
-    /// -translate-x-1/2 (-50%)
-    fn translate_x_neg_1_2(self) -> Self;
-    /// translate-y-1/2 (50%)
-    fn translate_y_1_2(self) -> Self;
-    /// -translate-y-1/2 (-50%)
-    fn translate_y_neg_1_2(self) -> Self;
-    /// translate-x-full (100%)
-    fn translate_x_full(self) -> Self;
-    /// -translate-x-full (-100%)
-    fn translate_x_neg_full(self) -> Self;
-    /// translate-y-full (100%)
-    fn translate_y_full(self) -> Self;
-    /// -translate-y-full (-100%)
-    fn translate_y_neg_full(self) -> Self;
-
-    // === Border Color Methods ===
-    fn border_transparent(self) -> Self;
-    fn border_black(self) -> Self;
-    fn border_white(self) -> Self;
-    fn border_gray_200(self) -> Self;
-    fn border_gray_300(self) -> Self;
-    fn border_gray_400(self) -> Self;
-    fn border_gray_500(self) -> Self;
-    fn border_gray_600(self) -> Self;
-    fn border_red_500(self) -> Self;
-    fn border_blue_500(self) -> Self;
-    fn border_green_500(self) -> Self;
-
-    // === Opacity Methods ===
-    /// opacity-0 (0%)
-    fn opacity_0(self) -> Self;
-    /// opacity-5 (5%)
-    fn opacity_5(self) -> Self;
-    /// opacity-10 (10%)
-    fn opacity_10(self) -> Self;
-    /// opacity-15 (15%)
-    fn opacity_15(self) -> Self;
-    /// opacity-20 (20%)
-    fn opacity_20(self) -> Self;
-    /// opacity-25 (25%)
-    fn opacity_25(self) -> Self;
-    /// opacity-30 (30%)
-    fn opacity_30(self) -> Self;
-    /// opacity-35 (35%)
-    fn opacity_35(self) -> Self;
-    /// opacity-40 (40%)
-    fn opacity_40(self) -> Self;
-    /// opacity-45 (45%)
-    fn opacity_45(self) -> Self;
-    /// opacity-50 (50%)
-    fn opacity_50(self) -> Self;
-    /// opacity-55 (55%)
-    fn opacity_55(self) -> Self;
-    /// opacity-60 (60%)
-    fn opacity_60(self) -> Self;
-    /// opacity-65 (65%)
-    fn opacity_65(self) -> Self;
-    /// opacity-70 (70%)
-    fn opacity_70(self) -> Self;
-    /// opacity-75 (75%)
-    fn opacity_75(self) -> Self;
-    /// opacity-80 (80%)
-    fn opacity_80(self) -> Self;
-    /// opacity-85 (85%)
-    fn opacity_85(self) -> Self;
-    /// opacity-90 (90%)
-    fn opacity_90(self) -> Self;
-    /// opacity-95 (95%)
-    fn opacity_95(self) -> Self;
-    /// opacity-100 (100%)
-    fn opacity_100(self) -> Self;
-
-    // === Pointer Events Methods ===
-    /// pointer-events-none
-    fn pointer_events_none(self) -> Self;
-    /// pointer-events-auto
-    fn pointer_events_auto(self) -> Self;
-
-    // === Overflow Methods ===
-    /// overflow-hidden
-    fn overflow_hidden(self) -> Self;
-    /// overflow-visible
-    fn overflow_visible(self) -> Self;
-    /// overflow-scroll
-    fn overflow_scroll(self) -> Self;
-    /// overflow-clip
-    fn overflow_clip(self) -> Self;
-    /// overflow-auto
-    fn overflow_auto(self) -> Self;
-    /// overflow-x-hidden
-    fn overflow_x_hidden(self) -> Self;
-    /// overflow-x-visible
-    fn overflow_x_visible(self) -> Self;
-    /// overflow-x-scroll
-    fn overflow_x_scroll(self) -> Self;
-    /// overflow-x-clip
-    fn overflow_x_clip(self) -> Self;
-    /// overflow-x-auto
-    fn overflow_x_auto(self) -> Self;
-    /// overflow-y-hidden
-    fn overflow_y_hidden(self) -> Self;
-    /// overflow-y-visible
-    fn overflow_y_visible(self) -> Self;
-    /// overflow-y-scroll
-    fn overflow_y_scroll(self) -> Self;
-    /// overflow-y-clip
-    fn overflow_y_clip(self) -> Self;
-    /// overflow-y-auto
-    fn overflow_y_auto(self) -> Self;
-
-    // === Outline Methods ===
-    /// outline-none (removes outline)
-    fn outline_none(self) -> Self;
+/// Every generated utility table in the enabled traits
+const UTILITY_TABLES: &[UtilityTable] = &[
+    CORE_UTILITIES,
+    #[cfg(feature = "spacing")]
+    SPACING_UTILITIES,
+    #[cfg(feature = "color")]
+    COLOR_UTILITIES,
+    #[cfg(feature = "color")]
+    ACTIVE_UTILITIES,
+    #[cfg(feature = "typography")]
+    TYPOGRAPHY_UTILITIES,
+    #[cfg(feature = "layout")]
+    LAYOUT_UTILITIES,
+];
+
+/// Class names and styles of the utility methods in the enabled traits
+///
+/// Yields `("w-4", style)` pairs in trait order, where `style` does what the
+/// matching method (`w_4()`) does. Methods that take arguments, like
+/// `p(Spacing)`, aren't included.
+///
+/// These seed the [`registry`], which also holds custom utilities.
+pub fn utilities() -> impl Iterator<Item = (&'static str, fn(Style) -> Style)> {
+    UTILITY_TABLES
+        .iter()
+        .flat_map(|table| table.iter().copied())
 }
 
-impl TailwindExt for Style {
-    // === Width Implementations ===
-    impl_width_methods! {
-        w_0 => 0.0,
-        w_px => 1.0,
-        w_0p5 => spacing::SPACING_0_5,
-        w_1 => spacing::SPACING_1,
-        w_1p5 => spacing::SPACING_1_5,
-        w_2 => spacing::SPACING_2,
-        w_2p5 => spacing::SPACING_2_5,
-        w_3 => spacing::SPACING_3,
-        w_3p5 => spacing::SPACING_3_5,
-        w_4 => spacing::SPACING_4,
-        w_5 => spacing::SPACING_5,
-        w_6 => spacing::SPACING_6,
-        w_7 => spacing::SPACING_7,
-        w_8 => spacing::SPACING_8,
-        w_9 => spacing::SPACING_9,
-        w_10 => spacing::SPACING_10,
-        w_11 => spacing::SPACING_11,
-        w_12 => spacing::SPACING_12,
-        w_14 => spacing::SPACING_14,
-        w_16 => spacing::SPACING_16,
-        w_20 => spacing::SPACING_20,
-        w_24 => spacing::SPACING_24,
-        w_28 => spacing::SPACING_28,
-        w_32 => spacing::SPACING_32,
-        w_36 => spacing::SPACING_36,
-        w_40 => spacing::SPACING_40,
-        w_44 => spacing::SPACING_44,
-        w_48 => spacing::SPACING_48,
-        w_52 => spacing::SPACING_52,
-        w_56 => spacing::SPACING_56,
-        w_60 => spacing::SPACING_60,
-        w_64 => spacing::SPACING_64,
-        w_72 => spacing::SPACING_72,
-        w_80 => spacing::SPACING_80,
-        w_96 => spacing::SPACING_96,
-    }
-
-    // Named width sizes
-    fn w_xs(self) -> Self {
-        self.width(spacing::SIZE_XS)
-    }
-    fn w_sm(self) -> Self {
-        self.width(spacing::SIZE_SM)
-    }
-    fn w_md(self) -> Self {
-        self.width(spacing::SIZE_MD)
-    }
-    fn w_lg(self) -> Self {
-        self.width(spacing::SIZE_LG)
-    }
-    fn w_xl(self) -> Self {
-        self.width(spacing::SIZE_XL)
-    }
-    fn w_2xl(self) -> Self {
-        self.width(spacing::SIZE_2XL)
-    }
-    fn w_3xl(self) -> Self {
-        self.width(spacing::SIZE_3XL)
-    }
-    fn w_4xl(self) -> Self {
-        self.width(spacing::SIZE_4XL)
-    }
-    fn w_5xl(self) -> Self {
-        self.width(spacing::SIZE_5XL)
-    }
-    fn w_6xl(self) -> Self {
-        self.width(spacing::SIZE_6XL)
-    }
-    fn w_7xl(self) -> Self {
-        self.width(spacing::SIZE_7XL)
-    }
-
-    // Percentage widths
-    fn w_full(self) -> Self {
-        self.width(Pct(100.0))
-    }
-    fn w_auto(self) -> Self {
-        self.width(PxPctAuto::Auto)
-    }
-    // Fractional widths (GPUI-style)
-    fn w_1_2(self) -> Self {
-        self.width(Pct(50.0))
-    }
-    fn w_1_3(self) -> Self {
-        self.width(Pct(33.333333))
-    }
-    fn w_2_3(self) -> Self {
-        self.width(Pct(66.666667))
-    }
-    fn w_1_4(self) -> Self {
-        self.width(Pct(25.0))
-    }
-    fn w_3_4(self) -> Self {
-        self.width(Pct(75.0))
-    }
-    fn w_1_5(self) -> Self {
-        self.width(Pct(20.0))
-    }
-    fn w_2_5(self) -> Self {
-        self.width(Pct(40.0))
-    }
-    fn w_3_5(self) -> Self {
-        self.width(Pct(60.0))
-    }
-    fn w_4_5(self) -> Self {
-        self.width(Pct(80.0))
-    }
-    fn w_1_6(self) -> Self {
-        self.width(Pct(16.666667))
-    }
-    fn w_5_6(self) -> Self {
-        self.width(Pct(83.333333))
-    }
-    fn w_1_12(self) -> Self {
-        self.width(Pct(8.333333))
-    }
-
-    // === Height Implementations ===
-    impl_height_methods! {
-        h_0 => 0.0,
-        h_px => 1.0,
-        h_0p5 => spacing::SPACING_0_5,
-        h_1 => spacing::SPACING_1,
-        h_1p5 => spacing::SPACING_1_5,
-        h_2 => spacing::SPACING_2,
-        h_2p5 => spacing::SPACING_2_5,
-        h_3 => spacing::SPACING_3,
-        h_3p5 => spacing::SPACING_3_5,
-        h_4 => spacing::SPACING_4,
-        h_5 => spacing::SPACING_5,
-        h_6 => spacing::SPACING_6,
-        h_7 => spacing::SPACING_7,
-        h_8 => spacing::SPACING_8,
-        h_9 => spacing::SPACING_9,
-        h_10 => spacing::SPACING_10,
-        h_11 => spacing::SPACING_11,
-        h_12 => spacing::SPACING_12,
-        h_14 => spacing::SPACING_14,
-        h_16 => spacing::SPACING_16,
-        h_20 => spacing::SPACING_20,
-        h_24 => spacing::SPACING_24,
-        h_28 => spacing::SPACING_28,
-        h_32 => spacing::SPACING_32,
-        h_36 => spacing::SPACING_36,
-        h_40 => spacing::SPACING_40,
-        h_44 => spacing::SPACING_44,
-        h_48 => spacing::SPACING_48,
-        h_52 => spacing::SPACING_52,
-        h_56 => spacing::SPACING_56,
-        h_60 => spacing::SPACING_60,
-        h_64 => spacing::SPACING_64,
-        h_72 => spacing::SPACING_72,
-        h_80 => spacing::SPACING_80,
-        h_96 => spacing::SPACING_96,
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Named height sizes
-    fn h_xs(self) -> Self {
-        self.height(spacing::SIZE_XS)
-    }
-    fn h_sm(self) -> Self {
-        self.height(spacing::SIZE_SM)
-    }
-    fn h_md(self) -> Self {
-        self.height(spacing::SIZE_MD)
-    }
-    fn h_lg(self) -> Self {
-        self.height(spacing::SIZE_LG)
-    }
-    fn h_xl(self) -> Self {
-        self.height(spacing::SIZE_XL)
-    }
-    fn h_2xl(self) -> Self {
-        self.height(spacing::SIZE_2XL)
-    }
-    fn h_3xl(self) -> Self {
-        self.height(spacing::SIZE_3XL)
-    }
-    fn h_4xl(self) -> Self {
-        self.height(spacing::SIZE_4XL)
-    }
-    fn h_5xl(self) -> Self {
-        self.height(spacing::SIZE_5XL)
-    }
-    fn h_6xl(self) -> Self {
-        self.height(spacing::SIZE_6XL)
-    }
-    fn h_7xl(self) -> Self {
-        self.height(spacing::SIZE_7XL)
-    }
-
-    // Percentage heights
-    fn h_full(self) -> Self {
-        self.height(Pct(100.0))
-    }
-    fn h_auto(self) -> Self {
-        self.height(PxPctAuto::Auto)
-    }
-    // Fractional heights (GPUI-style)
-    fn h_1_2(self) -> Self {
-        self.height(Pct(50.0))
-    }
-    fn h_1_3(self) -> Self {
-        self.height(Pct(33.333333))
-    }
-    fn h_2_3(self) -> Self {
-        self.height(Pct(66.666667))
-    }
-    fn h_1_4(self) -> Self {
-        self.height(Pct(25.0))
-    }
-    fn h_3_4(self) -> Self {
-        self.height(Pct(75.0))
-    }
-    fn h_1_5(self) -> Self {
-        self.height(Pct(20.0))
-    }
-    fn h_2_5(self) -> Self {
-        self.height(Pct(40.0))
-    }
-    fn h_3_5(self) -> Self {
-        self.height(Pct(60.0))
-    }
-    fn h_4_5(self) -> Self {
-        self.height(Pct(80.0))
-    }
-    fn h_1_6(self) -> Self {
-        self.height(Pct(16.666667))
-    }
-    fn h_5_6(self) -> Self {
-        self.height(Pct(83.333333))
-    }
-    fn h_1_12(self) -> Self {
-        self.height(Pct(8.333333))
-    }
-
-    // === Size Implementations (width + height) ===
-    impl_size_methods! {
-        size_0 => 0.0,
-        size_px => 1.0,
-        size_0p5 => spacing::SPACING_0_5,
-        size_1 => spacing::SPACING_1,
-        size_1p5 => spacing::SPACING_1_5,
-        size_2 => spacing::SPACING_2,
-        size_2p5 => spacing::SPACING_2_5,
-        size_3 => spacing::SPACING_3,
-        size_3p5 => spacing::SPACING_3_5,
-        size_4 => spacing::SPACING_4,
-        size_5 => spacing::SPACING_5,
-        size_6 => spacing::SPACING_6,
-        size_7 => spacing::SPACING_7,
-        size_8 => spacing::SPACING_8,
-        size_9 => spacing::SPACING_9,
-        size_10 => spacing::SPACING_10,
-        size_11 => spacing::SPACING_11,
-        size_12 => spacing::SPACING_12,
-        size_14 => spacing::SPACING_14,
-        size_16 => spacing::SPACING_16,
-        size_20 => spacing::SPACING_20,
-        size_24 => spacing::SPACING_24,
-        size_28 => spacing::SPACING_28,
-        size_32 => spacing::SPACING_32,
-        size_36 => spacing::SPACING_36,
-        size_40 => spacing::SPACING_40,
-        size_44 => spacing::SPACING_44,
-        size_48 => spacing::SPACING_48,
-        size_52 => spacing::SPACING_52,
-        size_56 => spacing::SPACING_56,
-        size_60 => spacing::SPACING_60,
-        size_64 => spacing::SPACING_64,
-        size_72 => spacing::SPACING_72,
-        size_80 => spacing::SPACING_80,
-        size_96 => spacing::SPACING_96,
-    }
-
-    // === Min-Width Implementations ===
-    impl_min_width_methods! {
-        min_w_0 => 0.0,
-        min_w_px => 1.0,
-        min_w_1 => spacing::SPACING_1,
-        min_w_2 => spacing::SPACING_2,
-        min_w_4 => spacing::SPACING_4,
-        min_w_8 => spacing::SPACING_8,
-        min_w_16 => spacing::SPACING_16,
-        min_w_32 => spacing::SPACING_32,
-        min_w_64 => spacing::SPACING_64,
-        min_w_96 => spacing::SPACING_96,
-    }
-    fn min_w_full(self) -> Self {
-        self.min_width(Pct(100.0))
-    }
-    fn min_w_xs(self) -> Self {
-        self.min_width(spacing::SIZE_XS)
-    }
-    fn min_w_sm(self) -> Self {
-        self.min_width(spacing::SIZE_SM)
-    }
-    fn min_w_md(self) -> Self {
-        self.min_width(spacing::SIZE_MD)
-    }
-    fn min_w_lg(self) -> Self {
-        self.min_width(spacing::SIZE_LG)
-    }
-    fn min_w_xl(self) -> Self {
-        self.min_width(spacing::SIZE_XL)
-    }
-
-    // === Max-Width Implementations ===
-    impl_max_width_methods! {
-        max_w_0 => 0.0,
-        max_w_px => 1.0,
-        max_w_1 => spacing::SPACING_1,
-        max_w_2 => spacing::SPACING_2,
-        max_w_4 => spacing::SPACING_4,
-        max_w_8 => spacing::SPACING_8,
-        max_w_16 => spacing::SPACING_16,
-        max_w_32 => spacing::SPACING_32,
-        max_w_64 => spacing::SPACING_64,
-        max_w_96 => spacing::SPACING_96,
-    }
-    fn max_w_full(self) -> Self {
-        self.max_width(Pct(100.0))
-    }
-    fn max_w_xs(self) -> Self {
-        self.max_width(spacing::SIZE_XS)
-    }
-    fn max_w_sm(self) -> Self {
-        self.max_width(spacing::SIZE_SM)
-    }
-    fn max_w_md(self) -> Self {
-        self.max_width(spacing::SIZE_MD)
-    }
-    fn max_w_lg(self) -> Self {
-        self.max_width(spacing::SIZE_LG)
-    }
-    fn max_w_xl(self) -> Self {
-        self.max_width(spacing::SIZE_XL)
-    }
-    fn max_w_2xl(self) -> Self {
-        self.max_width(spacing::SIZE_2XL)
-    }
-    fn max_w_3xl(self) -> Self {
-        self.max_width(spacing::SIZE_3XL)
-    }
-    fn max_w_4xl(self) -> Self {
-        self.max_width(spacing::SIZE_4XL)
-    }
-    fn max_w_5xl(self) -> Self {
-        self.max_width(spacing::SIZE_5XL)
-    }
-    fn max_w_6xl(self) -> Self {
-        self.max_width(spacing::SIZE_6XL)
-    }
-    fn max_w_7xl(self) -> Self {
-        self.max_width(spacing::SIZE_7XL)
-    }
-
-    // === Padding Implementations ===
-    impl_padding_methods! {
-        p_0 => 0.0,
-        p_px => 1.0,
-        p_0p5 => spacing::SPACING_0_5,
-        p_1 => spacing::SPACING_1,
-        p_1p5 => spacing::SPACING_1_5,
-        p_2 => spacing::SPACING_2,
-        p_2p5 => spacing::SPACING_2_5,
-        p_3 => spacing::SPACING_3,
-        p_3p5 => spacing::SPACING_3_5,
-        p_4 => spacing::SPACING_4,
-        p_5 => spacing::SPACING_5,
-        p_6 => spacing::SPACING_6,
-        p_7 => spacing::SPACING_7,
-        p_8 => spacing::SPACING_8,
-        p_9 => spacing::SPACING_9,
-        p_10 => spacing::SPACING_10,
-        p_11 => spacing::SPACING_11,
-        p_12 => spacing::SPACING_12,
-        p_14 => spacing::SPACING_14,
-        p_16 => spacing::SPACING_16,
-        p_20 => spacing::SPACING_20,
-        p_24 => spacing::SPACING_24,
-    }
-
-    // Horizontal padding
-    impl_padding_x_methods! {
-        px_0 => 0.0,
-        px_px => 1.0,
-        px_0p5 => spacing::SPACING_0_5,
-        px_1 => spacing::SPACING_1,
-        px_1p5 => spacing::SPACING_1_5,
-        px_2 => spacing::SPACING_2,
-        px_2p5 => spacing::SPACING_2_5,
-        px_3 => spacing::SPACING_3,
-        px_3p5 => spacing::SPACING_3_5,
-        px_4 => spacing::SPACING_4,
-        px_5 => spacing::SPACING_5,
-        px_6 => spacing::SPACING_6,
-        px_7 => spacing::SPACING_7,
-        px_8 => spacing::SPACING_8,
-        px_9 => spacing::SPACING_9,
-        px_10 => spacing::SPACING_10,
-        px_12 => spacing::SPACING_12,
-        px_16 => spacing::SPACING_16,
-        px_20 => spacing::SPACING_20,
-        px_24 => spacing::SPACING_24,
-    }
-
-    // Vertical padding
-    impl_padding_y_methods! {
-        py_0 => 0.0,
-        py_px => 1.0,
-        py_0p5 => spacing::SPACING_0_5,
-        py_1 => spacing::SPACING_1,
-        py_1p5 => spacing::SPACING_1_5,
-        py_2 => spacing::SPACING_2,
-        py_2p5 => spacing::SPACING_2_5,
-        py_3 => spacing::SPACING_3,
-        py_3p5 => spacing::SPACING_3_5,
-        py_4 => spacing::SPACING_4,
-        py_5 => spacing::SPACING_5,
-        py_6 => spacing::SPACING_6,
-        py_7 => spacing::SPACING_7,
-        py_8 => spacing::SPACING_8,
-        py_9 => spacing::SPACING_9,
-        py_10 => spacing::SPACING_10,
-        py_12 => spacing::SPACING_12,
-        py_16 => spacing::SPACING_16,
-        py_20 => spacing::SPACING_20,
-        py_24 => spacing::SPACING_24,
+    #[test]
+    fn test_spacing_values() {
+        assert_eq!(spacing::SPACING_1, 4.0);
+        assert_eq!(spacing::SPACING_2, 8.0);
+        assert_eq!(spacing::SPACING_4, 16.0);
+        assert_eq!(spacing::SPACING_8, 32.0);
+        assert_eq!(spacing::SPACING_16, 64.0);
     }
 
-    // Individual padding sides
-    fn pt_0(self) -> Self {
-        self.padding_top(0.0)
-    }
-    fn pt_1(self) -> Self {
-        self.padding_top(spacing::SPACING_1)
-    }
-    fn pt_2(self) -> Self {
-        self.padding_top(spacing::SPACING_2)
-    }
-    fn pt_3(self) -> Self {
-        self.padding_top(spacing::SPACING_3)
-    }
-    fn pt_4(self) -> Self {
-        self.padding_top(spacing::SPACING_4)
-    }
-    fn pt_5(self) -> Self {
-        self.padding_top(spacing::SPACING_5)
-    }
-    fn pt_6(self) -> Self {
-        self.padding_top(spacing::SPACING_6)
-    }
-    fn pt_8(self) -> Self {
-        self.padding_top(spacing::SPACING_8)
-    }
-
-    fn pb_0(self) -> Self {
-        self.padding_bottom(0.0)
-    }
-    fn pb_1(self) -> Self {
-        self.padding_bottom(spacing::SPACING_1)
-    }
-    fn pb_2(self) -> Self {
-        self.padding_bottom(spacing::SPACING_2)
-    }
-    fn pb_3(self) -> Self {
-        self.padding_bottom(spacing::SPACING_3)
-    }
-    fn pb_4(self) -> Self {
-        self.padding_bottom(spacing::SPACING_4)
-    }
-    fn pb_5(self) -> Self {
-        self.padding_bottom(spacing::SPACING_5)
-    }
-    fn pb_6(self) -> Self {
-        self.padding_bottom(spacing::SPACING_6)
-    }
-    fn pb_8(self) -> Self {
-        self.padding_bottom(spacing::SPACING_8)
-    }
-
-    fn pl_0(self) -> Self {
-        self.padding_left(0.0)
-    }
-    fn pl_1(self) -> Self {
-        self.padding_left(spacing::SPACING_1)
-    }
-    fn pl_2(self) -> Self {
-        self.padding_left(spacing::SPACING_2)
-    }
-    fn pl_3(self) -> Self {
-        self.padding_left(spacing::SPACING_3)
-    }
-    fn pl_4(self) -> Self {
-        self.padding_left(spacing::SPACING_4)
-    }
-    fn pl_5(self) -> Self {
-        self.padding_left(spacing::SPACING_5)
-    }
-    fn pl_6(self) -> Self {
-        self.padding_left(spacing::SPACING_6)
-    }
-    fn pl_8(self) -> Self {
-        self.padding_left(spacing::SPACING_8)
-    }
-
-    fn pr_0(self) -> Self {
-        self.padding_right(0.0)
-    }
-    fn pr_1(self) -> Self {
-        self.padding_right(spacing::SPACING_1)
-    }
-    fn pr_2(self) -> Self {
-        self.padding_right(spacing::SPACING_2)
-    }
-    fn pr_3(self) -> Self {
-        self.padding_right(spacing::SPACING_3)
-    }
-    fn pr_4(self) -> Self {
-        self.padding_right(spacing::SPACING_4)
-    }
-    fn pr_5(self) -> Self {
-        self.padding_right(spacing::SPACING_5)
-    }
-    fn pr_6(self) -> Self {
-        self.padding_right(spacing::SPACING_6)
-    }
-    fn pr_8(self) -> Self {
-        self.padding_right(spacing::SPACING_8)
-    }
+    #[cfg(feature = "spacing")]
+    #[test]
+    fn test_unit_methods() {
+        use floem::style::{ColGap, PaddingLeft, Width};
 
-    // === Margin Implementations ===
-    impl_margin_methods! {
-        m_0 => 0.0,
-        m_px => 1.0,
-        m_0p5 => spacing::SPACING_0_5,
-        m_1 => spacing::SPACING_1,
-        m_1p5 => spacing::SPACING_1_5,
-        m_2 => spacing::SPACING_2,
-        m_2p5 => spacing::SPACING_2_5,
-        m_3 => spacing::SPACING_3,
-        m_3p5 => spacing::SPACING_3_5,
-        m_4 => spacing::SPACING_4,
-        m_5 => spacing::SPACING_5,
-        m_6 => spacing::SPACING_6,
-        m_7 => spacing::SPACING_7,
-        m_8 => spacing::SPACING_8,
-        m_9 => spacing::SPACING_9,
-        m_10 => spacing::SPACING_10,
-        m_11 => spacing::SPACING_11,
-        m_12 => spacing::SPACING_12,
-        m_14 => spacing::SPACING_14,
-        m_16 => spacing::SPACING_16,
-        m_20 => spacing::SPACING_20,
-        m_24 => spacing::SPACING_24,
-    }
+        assert_eq!(spacing::units(13.0), 52.0);
+        let s = Style::new().w_units(13.0).px_units(2.5).gap_units(0.5);
+        let spaced = Style::new().w(Spacing::S4).px_2p5().gap_0p5();
+        assert_eq!(s.get(Width), PxPctAuto::Px(52.0));
+        assert_eq!(s.get(PaddingLeft), spaced.get(PaddingLeft));
+        assert_eq!(s.get(ColGap), spaced.get(ColGap));
 
-    fn m_auto(self) -> Self {
-        self.margin(PxPctAuto::Auto)
+        let compact = density::with_factor(density::COMPACT, || {
+            assert_eq!(spacing::units(8.0), 28.0);
+            Style::new().w_units(8.0).gap_units(8.0)
+        });
+        assert_eq!(compact.get(Width), PxPctAuto::Px(28.0));
+        assert_eq!(compact.get(ColGap), floem::unit::PxPct::Px(28.0));
     }
 
-    // Horizontal margin
-    impl_margin_x_methods! {
-        mx_0 => 0.0,
-        mx_px => 1.0,
-        mx_0p5 => spacing::SPACING_0_5,
-        mx_1 => spacing::SPACING_1,
-        mx_1p5 => spacing::SPACING_1_5,
-        mx_2 => spacing::SPACING_2,
-        mx_2p5 => spacing::SPACING_2_5,
-        mx_3 => spacing::SPACING_3,
-        mx_3p5 => spacing::SPACING_3_5,
-        mx_4 => spacing::SPACING_4,
-        mx_5 => spacing::SPACING_5,
-        mx_6 => spacing::SPACING_6,
-        mx_8 => spacing::SPACING_8,
-        mx_10 => spacing::SPACING_10,
-        mx_12 => spacing::SPACING_12,
-        mx_16 => spacing::SPACING_16,
-        mx_20 => spacing::SPACING_20,
-        mx_24 => spacing::SPACING_24,
-    }
+    #[cfg(feature = "spacing")]
+    #[test]
+    fn test_percentage_methods() {
+        use floem::style::{FlexBasis, Height, Width};
 
-    fn mx_auto(self) -> Self {
-        self.margin_horiz(PxPctAuto::Auto)
+        let s = Style::new().w_pct(37.5).h_pct(62.0).basis_pct(25.0);
+        assert_eq!(s.get(Width), PxPctAuto::Pct(37.5));
+        assert_eq!(s.get(Height), PxPctAuto::Pct(62.0));
+        assert_eq!(s.get(FlexBasis), PxPctAuto::Pct(25.0));
     }
 
-    // Vertical margin
-    impl_margin_y_methods! {
-        my_0 => 0.0,
-        my_px => 1.0,
-        my_0p5 => spacing::SPACING_0_5,
-        my_1 => spacing::SPACING_1,
-        my_1p5 => spacing::SPACING_1_5,
-        my_2 => spacing::SPACING_2,
-        my_2p5 => spacing::SPACING_2_5,
-        my_3 => spacing::SPACING_3,
-        my_3p5 => spacing::SPACING_3_5,
-        my_4 => spacing::SPACING_4,
-        my_5 => spacing::SPACING_5,
-        my_6 => spacing::SPACING_6,
-        my_8 => spacing::SPACING_8,
-        my_10 => spacing::SPACING_10,
-        my_12 => spacing::SPACING_12,
-        my_16 => spacing::SPACING_16,
-        my_20 => spacing::SPACING_20,
-        my_24 => spacing::SPACING_24,
+    #[test]
+    fn test_size_values() {
+        assert_eq!(spacing::SIZE_SM, 384.0);
+        assert_eq!(spacing::SIZE_MD, 448.0);
+        assert_eq!(spacing::SIZE_LG, 512.0);
     }
 
-    fn my_auto(self) -> Self {
-        self.margin_vert(PxPctAuto::Auto)
+    #[test]
+    fn test_breakpoints() {
+        use screen::Breakpoint;
+        assert_eq!(Breakpoint::at(500.0), None);
+        assert_eq!(Breakpoint::at(768.0), Some(Breakpoint::Md));
+        assert_eq!(Breakpoint::at(4000.0), Some(Breakpoint::Xl2));
+        assert_eq!(
+            Breakpoint::from_name("2xl").map(Breakpoint::min_width),
+            Some(screen::XL2)
+        );
     }
 
-    // Individual margin sides
-    fn mt_0(self) -> Self {
-        self.margin_top(0.0)
-    }
-    fn mt_1(self) -> Self {
-        self.margin_top(spacing::SPACING_1)
-    }
-    fn mt_2(self) -> Self {
-        self.margin_top(spacing::SPACING_2)
-    }
-    fn mt_3(self) -> Self {
-        self.margin_top(spacing::SPACING_3)
-    }
-    fn mt_4(self) -> Self {
-        self.margin_top(spacing::SPACING_4)
-    }
-    fn mt_5(self) -> Self {
-        self.margin_top(spacing::SPACING_5)
-    }
-    fn mt_6(self) -> Self {
-        self.margin_top(spacing::SPACING_6)
-    }
-    fn mt_8(self) -> Self {
-        self.margin_top(spacing::SPACING_8)
-    }
-    fn mt_auto(self) -> Self {
-        self.margin_top(PxPctAuto::Auto)
-    }
-
-    fn mb_0(self) -> Self {
-        self.margin_bottom(0.0)
-    }
-    fn mb_1(self) -> Self {
-        self.margin_bottom(spacing::SPACING_1)
-    }
-    fn mb_2(self) -> Self {
-        self.margin_bottom(spacing::SPACING_2)
-    }
-    fn mb_3(self) -> Self {
-        self.margin_bottom(spacing::SPACING_3)
-    }
-    fn mb_4(self) -> Self {
-        self.margin_bottom(spacing::SPACING_4)
-    }
-    fn mb_5(self) -> Self {
-        self.margin_bottom(spacing::SPACING_5)
-    }
-    fn mb_6(self) -> Self {
-        self.margin_bottom(spacing::SPACING_6)
-    }
-    fn mb_8(self) -> Self {
-        self.margin_bottom(spacing::SPACING_8)
-    }
-    fn mb_auto(self) -> Self {
-        self.margin_bottom(PxPctAuto::Auto)
-    }
-
-    fn ml_0(self) -> Self {
-        self.margin_left(0.0)
-    }
-    fn ml_1(self) -> Self {
-        self.margin_left(spacing::SPACING_1)
-    }
-    fn ml_2(self) -> Self {
-        self.margin_left(spacing::SPACING_2)
-    }
-    fn ml_3(self) -> Self {
-        self.margin_left(spacing::SPACING_3)
-    }
-    fn ml_4(self) -> Self {
-        self.margin_left(spacing::SPACING_4)
-    }
-    fn ml_5(self) -> Self {
-        self.margin_left(spacing::SPACING_5)
-    }
-    fn ml_6(self) -> Self {
-        self.margin_left(spacing::SPACING_6)
-    }
-    fn ml_8(self) -> Self {
-        self.margin_left(spacing::SPACING_8)
-    }
-    fn ml_auto(self) -> Self {
-        self.margin_left(PxPctAuto::Auto)
-    }
-
-    fn mr_0(self) -> Self {
-        self.margin_right(0.0)
-    }
-    fn mr_1(self) -> Self {
-        self.margin_right(spacing::SPACING_1)
-    }
-    fn mr_2(self) -> Self {
-        self.margin_right(spacing::SPACING_2)
-    }
-    fn mr_3(self) -> Self {
-        self.margin_right(spacing::SPACING_3)
-    }
-    fn mr_4(self) -> Self {
-        self.margin_right(spacing::SPACING_4)
-    }
-    fn mr_5(self) -> Self {
-        self.margin_right(spacing::SPACING_5)
-    }
-    fn mr_6(self) -> Self {
-        self.margin_right(spacing::SPACING_6)
-    }
-    fn mr_8(self) -> Self {
-        self.margin_right(spacing::SPACING_8)
-    }
-    fn mr_auto(self) -> Self {
-        self.margin_right(PxPctAuto::Auto)
-    }
-
-    // === Gap Implementations ===
-    impl_gap_methods! {
-        gap_0 => 0.0,
-        gap_px => 1.0,
-        gap_0p5 => spacing::SPACING_0_5,
-        gap_1 => spacing::SPACING_1,
-        gap_1p5 => spacing::SPACING_1_5,
-        gap_2 => spacing::SPACING_2,
-        gap_2p5 => spacing::SPACING_2_5,
-        gap_3 => spacing::SPACING_3,
-        gap_3p5 => spacing::SPACING_3_5,
-        gap_4 => spacing::SPACING_4,
-        gap_5 => spacing::SPACING_5,
-        gap_6 => spacing::SPACING_6,
-        gap_7 => spacing::SPACING_7,
-        gap_8 => spacing::SPACING_8,
-        gap_9 => spacing::SPACING_9,
-        gap_10 => spacing::SPACING_10,
-        gap_11 => spacing::SPACING_11,
-        gap_12 => spacing::SPACING_12,
-        gap_14 => spacing::SPACING_14,
-        gap_16 => spacing::SPACING_16,
-        gap_20 => spacing::SPACING_20,
-        gap_24 => spacing::SPACING_24,
-    }
-
-    // === Border Radius Implementations ===
-    impl_rounded_methods! {
-        rounded_none => radius::ROUNDED_NONE,
-        rounded_sm => radius::ROUNDED_SM,
-        rounded => radius::ROUNDED,
-        rounded_md => radius::ROUNDED_MD,
-        rounded_lg => radius::ROUNDED_LG,
-        rounded_xl => radius::ROUNDED_XL,
-        rounded_2xl => radius::ROUNDED_2XL,
-        rounded_3xl => radius::ROUNDED_3XL,
-        rounded_full => radius::ROUNDED_FULL,
-    }
-
-    // === Border Width Implementations ===
-    fn border_0(self) -> Self {
-        self.border(0.0)
-    }
-    fn border_1(self) -> Self {
-        self.border(1.0)
-    }
-    fn border_2(self) -> Self {
-        self.border(2.0)
-    }
-    fn border_4(self) -> Self {
-        self.border(4.0)
-    }
-    fn border_8(self) -> Self {
-        self.border(8.0)
-    }
-
-    // === Shadow Implementations ===
-    fn shadow_sm(self) -> Self {
-        self.apply_box_shadows(vec![shadow::shadow_sm()])
-    }
-    fn shadow(self) -> Self {
-        self.apply_box_shadows(vec![shadow::shadow_default()])
-    }
-    fn shadow_md(self) -> Self {
-        self.apply_box_shadows(vec![shadow::shadow_md()])
-    }
-    fn shadow_lg(self) -> Self {
-        self.apply_box_shadows(vec![shadow::shadow_lg()])
-    }
-    fn shadow_xl(self) -> Self {
-        self.apply_box_shadows(vec![shadow::shadow_xl()])
-    }
-    fn shadow_2xl(self) -> Self {
-        self.apply_box_shadows(vec![shadow::shadow_2xl()])
-    }
-    fn shadow_none(self) -> Self {
-        self.apply_box_shadows(vec![])
-    }
-
-    // === Background Color Implementations ===
-    fn bg(self, color: impl Into<Color>) -> Self {
-        self.background(color.into())
-    }
-    fn bg_transparent(self) -> Self {
-        self.background(colors::TRANSPARENT)
-    }
-    fn bg_black(self) -> Self {
-        self.background(colors::BLACK)
-    }
-    fn bg_white(self) -> Self {
-        self.background(colors::WHITE)
-    }
-    // Slate
-    fn bg_slate_50(self) -> Self {
-        self.background(colors::slate::C50)
-    }
-    fn bg_slate_100(self) -> Self {
-        self.background(colors::slate::C100)
-    }
-    fn bg_slate_200(self) -> Self {
-        self.background(colors::slate::C200)
-    }
-    fn bg_slate_300(self) -> Self {
-        self.background(colors::slate::C300)
-    }
-    fn bg_slate_400(self) -> Self {
-        self.background(colors::slate::C400)
-    }
-    fn bg_slate_500(self) -> Self {
-        self.background(colors::slate::C500)
-    }
-    fn bg_slate_600(self) -> Self {
-        self.background(colors::slate::C600)
-    }
-    fn bg_slate_700(self) -> Self {
-        self.background(colors::slate::C700)
-    }
-    fn bg_slate_800(self) -> Self {
-        self.background(colors::slate::C800)
-    }
-    fn bg_slate_900(self) -> Self {
-        self.background(colors::slate::C900)
-    }
-    fn bg_slate_950(self) -> Self {
-        self.background(colors::slate::C950)
-    }
-    // Gray
-    fn bg_gray_50(self) -> Self {
-        self.background(colors::gray::C50)
-    }
-    fn bg_gray_100(self) -> Self {
-        self.background(colors::gray::C100)
-    }
-    fn bg_gray_200(self) -> Self {
-        self.background(colors::gray::C200)
-    }
-    fn bg_gray_300(self) -> Self {
-        self.background(colors::gray::C300)
-    }
-    fn bg_gray_400(self) -> Self {
-        self.background(colors::gray::C400)
-    }
-    fn bg_gray_500(self) -> Self {
-        self.background(colors::gray::C500)
-    }
-    fn bg_gray_600(self) -> Self {
-        self.background(colors::gray::C600)
-    }
-    fn bg_gray_700(self) -> Self {
-        self.background(colors::gray::C700)
-    }
-    fn bg_gray_800(self) -> Self {
-        self.background(colors::gray::C800)
-    }
-    fn bg_gray_900(self) -> Self {
-        self.background(colors::gray::C900)
-    }
-    fn bg_gray_950(self) -> Self {
-        self.background(colors::gray::C950)
-    }
-    // Zinc
-    fn bg_zinc_50(self) -> Self {
-        self.background(colors::zinc::C50)
-    }
-    fn bg_zinc_100(self) -> Self {
-        self.background(colors::zinc::C100)
-    }
-    fn bg_zinc_200(self) -> Self {
-        self.background(colors::zinc::C200)
-    }
-    fn bg_zinc_300(self) -> Self {
-        self.background(colors::zinc::C300)
-    }
-    fn bg_zinc_400(self) -> Self {
-        self.background(colors::zinc::C400)
-    }
-    fn bg_zinc_500(self) -> Self {
-        self.background(colors::zinc::C500)
-    }
-    fn bg_zinc_600(self) -> Self {
-        self.background(colors::zinc::C600)
-    }
-    fn bg_zinc_700(self) -> Self {
-        self.background(colors::zinc::C700)
-    }
-    fn bg_zinc_800(self) -> Self {
-        self.background(colors::zinc::C800)
-    }
-    fn bg_zinc_900(self) -> Self {
-        self.background(colors::zinc::C900)
-    }
-    fn bg_zinc_950(self) -> Self {
-        self.background(colors::zinc::C950)
-    }
-    // Red
-    fn bg_red_50(self) -> Self {
-        self.background(colors::red::C50)
-    }
-    fn bg_red_100(self) -> Self {
-        self.background(colors::red::C100)
-    }
-    fn bg_red_200(self) -> Self {
-        self.background(colors::red::C200)
-    }
-    fn bg_red_300(self) -> Self {
-        self.background(colors::red::C300)
-    }
-    fn bg_red_400(self) -> Self {
-        self.background(colors::red::C400)
-    }
-    fn bg_red_500(self) -> Self {
-        self.background(colors::red::C500)
-    }
-    fn bg_red_600(self) -> Self {
-        self.background(colors::red::C600)
-    }
-    fn bg_red_700(self) -> Self {
-        self.background(colors::red::C700)
-    }
-    fn bg_red_800(self) -> Self {
-        self.background(colors::red::C800)
-    }
-    fn bg_red_900(self) -> Self {
-        self.background(colors::red::C900)
-    }
-    fn bg_red_950(self) -> Self {
-        self.background(colors::red::C950)
-    }
-    // Orange
-    fn bg_orange_50(self) -> Self {
-        self.background(colors::orange::C50)
-    }
-    fn bg_orange_100(self) -> Self {
-        self.background(colors::orange::C100)
-    }
-    fn bg_orange_200(self) -> Self {
-        self.background(colors::orange::C200)
-    }
-    fn bg_orange_300(self) -> Self {
-        self.background(colors::orange::C300)
-    }
-    fn bg_orange_400(self) -> Self {
-        self.background(colors::orange::C400)
-    }
-    fn bg_orange_500(self) -> Self {
-        self.background(colors::orange::C500)
-    }
-    fn bg_orange_600(self) -> Self {
-        self.background(colors::orange::C600)
-    }
-    fn bg_orange_700(self) -> Self {
-        self.background(colors::orange::C700)
-    }
-    fn bg_orange_800(self) -> Self {
-        self.background(colors::orange::C800)
-    }
-    fn bg_orange_900(self) -> Self {
-        self.background(colors::orange::C900)
-    }
-    fn bg_orange_950(self) -> Self {
-        self.background(colors::orange::C950)
-    }
-    // Yellow
-    fn bg_yellow_50(self) -> Self {
-        self.background(colors::yellow::C50)
-    }
-    fn bg_yellow_100(self) -> Self {
-        self.background(colors::yellow::C100)
-    }
-    fn bg_yellow_200(self) -> Self {
-        self.background(colors::yellow::C200)
-    }
-    fn bg_yellow_300(self) -> Self {
-        self.background(colors::yellow::C300)
-    }
-    fn bg_yellow_400(self) -> Self {
-        self.background(colors::yellow::C400)
-    }
-    fn bg_yellow_500(self) -> Self {
-        self.background(colors::yellow::C500)
-    }
-    fn bg_yellow_600(self) -> Self {
-        self.background(colors::yellow::C600)
-    }
-    fn bg_yellow_700(self) -> Self {
-        self.background(colors::yellow::C700)
-    }
-    fn bg_yellow_800(self) -> Self {
-        self.background(colors::yellow::C800)
-    }
-    fn bg_yellow_900(self) -> Self {
-        self.background(colors::yellow::C900)
-    }
-    fn bg_yellow_950(self) -> Self {
-        self.background(colors::yellow::C950)
-    }
-    // Green
-    fn bg_green_50(self) -> Self {
-        self.background(colors::green::C50)
-    }
-    fn bg_green_100(self) -> Self {
-        self.background(colors::green::C100)
-    }
-    fn bg_green_200(self) -> Self {
-        self.background(colors::green::C200)
-    }
-    fn bg_green_300(self) -> Self {
-        self.background(colors::green::C300)
-    }
-    fn bg_green_400(self) -> Self {
-        self.background(colors::green::C400)
-    }
-    fn bg_green_500(self) -> Self {
-        self.background(colors::green::C500)
-    }
-    fn bg_green_600(self) -> Self {
-        self.background(colors::green::C600)
-    }
-    fn bg_green_700(self) -> Self {
-        self.background(colors::green::C700)
-    }
-    fn bg_green_800(self) -> Self {
-        self.background(colors::green::C800)
-    }
-    fn bg_green_900(self) -> Self {
-        self.background(colors::green::C900)
-    }
-    fn bg_green_950(self) -> Self {
-        self.background(colors::green::C950)
-    }
-    // Blue
-    fn bg_blue_50(self) -> Self {
-        self.background(colors::blue::C50)
-    }
-    fn bg_blue_100(self) -> Self {
-        self.background(colors::blue::C100)
-    }
-    fn bg_blue_200(self) -> Self {
-        self.background(colors::blue::C200)
-    }
-    fn bg_blue_300(self) -> Self {
-        self.background(colors::blue::C300)
-    }
-    fn bg_blue_400(self) -> Self {
-        self.background(colors::blue::C400)
-    }
-    fn bg_blue_500(self) -> Self {
-        self.background(colors::blue::C500)
-    }
-    fn bg_blue_600(self) -> Self {
-        self.background(colors::blue::C600)
-    }
-    fn bg_blue_700(self) -> Self {
-        self.background(colors::blue::C700)
-    }
-    fn bg_blue_800(self) -> Self {
-        self.background(colors::blue::C800)
-    }
-    fn bg_blue_900(self) -> Self {
-        self.background(colors::blue::C900)
-    }
-    fn bg_blue_950(self) -> Self {
-        self.background(colors::blue::C950)
-    }
-    // Indigo
-    fn bg_indigo_50(self) -> Self {
-        self.background(colors::indigo::C50)
-    }
-    fn bg_indigo_100(self) -> Self {
-        self.background(colors::indigo::C100)
-    }
-    fn bg_indigo_200(self) -> Self {
-        self.background(colors::indigo::C200)
-    }
-    fn bg_indigo_300(self) -> Self {
-        self.background(colors::indigo::C300)
-    }
-    fn bg_indigo_400(self) -> Self {
-        self.background(colors::indigo::C400)
-    }
-    fn bg_indigo_500(self) -> Self {
-        self.background(colors::indigo::C500)
-    }
-    fn bg_indigo_600(self) -> Self {
-        self.background(colors::indigo::C600)
-    }
-    fn bg_indigo_700(self) -> Self {
-        self.background(colors::indigo::C700)
-    }
-    fn bg_indigo_800(self) -> Self {
-        self.background(colors::indigo::C800)
-    }
-    fn bg_indigo_900(self) -> Self {
-        self.background(colors::indigo::C900)
-    }
-    fn bg_indigo_950(self) -> Self {
-        self.background(colors::indigo::C950)
-    }
-    // Purple
-    fn bg_purple_50(self) -> Self {
-        self.background(colors::purple::C50)
-    }
-    fn bg_purple_100(self) -> Self {
-        self.background(colors::purple::C100)
-    }
-    fn bg_purple_200(self) -> Self {
-        self.background(colors::purple::C200)
-    }
-    fn bg_purple_300(self) -> Self {
-        self.background(colors::purple::C300)
-    }
-    fn bg_purple_400(self) -> Self {
-        self.background(colors::purple::C400)
-    }
-    fn bg_purple_500(self) -> Self {
-        self.background(colors::purple::C500)
-    }
-    fn bg_purple_600(self) -> Self {
-        self.background(colors::purple::C600)
-    }
-    fn bg_purple_700(self) -> Self {
-        self.background(colors::purple::C700)
-    }
-    fn bg_purple_800(self) -> Self {
-        self.background(colors::purple::C800)
-    }
-    fn bg_purple_900(self) -> Self {
-        self.background(colors::purple::C900)
-    }
-    fn bg_purple_950(self) -> Self {
-        self.background(colors::purple::C950)
-    }
-    // Pink
-    fn bg_pink_50(self) -> Self {
-        self.background(colors::pink::C50)
-    }
-    fn bg_pink_100(self) -> Self {
-        self.background(colors::pink::C100)
-    }
-    fn bg_pink_200(self) -> Self {
-        self.background(colors::pink::C200)
-    }
-    fn bg_pink_300(self) -> Self {
-        self.background(colors::pink::C300)
-    }
-    fn bg_pink_400(self) -> Self {
-        self.background(colors::pink::C400)
-    }
-    fn bg_pink_500(self) -> Self {
-        self.background(colors::pink::C500)
-    }
-    fn bg_pink_600(self) -> Self {
-        self.background(colors::pink::C600)
-    }
-    fn bg_pink_700(self) -> Self {
-        self.background(colors::pink::C700)
-    }
-    fn bg_pink_800(self) -> Self {
-        self.background(colors::pink::C800)
-    }
-    fn bg_pink_900(self) -> Self {
-        self.background(colors::pink::C900)
-    }
-    fn bg_pink_950(self) -> Self {
-        self.background(colors::pink::C950)
-    }
-
-    // === Text Color Implementations ===
-    fn text(self, color: impl Into<Color>) -> Self {
-        self.color(color.into())
-    }
-    fn text_transparent(self) -> Self {
-        self.color(colors::TRANSPARENT)
-    }
-    fn text_black(self) -> Self {
-        self.color(colors::BLACK)
-    }
-    fn text_white(self) -> Self {
-        self.color(colors::WHITE)
-    }
-    // Slate
-    fn text_slate_50(self) -> Self {
-        self.color(colors::slate::C50)
-    }
-    fn text_slate_100(self) -> Self {
-        self.color(colors::slate::C100)
-    }
-    fn text_slate_200(self) -> Self {
-        self.color(colors::slate::C200)
-    }
-    fn text_slate_300(self) -> Self {
-        self.color(colors::slate::C300)
-    }
-    fn text_slate_400(self) -> Self {
-        self.color(colors::slate::C400)
-    }
-    fn text_slate_500(self) -> Self {
-        self.color(colors::slate::C500)
-    }
-    fn text_slate_600(self) -> Self {
-        self.color(colors::slate::C600)
-    }
-    fn text_slate_700(self) -> Self {
-        self.color(colors::slate::C700)
-    }
-    fn text_slate_800(self) -> Self {
-        self.color(colors::slate::C800)
-    }
-    fn text_slate_900(self) -> Self {
-        self.color(colors::slate::C900)
-    }
-    fn text_slate_950(self) -> Self {
-        self.color(colors::slate::C950)
-    }
-    // Gray
-    fn text_gray_50(self) -> Self {
-        self.color(colors::gray::C50)
-    }
-    fn text_gray_100(self) -> Self {
-        self.color(colors::gray::C100)
-    }
-    fn text_gray_200(self) -> Self {
-        self.color(colors::gray::C200)
-    }
-    fn text_gray_300(self) -> Self {
-        self.color(colors::gray::C300)
-    }
-    fn text_gray_400(self) -> Self {
-        self.color(colors::gray::C400)
-    }
-    fn text_gray_500(self) -> Self {
-        self.color(colors::gray::C500)
-    }
-    fn text_gray_600(self) -> Self {
-        self.color(colors::gray::C600)
-    }
-    fn text_gray_700(self) -> Self {
-        self.color(colors::gray::C700)
-    }
-    fn text_gray_800(self) -> Self {
-        self.color(colors::gray::C800)
-    }
-    fn text_gray_900(self) -> Self {
-        self.color(colors::gray::C900)
-    }
-    fn text_gray_950(self) -> Self {
-        self.color(colors::gray::C950)
-    }
-    // Red
-    fn text_red_50(self) -> Self {
-        self.color(colors::red::C50)
-    }
-    fn text_red_100(self) -> Self {
-        self.color(colors::red::C100)
-    }
-    fn text_red_200(self) -> Self {
-        self.color(colors::red::C200)
-    }
-    fn text_red_300(self) -> Self {
-        self.color(colors::red::C300)
-    }
-    fn text_red_400(self) -> Self {
-        self.color(colors::red::C400)
-    }
-    fn text_red_500(self) -> Self {
-        self.color(colors::red::C500)
-    }
-    fn text_red_600(self) -> Self {
-        self.color(colors::red::C600)
-    }
-    fn text_red_700(self) -> Self {
-        self.color(colors::red::C700)
-    }
-    fn text_red_800(self) -> Self {
-        self.color(colors::red::C800)
-    }
-    fn text_red_900(self) -> Self {
-        self.color(colors::red::C900)
-    }
-    fn text_red_950(self) -> Self {
-        self.color(colors::red::C950)
-    }
-    // Green
-    fn text_green_50(self) -> Self {
-        self.color(colors::green::C50)
-    }
-    fn text_green_100(self) -> Self {
-        self.color(colors::green::C100)
-    }
-    fn text_green_200(self) -> Self {
-        self.color(colors::green::C200)
-    }
-    fn text_green_300(self) -> Self {
-        self.color(colors::green::C300)
-    }
-    fn text_green_400(self) -> Self {
-        self.color(colors::green::C400)
-    }
-    fn text_green_500(self) -> Self {
-        self.color(colors::green::C500)
-    }
-    fn text_green_600(self) -> Self {
-        self.color(colors::green::C600)
-    }
-    fn text_green_700(self) -> Self {
-        self.color(colors::green::C700)
-    }
-    fn text_green_800(self) -> Self {
-        self.color(colors::green::C800)
-    }
-    fn text_green_900(self) -> Self {
-        self.color(colors::green::C900)
-    }
-    fn text_green_950(self) -> Self {
-        self.color(colors::green::C950)
-    }
-    // Blue
-    fn text_blue_50(self) -> Self {
-        self.color(colors::blue::C50)
-    }
-    fn text_blue_100(self) -> Self {
-        self.color(colors::blue::C100)
-    }
-    fn text_blue_200(self) -> Self {
-        self.color(colors::blue::C200)
-    }
-    fn text_blue_300(self) -> Self {
-        self.color(colors::blue::C300)
-    }
-    fn text_blue_400(self) -> Self {
-        self.color(colors::blue::C400)
-    }
-    fn text_blue_500(self) -> Self {
-        self.color(colors::blue::C500)
-    }
-    fn text_blue_600(self) -> Self {
-        self.color(colors::blue::C600)
-    }
-    fn text_blue_700(self) -> Self {
-        self.color(colors::blue::C700)
-    }
-    fn text_blue_800(self) -> Self {
-        self.color(colors::blue::C800)
-    }
-    fn text_blue_900(self) -> Self {
-        self.color(colors::blue::C900)
-    }
-    fn text_blue_950(self) -> Self {
-        self.color(colors::blue::C950)
-    }
-
-    // === Font Size Implementations ===
-    impl_font_size_methods! {
-        text_xs => font_size::TEXT_XS,
-        text_sm => font_size::TEXT_SM,
-        text_base => font_size::TEXT_BASE,
-        text_lg => font_size::TEXT_LG,
-        text_xl => font_size::TEXT_XL,
-        text_2xl => font_size::TEXT_2XL,
-        text_3xl => font_size::TEXT_3XL,
-        text_4xl => font_size::TEXT_4XL,
-        text_5xl => font_size::TEXT_5XL,
-        text_6xl => font_size::TEXT_6XL,
-        text_7xl => font_size::TEXT_7XL,
-        text_8xl => font_size::TEXT_8XL,
-        text_9xl => font_size::TEXT_9XL,
-    }
-
-    // === Font Weight Implementations ===
-    impl_font_weight_methods! {
-        font_thin => font_weight::THIN,
-        font_extralight => font_weight::EXTRALIGHT,
-        font_light => font_weight::LIGHT,
-        font_normal => font_weight::NORMAL,
-        font_medium => font_weight::MEDIUM,
-        font_semibold => font_weight::SEMIBOLD,
-        font_bold => font_weight::BOLD,
-        font_extrabold => font_weight::EXTRABOLD,
-        font_black => font_weight::BLACK,
-    }
-
-    // === Line Height Implementations ===
-    impl_line_height_methods! {
-        leading_none => line_height::LEADING_NONE,
-        leading_tight => line_height::LEADING_TIGHT,
-        leading_snug => line_height::LEADING_SNUG,
-        leading_normal => line_height::LEADING_NORMAL,
-        leading_relaxed => line_height::LEADING_RELAXED,
-        leading_loose => line_height::LEADING_LOOSE,
-    }
-
-    // === Display Implementations ===
-    fn flex(self) -> Self {
-        self.display(floem::style::Display::Flex)
-    }
-    fn block(self) -> Self {
-        self.display(floem::style::Display::Block)
-    }
-    fn grid(self) -> Self {
-        self.display(floem::style::Display::Grid)
-    }
-    fn hidden(self) -> Self {
-        self.display(floem::style::Display::None)
-    }
-
-    // === Flex Direction Implementations ===
-    fn flex_row(self) -> Self {
-        self.flex_direction(floem::style::FlexDirection::Row)
-    }
-    fn flex_col(self) -> Self {
-        self.flex_direction(floem::style::FlexDirection::Column)
-    }
-    fn flex_row_reverse(self) -> Self {
-        self.flex_direction(floem::style::FlexDirection::RowReverse)
-    }
-    fn flex_col_reverse(self) -> Self {
-        self.flex_direction(floem::style::FlexDirection::ColumnReverse)
-    }
-
-    // === Flex Wrap Implementations ===
-    fn wrap(self) -> Self {
-        self.flex_wrap(floem::style::FlexWrap::Wrap)
-    }
-    fn nowrap(self) -> Self {
-        self.flex_wrap(floem::style::FlexWrap::NoWrap)
-    }
-    fn wrap_reverse(self) -> Self {
-        self.flex_wrap(floem::style::FlexWrap::WrapReverse)
-    }
-
-    // === Cursor Implementations ===
-    fn cursor_pointer(self) -> Self {
-        self.cursor(floem::style::CursorStyle::Pointer)
-    }
-    fn cursor_default(self) -> Self {
-        self.cursor(floem::style::CursorStyle::Default)
-    }
-    fn cursor_text(self) -> Self {
-        self.cursor(floem::style::CursorStyle::Text)
-    }
-    fn cursor_move(self) -> Self {
-        self.cursor(floem::style::CursorStyle::Move)
-    }
-    fn cursor_grab(self) -> Self {
-        self.cursor(floem::style::CursorStyle::Grab)
-    }
-    fn cursor_grabbing(self) -> Self {
-        self.cursor(floem::style::CursorStyle::Grabbing)
-    }
-
-    // === Position Implementations ===
-    fn absolute(self) -> Self {
-        self.position(floem::style::Position::Absolute)
-    }
-    fn relative(self) -> Self {
-        self.position(floem::style::Position::Relative)
-    }
-    fn fixed(self) -> Self {
-        self.set(floem::style::IsFixed, true)
-    }
-    fn inset_0(self) -> Self {
-        self.inset(0.0)
-    }
-    fn left_0(self) -> Self {
-        self.inset_left(0.0)
-    }
-    fn left_1_2(self) -> Self {
-        self.inset_left(Pct(50.0))
-    }
-    fn left_full(self) -> Self {
-        self.inset_left(Pct(100.0))
-    }
-    fn top_0(self) -> Self {
-        self.inset_top(0.0)
-    }
-    fn top_1_2(self) -> Self {
-        self.inset_top(Pct(50.0))
-    }
-    fn top_full(self) -> Self {
-        self.inset_top(Pct(100.0))
-    }
-    fn right_0(self) -> Self {
-        self.inset_right(0.0)
-    }
-    fn right_1_2(self) -> Self {
-        self.inset_right(Pct(50.0))
-    }
-    fn right_full(self) -> Self {
-        self.inset_right(Pct(100.0))
-    }
-    fn bottom_0(self) -> Self {
-        self.inset_bottom(0.0)
-    }
-    fn bottom_1_2(self) -> Self {
-        self.inset_bottom(Pct(50.0))
-    }
-    fn bottom_full(self) -> Self {
-        self.inset_bottom(Pct(100.0))
-    }
-
-    // === Justify Content Implementations ===
-    fn justify_start(self) -> Self {
-        self.justify_content(Some(floem::style::JustifyContent::FlexStart))
-    }
-    fn justify_center(self) -> Self {
-        self.justify_content(Some(floem::style::JustifyContent::Center))
-    }
-    fn justify_end(self) -> Self {
-        self.justify_content(Some(floem::style::JustifyContent::FlexEnd))
-    }
-    fn justify_between(self) -> Self {
-        self.justify_content(Some(floem::style::JustifyContent::SpaceBetween))
-    }
-    fn justify_around(self) -> Self {
-        self.justify_content(Some(floem::style::JustifyContent::SpaceAround))
-    }
-    fn justify_evenly(self) -> Self {
-        self.justify_content(Some(floem::style::JustifyContent::SpaceEvenly))
-    }
-
-    // === Align Items Implementations ===
-    fn items_start(self) -> Self {
-        self.align_items(Some(floem::style::AlignItems::FlexStart))
-    }
-    fn items_center(self) -> Self {
-        self.align_items(Some(floem::style::AlignItems::Center))
-    }
-    fn items_end(self) -> Self {
-        self.align_items(Some(floem::style::AlignItems::FlexEnd))
-    }
-    fn items_stretch(self) -> Self {
-        self.align_items(Some(floem::style::AlignItems::Stretch))
-    }
-    fn items_baseline(self) -> Self {
-        self.align_items(Some(floem::style::AlignItems::Baseline))
-    }
-
-    // === Align Self Implementations ===
-    fn self_auto(self) -> Self {
-        self.align_self(None)
-    }
-    fn self_start(self) -> Self {
-        self.align_self(Some(floem::style::AlignItems::FlexStart))
-    }
-    fn self_center(self) -> Self {
-        self.align_self(Some(floem::style::AlignItems::Center))
-    }
-    fn self_end(self) -> Self {
-        self.align_self(Some(floem::style::AlignItems::FlexEnd))
-    }
-    fn self_stretch(self) -> Self {
-        self.align_self(Some(floem::style::AlignItems::Stretch))
-    }
-    fn self_baseline(self) -> Self {
-        self.align_self(Some(floem::style::AlignItems::Baseline))
-    }
-
-    // === Translate Implementations ===
-    fn translate_x_1_2(self) -> Self {
-        self.translate_x(Pct(50.0))
-    }
-    fn translate_x_neg_1_2(self) -> Self {
-        self.translate_x(Pct(-50.0))
-    }
-    fn translate_y_1_2(self) -> Self {
-        self.translate_y(Pct(50.0))
-    }
-    fn translate_y_neg_1_2(self) -> Self {
-        self.translate_y(Pct(-50.0))
-    }
-    fn translate_x_full(self) -> Self {
-        self.translate_x(Pct(100.0))
-    }
-    fn translate_x_neg_full(self) -> Self {
-        self.translate_x(Pct(-100.0))
-    }
-    fn translate_y_full(self) -> Self {
-        self.translate_y(Pct(100.0))
-    }
-    fn translate_y_neg_full(self) -> Self {
-        self.translate_y(Pct(-100.0))
-    }
-
-    // === Border Color Implementations ===
-    fn border_transparent(self) -> Self {
-        self.border_color(colors::TRANSPARENT)
-    }
-    fn border_black(self) -> Self {
-        self.border_color(colors::BLACK)
-    }
-    fn border_white(self) -> Self {
-        self.border_color(colors::WHITE)
-    }
-    fn border_gray_200(self) -> Self {
-        self.border_color(colors::gray::C200)
-    }
-    fn border_gray_300(self) -> Self {
-        self.border_color(colors::gray::C300)
-    }
-    fn border_gray_400(self) -> Self {
-        self.border_color(colors::gray::C400)
-    }
-    fn border_gray_500(self) -> Self {
-        self.border_color(colors::gray::C500)
-    }
-    fn border_gray_600(self) -> Self {
-        self.border_color(colors::gray::C600)
-    }
-    fn border_red_500(self) -> Self {
-        self.border_color(colors::red::C500)
-    }
-    fn border_blue_500(self) -> Self {
-        self.border_color(colors::blue::C500)
-    }
-    fn border_green_500(self) -> Self {
-        self.border_color(colors::green::C500)
-    }
-
-    // === Opacity Implementations ===
-    fn opacity_0(self) -> Self {
-        self.set(floem::style::Opacity, 0.0)
-    }
-    fn opacity_5(self) -> Self {
-        self.set(floem::style::Opacity, 0.05)
-    }
-    fn opacity_10(self) -> Self {
-        self.set(floem::style::Opacity, 0.10)
-    }
-    fn opacity_15(self) -> Self {
-        self.set(floem::style::Opacity, 0.15)
-    }
-    fn opacity_20(self) -> Self {
-        self.set(floem::style::Opacity, 0.20)
-    }
-    fn opacity_25(self) -> Self {
-        self.set(floem::style::Opacity, 0.25)
-    }
-    fn opacity_30(self) -> Self {
-        self.set(floem::style::Opacity, 0.30)
-    }
-    fn opacity_35(self) -> Self {
-        self.set(floem::style::Opacity, 0.35)
-    }
-    fn opacity_40(self) -> Self {
-        self.set(floem::style::Opacity, 0.40)
-    }
-    fn opacity_45(self) -> Self {
-        self.set(floem::style::Opacity, 0.45)
-    }
-    fn opacity_50(self) -> Self {
-        self.set(floem::style::Opacity, 0.50)
-    }
-    fn opacity_55(self) -> Self {
-        self.set(floem::style::Opacity, 0.55)
-    }
-    fn opacity_60(self) -> Self {
-        self.set(floem::style::Opacity, 0.60)
-    }
-    fn opacity_65(self) -> Self {
-        self.set(floem::style::Opacity, 0.65)
-    }
-    fn opacity_70(self) -> Self {
-        self.set(floem::style::Opacity, 0.70)
-    }
-    fn opacity_75(self) -> Self {
-        self.set(floem::style::Opacity, 0.75)
-    }
-    fn opacity_80(self) -> Self {
-        self.set(floem::style::Opacity, 0.80)
-    }
-    fn opacity_85(self) -> Self {
-        self.set(floem::style::Opacity, 0.85)
-    }
-    fn opacity_90(self) -> Self {
-        self.set(floem::style::Opacity, 0.90)
-    }
-    fn opacity_95(self) -> Self {
-        self.set(floem::style::Opacity, 0.95)
-    }
-    fn opacity_100(self) -> Self {
-        self.set(floem::style::Opacity, 1.0)
-    }
-
-    // === Pointer Events Implementations ===
-    fn pointer_events_none(self) -> Self {
-        self.set(
-            floem::style::PointerEventsProp,
-            Some(floem::style::PointerEvents::None),
-        )
-    }
-    fn pointer_events_auto(self) -> Self {
-        self.set(
-            floem::style::PointerEventsProp,
-            Some(floem::style::PointerEvents::Auto),
-        )
+    #[test]
+    fn test_radius_values() {
+        assert_eq!(radius::ROUNDED_SM, 2.0);
+        assert_eq!(radius::ROUNDED_MD, 6.0);
+        assert_eq!(radius::ROUNDED_LG, 8.0);
     }
 
-    // === Overflow Implementations ===
-    fn overflow_hidden(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Hidden)
-            .set(floem::style::OverflowY, floem::taffy::Overflow::Hidden)
-    }
-    fn overflow_visible(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Visible)
-            .set(floem::style::OverflowY, floem::taffy::Overflow::Visible)
-    }
-    fn overflow_scroll(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Scroll)
-            .set(floem::style::OverflowY, floem::taffy::Overflow::Scroll)
-    }
-    fn overflow_clip(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Clip)
-            .set(floem::style::OverflowY, floem::taffy::Overflow::Clip)
-    }
-    fn overflow_auto(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Scroll)
-            .set(floem::style::OverflowY, floem::taffy::Overflow::Scroll)
-    }
-    fn overflow_x_hidden(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Hidden)
-    }
-    fn overflow_x_visible(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Visible)
-    }
-    fn overflow_x_scroll(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Scroll)
-    }
-    fn overflow_x_clip(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Clip)
-    }
-    fn overflow_x_auto(self) -> Self {
-        self.set(floem::style::OverflowX, floem::taffy::Overflow::Scroll)
-    }
-    fn overflow_y_hidden(self) -> Self {
-        self.set(floem::style::OverflowY, floem::taffy::Overflow::Hidden)
-    }
-    fn overflow_y_visible(self) -> Self {
-        self.set(floem::style::OverflowY, floem::taffy::Overflow::Visible)
-    }
-    fn overflow_y_scroll(self) -> Self {
-        self.set(floem::style::OverflowY, floem::taffy::Overflow::Scroll)
-    }
-    fn overflow_y_clip(self) -> Self {
-        self.set(floem::style::OverflowY, floem::taffy::Overflow::Clip)
-    }
-    fn overflow_y_auto(self) -> Self {
-        self.set(floem::style::OverflowY, floem::taffy::Overflow::Scroll)
+    #[test]
+    fn test_spacing_enum_values() {
+        assert_eq!(Spacing::Px.to_px(), spacing::SPACING_PX);
+        assert_eq!(Spacing::S0_5.to_px(), spacing::SPACING_0_5);
+        assert_eq!(Spacing::S4.to_px(), spacing::SPACING_4);
+        assert_eq!(Spacing::S96.to_px(), spacing::SPACING_96);
+        assert!(Spacing::ALL.windows(2).all(|w| w[0].to_px() < w[1].to_px()));
     }
 
-    // === Outline Implementations ===
-    fn outline_none(self) -> Self {
-        self.outline(0.0)
-    }
-}
+    #[test]
+    fn test_utilities_match_parser() {
+        let mut seen = std::collections::HashSet::new();
+        for (class, _) in utilities() {
+            assert!(seen.insert(class), "duplicate utility `{class}`");
+            let parsed: TwClass = class
+                .parse()
+                .unwrap_or_else(|_| panic!("utility `{class}` doesn't parse"));
+            assert_eq!(parsed.to_string(), class);
+        }
+        assert!(seen.contains("w-1/2") && seen.contains("-translate-x-1/2"));
+    }
+
+    #[cfg(feature = "spacing")]
+    #[test]
+    fn test_fraction_aliases() {
+        use floem::style::{FlexBasis, Width};
+
+        let half = Style::new().w_frac_1_2();
+        assert_eq!(half.get(Width), PxPctAuto::Pct(50.0));
+        assert_eq!(Style::new().w_half().get(Width), half.get(Width));
+        #[allow(deprecated)]
+        let deprecated = Style::new().w_1_2();
+        assert_eq!(deprecated.get(Width), half.get(Width));
+        assert_eq!(
+            Style::new().basis_two_thirds().get(FlexBasis),
+            PxPctAuto::Pct(66.666667)
+        );
+    }
+
+    #[cfg(all(feature = "prefixed", feature = "layout"))]
+    #[test]
+    fn test_prefixed_methods() {
+        use floem::style::{BorderLeft, DisplayProp, PaddingTop};
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        use crate::prefixed::*;
 
-    #[test]
-    fn test_spacing_values() {
-        assert_eq!(spacing::SPACING_1, 4.0);
-        assert_eq!(spacing::SPACING_2, 8.0);
-        assert_eq!(spacing::SPACING_4, 16.0);
-        assert_eq!(spacing::SPACING_8, 32.0);
-        assert_eq!(spacing::SPACING_16, 64.0);
+        let s = Style::new().tw_flex().tw_border_2().tw_rounded_md();
+        assert_eq!(s.get(DisplayProp), floem::style::Display::Flex);
+        assert_eq!(s.get(BorderLeft), Style::new().border_2().get(BorderLeft));
+        #[cfg(feature = "spacing")]
+        assert_eq!(Style::new().tw_p_4().get(PaddingTop), Style::new().p_4().get(PaddingTop));
     }
 
     #[test]
-    fn test_size_values() {
-        assert_eq!(spacing::SIZE_SM, 384.0);
-        assert_eq!(spacing::SIZE_MD, 448.0);
-        assert_eq!(spacing::SIZE_LG, 512.0);
+    fn test_auto_hover_shade() {
+        assert_eq!(
+            hover_shade(colors::blue::C500, false),
+            Some(colors::blue::C600)
+        );
+        assert_eq!(
+            hover_shade(colors::blue::C500, true),
+            Some(colors::blue::C400)
+        );
+        assert_eq!(hover_shade(colors::blue::C950, false), None);
+        assert_eq!(hover_shade(Color::from_rgb8(1, 2, 3), false), None);
     }
 
     #[test]
-    fn test_radius_values() {
-        assert_eq!(radius::ROUNDED_SM, 2.0);
-        assert_eq!(radius::ROUNDED_MD, 6.0);
-        assert_eq!(radius::ROUNDED_LG, 8.0);
+    fn test_outline_offset_moves_focus_ring() {
+        let before = Style::new().outline_offset_4().focus_ring();
+        let after = Style::new().focus_ring().outline_offset_4();
+        for s in [&before, &after] {
+            assert_eq!(s.get(OutlineOffset), Some(4.0));
+            assert_eq!(s.get(FocusRingColor), Some(colors::blue::C500));
+        }
+        let removed = after.no_focus_ring().outline_offset_8();
+        assert_eq!(removed.get(FocusRingColor), None);
     }
 }
@@ -0,0 +1,278 @@
+//! Machine-readable listing of the supported utilities
+//!
+//! [`manifest`] describes every registered utility: its class name, the
+//! utility it belongs to, its parameter, and the CSS properties it sets.
+//! Editor plugins and autocompletion for class strings can consume it as
+//! JSON (with the `json` feature; see `examples/manifest.rs`):
+//!
+//! ```json
+//! {
+//!   "version": "0.1.0",
+//!   "utilities": [
+//!     {
+//!       "class": "w-1/2",
+//!       "utility": "w",
+//!       "parameter": { "kind": "length", "value": "1/2" },
+//!       "properties": ["width"]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! The parser also accepts a few values without a utility method (such as
+//! `m-md` or `opacity-35`); those aren't listed.
+
+use crate::class::{Edge, TwClass};
+use crate::registry;
+
+/// The value a utility takes, e.g. `500` in `bg-blue-500`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Parameter {
+    /// The value's scale: `spacing`, `length`, `color`, `radius`, `keyword`, ...
+    pub kind: &'static str,
+    /// The class-name fragment
+    pub value: String,
+}
+
+/// One utility class
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Entry {
+    /// The full class name
+    pub class: String,
+    /// The utility the class belongs to, e.g. `w` for `w-4`
+    pub utility: &'static str,
+    /// The class's value, if it has one
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub parameter: Option<Parameter>,
+    /// The CSS properties the class sets; empty for custom utilities
+    pub properties: &'static [&'static str],
+}
+
+/// Every registered utility with the crate version
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Manifest {
+    /// The floem-tailwind version
+    pub version: &'static str,
+    /// The utilities, in registry order
+    pub utilities: Vec<Entry>,
+}
+
+impl Manifest {
+    /// Serializes the manifest as pretty-printed JSON
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("manifest serializes to JSON")
+    }
+}
+
+/// Describes the registered utilities, including custom ones
+pub fn manifest() -> Manifest {
+    Manifest {
+        version: env!("CARGO_PKG_VERSION"),
+        utilities: registry::all()
+            .into_iter()
+            .map(|utility| {
+                let class = utility.name().parse().unwrap_or(TwClass::Utility(utility));
+                describe(class)
+            })
+            .collect(),
+    }
+}
+
+/// The padding or margin properties of each side shorthand: all, x, y, top, right, bottom, left
+macro_rules! sides {
+    ($all:literal) => {
+        [
+            &[$all],
+            &[concat!($all, "-left"), concat!($all, "-right")],
+            &[concat!($all, "-top"), concat!($all, "-bottom")],
+            &[concat!($all, "-top")],
+            &[concat!($all, "-right")],
+            &[concat!($all, "-bottom")],
+            &[concat!($all, "-left")],
+        ]
+    };
+}
+
+const PADDING: [&[&str]; 7] = sides!("padding");
+const MARGIN: [&[&str]; 7] = sides!("margin");
+
+/// The properties an inset utility sets
+fn inset_properties(edge: Edge) -> &'static [&'static str] {
+    match edge {
+        Edge::All => &["top", "right", "bottom", "left"],
+        Edge::Top => &["top"],
+        Edge::Right => &["right"],
+        Edge::Bottom => &["bottom"],
+        Edge::Left => &["left"],
+    }
+}
+
+/// Describes a single utility
+pub fn describe(class: TwClass) -> Entry {
+    let (utility, kind, properties): (&'static str, &'static str, &'static [&'static str]) =
+        match class {
+            TwClass::W(_) => ("w", "length", &["width"]),
+            TwClass::H(_) => ("h", "length", &["height"]),
+            TwClass::Size(_) => ("size", "length", &["width", "height"]),
+            TwClass::MinW(_) => ("min-w", "length", &["min-width"]),
+            TwClass::MaxW(_) => ("max-w", "length", &["max-width"]),
+            TwClass::MaxWScreen(_) => ("max-w", "screen", &["max-width"]),
+            TwClass::MinH(_) => ("min-h", "length", &["min-height"]),
+            TwClass::MaxH(_) => ("max-h", "length", &["max-height"]),
+            TwClass::P(_) => ("p", "length", PADDING[0]),
+            TwClass::Px(_) => ("px", "length", PADDING[1]),
+            TwClass::Py(_) => ("py", "length", PADDING[2]),
+            TwClass::Pt(_) => ("pt", "length", PADDING[3]),
+            TwClass::Pr(_) => ("pr", "length", PADDING[4]),
+            TwClass::Pb(_) => ("pb", "length", PADDING[5]),
+            TwClass::Pl(_) => ("pl", "length", PADDING[6]),
+            TwClass::Ps(_) => ("ps", "length", &["padding-inline-start"]),
+            TwClass::Pe(_) => ("pe", "length", &["padding-inline-end"]),
+            TwClass::M(_) => ("m", "length", MARGIN[0]),
+            TwClass::Mx(_) => ("mx", "length", MARGIN[1]),
+            TwClass::My(_) => ("my", "length", MARGIN[2]),
+            TwClass::Mt(_) => ("mt", "length", MARGIN[3]),
+            TwClass::Mr(_) => ("mr", "length", MARGIN[4]),
+            TwClass::Mb(_) => ("mb", "length", MARGIN[5]),
+            TwClass::Ml(_) => ("ml", "length", MARGIN[6]),
+            TwClass::Ms(_) => ("ms", "length", &["margin-inline-start"]),
+            TwClass::Me(_) => ("me", "length", &["margin-inline-end"]),
+            TwClass::Gap(_) => ("gap", "spacing", &["gap"]),
+            TwClass::Rounded(_) => ("rounded", "radius", &["border-radius"]),
+            TwClass::RoundedStart(_) => (
+                "rounded-s",
+                "radius",
+                &["border-start-start-radius", "border-end-start-radius"],
+            ),
+            TwClass::RoundedEnd(_) => (
+                "rounded-e",
+                "radius",
+                &["border-start-end-radius", "border-end-end-radius"],
+            ),
+            TwClass::BorderWidth(_) => ("border", "number", &["border-width"]),
+            TwClass::BorderColor(..) | TwClass::BorderKeyword(_) => {
+                ("border", "color", &["border-color"])
+            }
+            TwClass::OutlineNone => ("outline", "keyword", &["outline"]),
+            TwClass::OutlineOffset(_) => ("outline-offset", "number", &["outline-offset"]),
+            TwClass::Shadow(_) => ("shadow", "shadow", &["box-shadow"]),
+            TwClass::DropShadow(_) => ("drop-shadow", "shadow", &["box-shadow"]),
+            TwClass::Elevation(_) => (
+                "elevation",
+                "number",
+                &[
+                    "background-color",
+                    "box-shadow",
+                    "border-width",
+                    "border-color",
+                ],
+            ),
+            TwClass::Opacity(_) => ("opacity", "number", &["opacity"]),
+            TwClass::BgColor(..) | TwClass::BgKeyword(_) => ("bg", "color", &["background-color"]),
+            TwClass::TextColor(..) | TwClass::TextKeyword(_) => ("text", "color", &["color"]),
+            TwClass::PlaceholderColor(..) | TwClass::PlaceholderKeyword(_) => {
+                ("placeholder", "color", &["color"])
+            }
+            TwClass::TextSize(_) => ("text", "font-size", &["font-size"]),
+            TwClass::TextSizeLeading(..) => ("text", "font-size", &["font-size", "line-height"]),
+            TwClass::FontWeight(_) => ("font", "font-weight", &["font-weight"]),
+            TwClass::FontStretch(_) => ("font-stretch", "keyword", &["font-stretch"]),
+            TwClass::Leading(_) => ("leading", "line-height", &["line-height"]),
+            TwClass::Display(display) => (display.name(), "keyword", &["display"]),
+            TwClass::FlexDirection(_) => ("flex", "keyword", &["flex-direction"]),
+            TwClass::FlexWrap(_) => ("flex", "keyword", &["flex-wrap"]),
+            TwClass::Position(position) => (position.name(), "keyword", &["position"]),
+            TwClass::Inset(edge, _) => (edge.name(), "length", inset_properties(edge)),
+            TwClass::NegInset(edge, _) => (
+                match edge {
+                    Edge::All => "-inset",
+                    Edge::Top => "-top",
+                    Edge::Right => "-right",
+                    Edge::Bottom => "-bottom",
+                    Edge::Left => "-left",
+                },
+                "length",
+                inset_properties(edge),
+            ),
+            TwClass::TranslateX(_) => ("translate-x", "length", &["translate"]),
+            TwClass::TranslateY(_) => ("translate-y", "length", &["translate"]),
+            TwClass::NegTranslateX(_) => ("-translate-x", "length", &["translate"]),
+            TwClass::NegTranslateY(_) => ("-translate-y", "length", &["translate"]),
+            TwClass::ZIndex(Some(z)) if z < 0 => ("-z", "number", &["z-index"]),
+            TwClass::ZIndex(_) => ("z", "number", &["z-index"]),
+            TwClass::Justify(_) => ("justify", "keyword", &["justify-content"]),
+            TwClass::Items(_) => ("items", "keyword", &["align-items"]),
+            TwClass::SelfAlign(_) => ("self", "keyword", &["align-self"]),
+            TwClass::VerticalAlign(_) => ("align", "keyword", &["vertical-align"]),
+            TwClass::Cursor(_) => ("cursor", "keyword", &["cursor"]),
+            TwClass::PointerEventsNone | TwClass::PointerEventsAuto => {
+                ("pointer-events", "keyword", &["pointer-events"])
+            }
+            TwClass::Overflow(_) => ("overflow", "keyword", &["overflow-x", "overflow-y"]),
+            TwClass::OverflowX(_) => ("overflow-x", "keyword", &["overflow-x"]),
+            TwClass::OverflowY(_) => ("overflow-y", "keyword", &["overflow-y"]),
+            TwClass::Utility(utility) => (utility.name(), "keyword", &[]),
+            TwClass::ContrastMore(variant) => {
+                return Entry {
+                    class: class.to_string(),
+                    ..describe(variant.class())
+                };
+            }
+            TwClass::Active(variant) => {
+                return Entry {
+                    class: class.to_string(),
+                    ..describe(variant.class())
+                };
+            }
+        };
+    let name = class.to_string();
+    let parameter = name
+        .strip_prefix(utility)
+        .and_then(|rest| rest.strip_prefix('-'))
+        .map(|value| Parameter {
+            kind,
+            value: value.to_string(),
+        });
+    Entry {
+        class: name,
+        utility,
+        parameter,
+        properties,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let entry = describe("bg-blue-500".parse().unwrap());
+        assert_eq!(entry.utility, "bg");
+        assert_eq!(
+            entry.parameter,
+            Some(Parameter {
+                kind: "color",
+                value: "blue-500".to_string()
+            })
+        );
+        assert_eq!(entry.properties, ["background-color"]);
+
+        let entry = describe("rounded".parse().unwrap());
+        assert_eq!((entry.utility, entry.parameter), ("rounded", None));
+        assert_eq!(describe("px-4".parse().unwrap()).properties, PADDING[1]);
+        assert_eq!(describe("absolute".parse().unwrap()).utility, "absolute");
+
+        let manifest = manifest();
+        assert!(
+            manifest
+                .utilities
+                .iter()
+                .any(|entry| entry.class == "w-1/2")
+        );
+    }
+}
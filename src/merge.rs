@@ -0,0 +1,489 @@
+//! Conflict-resolving class merging, after tailwind-merge
+//!
+//! When class strings are composed (base + variant + override), a later
+//! utility replaces every earlier utility that sets the same property:
+//!
+//! ```rust
+//! use floem_tailwind::tw_merge;
+//!
+//! assert_eq!(tw_merge("p-4 bg-red-500", "p-2"), "bg-red-500 p-2");
+//! assert_eq!(tw_merge("px-2 py-1", "p-3"), "p-3");
+//! assert_eq!(tw_merge("p-3", "px-2"), "p-3 px-2");
+//! ```
+//!
+//! Classes that don't parse are kept as-is.
+//!
+//! [`conflicts`] reports the same overrides without removing anything, to
+//! catch copy-paste mistakes in hand-written class strings:
+//!
+//! ```rust
+//! use floem_tailwind::merge::conflicts;
+//!
+//! let found = conflicts("w-4 flex-row p-2 w-full flex-col");
+//! assert_eq!(found[0].to_string(), "`w-full` overrides `w-4`");
+//! assert_eq!(found[1].to_string(), "`flex-col` overrides `flex-row`");
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use floem::style::Style;
+
+use crate::TailwindExt;
+use crate::class::{Edge, TwClass};
+use crate::intern;
+
+/// The property a utility sets; utilities in the same group conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Group {
+    W,
+    H,
+    Size,
+    MinW,
+    MaxW,
+    MinH,
+    MaxH,
+    P,
+    Px,
+    Py,
+    Pt,
+    Pr,
+    Pb,
+    Pl,
+    Ps,
+    Pe,
+    M,
+    Mx,
+    My,
+    Mt,
+    Mr,
+    Mb,
+    Ml,
+    Ms,
+    Me,
+    Gap,
+    Rounded,
+    RoundedStart,
+    RoundedEnd,
+    BorderWidth,
+    BorderColor,
+    Outline,
+    OutlineOffset,
+    Shadow,
+    Elevation,
+    Opacity,
+    Bg,
+    TextColor,
+    Placeholder,
+    TextSize,
+    TextSizeLeading,
+    FontWeight,
+    FontStretch,
+    Leading,
+    Display,
+    FlexDirection,
+    FlexWrap,
+    Position,
+    Inset(Edge),
+    TranslateX,
+    TranslateY,
+    ZIndex,
+    Justify,
+    Items,
+    SelfAlign,
+    Cursor,
+    PointerEvents,
+    Overflow,
+    OverflowX,
+    OverflowY,
+    /// A registered utility only conflicts with itself
+    Utility(&'static str),
+}
+
+impl Group {
+    fn of(class: TwClass) -> Group {
+        match class {
+            TwClass::W(_) => Group::W,
+            TwClass::H(_) => Group::H,
+            TwClass::Size(_) => Group::Size,
+            TwClass::MinW(_) => Group::MinW,
+            TwClass::MaxW(_) | TwClass::MaxWScreen(_) => Group::MaxW,
+            TwClass::MinH(_) => Group::MinH,
+            TwClass::MaxH(_) => Group::MaxH,
+            TwClass::P(_) => Group::P,
+            TwClass::Px(_) => Group::Px,
+            TwClass::Py(_) => Group::Py,
+            TwClass::Pt(_) => Group::Pt,
+            TwClass::Pr(_) => Group::Pr,
+            TwClass::Pb(_) => Group::Pb,
+            TwClass::Pl(_) => Group::Pl,
+            TwClass::Ps(_) => Group::Ps,
+            TwClass::Pe(_) => Group::Pe,
+            TwClass::M(_) => Group::M,
+            TwClass::Mx(_) => Group::Mx,
+            TwClass::My(_) => Group::My,
+            TwClass::Mt(_) => Group::Mt,
+            TwClass::Mr(_) => Group::Mr,
+            TwClass::Mb(_) => Group::Mb,
+            TwClass::Ml(_) => Group::Ml,
+            TwClass::Ms(_) => Group::Ms,
+            TwClass::Me(_) => Group::Me,
+            TwClass::Gap(_) => Group::Gap,
+            TwClass::Rounded(_) => Group::Rounded,
+            TwClass::RoundedStart(_) => Group::RoundedStart,
+            TwClass::RoundedEnd(_) => Group::RoundedEnd,
+            TwClass::BorderWidth(_) => Group::BorderWidth,
+            TwClass::BorderColor(..) | TwClass::BorderKeyword(_) => Group::BorderColor,
+            TwClass::OutlineNone => Group::Outline,
+            TwClass::OutlineOffset(_) => Group::OutlineOffset,
+            // Drop shadows are drawn as box shadows
+            TwClass::Shadow(_) | TwClass::DropShadow(_) => Group::Shadow,
+            TwClass::Elevation(_) => Group::Elevation,
+            TwClass::Opacity(_) => Group::Opacity,
+            TwClass::BgColor(..) | TwClass::BgKeyword(_) => Group::Bg,
+            TwClass::TextColor(..) | TwClass::TextKeyword(_) => Group::TextColor,
+            TwClass::PlaceholderColor(..) | TwClass::PlaceholderKeyword(_) => Group::Placeholder,
+            TwClass::TextSize(_) => Group::TextSize,
+            TwClass::TextSizeLeading(..) => Group::TextSizeLeading,
+            TwClass::FontWeight(_) => Group::FontWeight,
+            TwClass::FontStretch(_) => Group::FontStretch,
+            TwClass::Leading(_) => Group::Leading,
+            TwClass::Display(_) => Group::Display,
+            TwClass::FlexDirection(_) => Group::FlexDirection,
+            TwClass::FlexWrap(_) => Group::FlexWrap,
+            TwClass::Position(_) => Group::Position,
+            TwClass::Inset(edge, _) | TwClass::NegInset(edge, _) => Group::Inset(edge),
+            TwClass::TranslateX(_) | TwClass::NegTranslateX(_) => Group::TranslateX,
+            TwClass::TranslateY(_) | TwClass::NegTranslateY(_) => Group::TranslateY,
+            TwClass::ZIndex(_) => Group::ZIndex,
+            TwClass::Justify(_) => Group::Justify,
+            TwClass::Items(_) => Group::Items,
+            // Both set Floem's align-self
+            TwClass::SelfAlign(_) | TwClass::VerticalAlign(_) => Group::SelfAlign,
+            TwClass::Cursor(_) => Group::Cursor,
+            TwClass::PointerEventsNone | TwClass::PointerEventsAuto => Group::PointerEvents,
+            TwClass::Overflow(_) => Group::Overflow,
+            TwClass::OverflowX(_) => Group::OverflowX,
+            TwClass::OverflowY(_) => Group::OverflowY,
+            TwClass::Utility(utility) => Group::Utility(utility.name()),
+            TwClass::ContrastMore(variant) => Group::of(variant.class()),
+            TwClass::Active(variant) => Group::of(variant.class()),
+        }
+    }
+
+    /// Narrower groups that this group fully overrides
+    fn overrides(self) -> &'static [Group] {
+        match self {
+            Group::Size => &[Group::W, Group::H],
+            Group::P => &[
+                Group::Px,
+                Group::Py,
+                Group::Pt,
+                Group::Pr,
+                Group::Pb,
+                Group::Pl,
+                Group::Ps,
+                Group::Pe,
+            ],
+            Group::Px => &[Group::Pr, Group::Pl, Group::Ps, Group::Pe],
+            Group::Py => &[Group::Pt, Group::Pb],
+            Group::M => &[
+                Group::Mx,
+                Group::My,
+                Group::Mt,
+                Group::Mr,
+                Group::Mb,
+                Group::Ml,
+                Group::Ms,
+                Group::Me,
+            ],
+            Group::Mx => &[Group::Mr, Group::Ml, Group::Ms, Group::Me],
+            Group::My => &[Group::Mt, Group::Mb],
+            Group::Inset(Edge::All) => &[
+                Group::Inset(Edge::Top),
+                Group::Inset(Edge::Right),
+                Group::Inset(Edge::Bottom),
+                Group::Inset(Edge::Left),
+            ],
+            Group::Rounded => &[Group::RoundedStart, Group::RoundedEnd],
+            Group::TextSizeLeading => &[Group::TextSize, Group::Leading],
+            Group::Overflow => &[Group::OverflowX, Group::OverflowY],
+            // Elevation presets set the surface color, shadow, and border
+            Group::Elevation => &[
+                Group::Bg,
+                Group::Shadow,
+                Group::BorderWidth,
+                Group::BorderColor,
+            ],
+            _ => &[],
+        }
+    }
+}
+
+/// The group of a utility, and the variant it's behind, if any; utilities
+/// only conflict with others behind the same variant
+fn key(class: TwClass) -> (Option<&'static str>, Group) {
+    let variant = match class {
+        TwClass::ContrastMore(_) => Some("contrast-more"),
+        TwClass::Active(_) => Some("active"),
+        _ => None,
+    };
+    (variant, Group::of(class))
+}
+
+/// Whether `later` replaces everything `earlier` sets
+pub(crate) fn overrides(earlier: TwClass, later: TwClass) -> bool {
+    let ((earlier_variant, earlier), (later_variant, later)) = (key(earlier), key(later));
+    earlier_variant == later_variant && (earlier == later || later.overrides().contains(&earlier))
+}
+
+/// Whether two utilities set the same property, behind the same variant
+pub(crate) fn same_group(a: TwClass, b: TwClass) -> bool {
+    key(a) == key(b)
+}
+
+/// A utility whose effect is replaced by a later one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The earlier class, which has no effect
+    pub overridden: String,
+    /// The later class that replaces it
+    pub by: String,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` overrides `{}`", self.by, self.overridden)
+    }
+}
+
+/// Finds utilities in a class string that a later utility fully overrides
+///
+/// Each overridden class is reported once, against the first class that
+/// overrides it. Refinements like `p-4 pt-2` aren't conflicts.
+pub fn conflicts(classes: &str) -> Vec<Conflict> {
+    let parsed: Vec<(&str, TwClass)> = classes
+        .split_whitespace()
+        .filter_map(|token| Some((token, token.parse().ok()?)))
+        .collect();
+    parsed
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &(token, class))| {
+            let &(by, _) = parsed[i + 1..]
+                .iter()
+                .find(|&&(_, later)| overrides(class, later))?;
+            Some(Conflict {
+                overridden: token.to_string(),
+                by: by.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Merges class fragments in order, dropping utilities overridden by later ones
+pub fn tw_merge_all<'a>(fragments: impl IntoIterator<Item = &'a str>) -> String {
+    let tokens: Vec<&str> = fragments
+        .into_iter()
+        .flat_map(str::split_whitespace)
+        .collect();
+
+    // Walk backwards so the last utility of each group is the one kept
+    let mut seen = HashSet::new();
+    let mut kept: Vec<&str> = tokens
+        .into_iter()
+        .rev()
+        .filter(|token| {
+            let Ok(class) = token.parse::<TwClass>() else {
+                return true;
+            };
+            let (variant, group) = key(class);
+            if seen.contains(&(variant, group)) {
+                return false;
+            }
+            seen.insert((variant, group));
+            seen.extend(group.overrides().iter().map(|&group| (variant, group)));
+            true
+        })
+        .collect();
+    kept.reverse();
+    kept.join(" ")
+}
+
+/// Merges two class strings; `overrides` wins wherever they conflict
+pub fn tw_merge(base: &str, overrides: &str) -> String {
+    tw_merge_all([base, overrides])
+}
+
+/// Builds a merged class string from a base and conditional fragments
+///
+/// Fragments whose condition is true are merged in order with [`tw_merge_all`],
+/// so later fragments override conflicting utilities from earlier ones.
+///
+/// ```rust
+/// use floem_tailwind::cn;
+///
+/// let (active, disabled) = (true, false);
+/// let classes = cn!("px-4 py-2 bg-blue-500", active => "bg-blue-600", disabled => "opacity-50");
+/// assert_eq!(classes, "px-4 py-2 bg-blue-600");
+/// ```
+#[macro_export]
+macro_rules! cn {
+    ($base:expr $(, $cond:expr => $class:expr)* $(,)?) => {
+        $crate::merge::tw_merge_all(
+            [
+                ::core::option::Option::Some(::core::convert::AsRef::<str>::as_ref(&$base)),
+                $(if $cond {
+                    ::core::option::Option::Some(::core::convert::AsRef::<str>::as_ref(&$class))
+                } else {
+                    ::core::option::Option::None
+                },)*
+            ]
+            .into_iter()
+            .flatten(),
+        )
+    };
+}
+
+/// Builds a class string from a base and conditional fragments, like [`cn!`]
+/// as method calls
+///
+/// ```rust
+/// use floem_tailwind::classes;
+///
+/// let (active, enabled) = (true, false);
+/// let classes = classes()
+///     .base("px-4 py-2 bg-blue-500")
+///     .when(active, "bg-blue-600")
+///     .unless(enabled, "opacity-50");
+/// assert_eq!(classes.build(), "px-4 py-2 bg-blue-600 opacity-50");
+/// let style = classes.to_style();
+/// ```
+pub fn classes<'a>() -> Classes<'a> {
+    Classes::default()
+}
+
+/// A class string being built with [`classes`]
+#[derive(Debug, Clone, Default)]
+pub struct Classes<'a> {
+    fragments: Vec<&'a str>,
+}
+
+impl<'a> Classes<'a> {
+    /// Adds classes unconditionally
+    pub fn base(mut self, classes: &'a str) -> Self {
+        self.fragments.push(classes);
+        self
+    }
+
+    /// Adds classes if `condition` is true
+    pub fn when(self, condition: bool, classes: &'a str) -> Self {
+        if condition { self.base(classes) } else { self }
+    }
+
+    /// Adds classes if `condition` is false
+    pub fn unless(self, condition: bool, classes: &'a str) -> Self {
+        self.when(!condition, classes)
+    }
+
+    /// The merged class string; later fragments override conflicting
+    /// utilities of earlier ones, as with [`tw_merge_all`]
+    pub fn build(&self) -> String {
+        tw_merge_all(self.fragments.iter().copied())
+    }
+
+    /// The merged classes as a style; unknown classes are ignored
+    pub fn to_style(&self) -> Style {
+        Style::new().apply_tw_classes(&intern::parse_classes_lossy(&self.build()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tw_merge() {
+        assert_eq!(tw_merge("p-4 bg-red-500", "p-2"), "bg-red-500 p-2");
+        assert_eq!(
+            tw_merge("bg-red-500 text-sm", "bg-white text-gray-700"),
+            "text-sm bg-white text-gray-700"
+        );
+        assert_eq!(
+            tw_merge("inset-0 overflow-x-auto", "top-2 overflow-hidden"),
+            "inset-0 top-2 overflow-hidden"
+        );
+        assert_eq!(
+            tw_merge("shadow-sm border foo", "elevation-3 foo"),
+            "foo elevation-3 foo"
+        );
+        assert_eq!(tw_merge_all(["w-4 h-4", "size-8", "h-2"]), "size-8 h-2");
+        assert_eq!(
+            tw_merge("rounded-s-lg rounded-e", "rounded-md"),
+            "rounded-md"
+        );
+        assert_eq!(
+            tw_merge(
+                "border contrast-more:border-2",
+                "border-0 contrast-more:border-4"
+            ),
+            "border-0 contrast-more:border-4"
+        );
+        assert_eq!(
+            tw_merge(
+                "bg-blue-500 active:bg-blue-700",
+                "contrast-more:bg-black active:bg-blue-800"
+            ),
+            "bg-blue-500 contrast-more:bg-black active:bg-blue-800"
+        );
+    }
+
+    #[test]
+    fn test_conflicts() {
+        assert_eq!(
+            conflicts("px-2 m-1 p-4 flex-row mt-2 flex-col px-2"),
+            [
+                Conflict {
+                    overridden: "px-2".to_string(),
+                    by: "p-4".to_string()
+                },
+                Conflict {
+                    overridden: "flex-row".to_string(),
+                    by: "flex-col".to_string()
+                },
+            ]
+        );
+        assert!(conflicts("p-4 pt-2 size-8 w-4 foo foo").is_empty());
+    }
+
+    #[test]
+    fn test_cn_macro() {
+        let variant = String::from("rounded-lg");
+        assert_eq!(
+            crate::cn!("p-4 rounded", true => variant, false => "p-8", 1 > 0 => "p-2",),
+            "rounded-lg p-2"
+        );
+        assert_eq!(crate::cn!("p-4"), "p-4");
+    }
+
+    #[test]
+    fn test_classes_builder() {
+        let build = |active, enabled| {
+            classes()
+                .base("px-4 py-2")
+                .when(active, "bg-blue-600")
+                .unless(enabled, "opacity-50 cursor-not-allowed")
+                .base("px-6")
+                .build()
+        };
+        assert_eq!(build(false, true), "py-2 px-6");
+        assert_eq!(
+            build(true, false),
+            "py-2 bg-blue-600 opacity-50 cursor-not-allowed px-6"
+        );
+        assert_eq!(
+            crate::inspect::to_class_string(&classes().base("p-4").when(true, "p-2").to_style()),
+            "p-2"
+        );
+    }
+}
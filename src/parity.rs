@@ -0,0 +1,285 @@
+//! Parity tests against Tailwind's default theme
+//!
+//! `tailwind-defaults.json` holds the relevant sections of Tailwind's resolved
+//! default config (`resolveConfig(defaultConfig).theme`). Every scale step of
+//! this crate is checked against it, so a typo or an upstream change shows up
+//! as a failing test naming the key. To update it, re-export the theme and
+//! keep the same sections.
+
+use floem::style::BoxShadow;
+use floem::unit::PxPct;
+use peniko::Color;
+use serde_json::Value;
+
+use crate::class::{
+    DropShadow, FixedLeading, FontWeight, Leading, NamedSize, Radius, Shadow, TextSize,
+};
+use crate::colors::{Family, SHADES};
+use crate::screen::Breakpoint;
+use crate::shadow;
+use crate::spacing::Spacing;
+use crate::{duration, easing};
+
+/// Root font size the crate's pixel values assume
+const ROOT_PX: f64 = 16.0;
+
+fn theme(section: &str) -> Value {
+    let defaults: Value = serde_json::from_str(include_str!("tailwind-defaults.json"))
+        .expect("tailwind-defaults.json is valid JSON");
+    defaults["theme"][section].clone()
+}
+
+/// Looks up a theme key, mapping the crate's `""` fragment to `DEFAULT`
+fn value<'a>(section: &'a Value, key: &str) -> &'a str {
+    let key = if key.is_empty() { "DEFAULT" } else { key };
+    section[key]
+        .as_str()
+        .unwrap_or_else(|| panic!("no Tailwind default for `{key}`"))
+}
+
+/// Converts a CSS length in `rem` or `px` to pixels
+fn to_px(length: &str) -> f64 {
+    if let Some(rem) = length.strip_suffix("rem") {
+        rem.parse::<f64>().unwrap() * ROOT_PX
+    } else {
+        length.strip_suffix("px").unwrap_or(length).parse().unwrap()
+    }
+}
+
+fn px(value: PxPct) -> f64 {
+    match value {
+        PxPct::Px(px) => px,
+        PxPct::Pct(_) => panic!("expected a pixel value"),
+    }
+}
+
+/// Parses the first layer of a CSS box shadow: offsets, blur, spread, and alpha
+fn first_layer(shadow: &str) -> ([f64; 4], f64) {
+    let (lengths, alpha) = layer(shadow.split("), ").next().unwrap());
+    (lengths.try_into().unwrap(), alpha)
+}
+
+/// The lengths in pixels and the alpha of a single `... rgb(0 0 0 / a)` shadow
+fn layer(layer: &str) -> (Vec<f64>, f64) {
+    let (lengths, color) = layer.split_once("rgb(").unwrap();
+    let lengths: Vec<f64> = lengths.split_whitespace().map(to_px).collect();
+    let alpha = color.trim_end_matches(')').rsplit('/').next().unwrap();
+    (lengths, alpha.trim().parse().unwrap())
+}
+
+#[test]
+fn test_colors_match_tailwind() {
+    let colors = theme("colors");
+    for family in Family::ALL {
+        for shade in SHADES {
+            let hex = value(&colors[family.name()], &shade.to_string());
+            let rgb = u32::from_str_radix(&hex[1..], 16).unwrap();
+            let [_, r, g, b] = rgb.to_be_bytes();
+            assert_eq!(
+                family.shade(shade),
+                Some(Color::from_rgb8(r, g, b)),
+                "{}-{shade}",
+                family.name()
+            );
+        }
+    }
+    let families = colors.as_object().unwrap().values();
+    assert_eq!(
+        families.filter(|shades| shades.is_object()).count(),
+        Family::ALL.len()
+    );
+}
+
+#[test]
+fn test_spacing_matches_tailwind() {
+    let spacing = theme("spacing");
+    for step in Spacing::ALL {
+        assert_eq!(
+            step.to_px(),
+            to_px(value(&spacing, step.name())),
+            "{}",
+            step.name()
+        );
+    }
+    assert_eq!(spacing.as_object().unwrap().len(), Spacing::ALL.len());
+
+    let max_width = theme("maxWidth");
+    for &size in NamedSize::ALL {
+        assert_eq!(
+            size.to_px(),
+            to_px(value(&max_width, size.name())),
+            "{}",
+            size.name()
+        );
+    }
+}
+
+#[test]
+fn test_radii_match_tailwind() {
+    let radii = theme("borderRadius");
+    for &radius in Radius::ALL {
+        assert_eq!(
+            radius.to_px(),
+            to_px(value(&radii, radius.name())),
+            "rounded-{}",
+            radius.name()
+        );
+    }
+}
+
+#[test]
+fn test_typography_matches_tailwind() {
+    let sizes = theme("fontSize");
+    for &size in TextSize::ALL {
+        let expected = sizes[size.name()][0].as_str().unwrap();
+        assert_eq!(size.to_px() as f64, to_px(expected), "text-{}", size.name());
+    }
+
+    let weights = theme("fontWeight");
+    for &weight in FontWeight::ALL {
+        let expected: u16 = value(&weights, weight.name()).parse().unwrap();
+        assert_eq!(weight.to_weight().0, expected, "font-{}", weight.name());
+    }
+
+    let line_heights = theme("lineHeight");
+    for &leading in Leading::ALL {
+        let expected: f32 = value(&line_heights, leading.name()).parse().unwrap();
+        assert_eq!(
+            leading.to_multiplier(),
+            expected,
+            "leading-{}",
+            leading.name()
+        );
+    }
+    for &leading in FixedLeading::ALL {
+        let expected = to_px(value(&line_heights, leading.name()));
+        assert_eq!(leading.to_px(), expected, "leading-{}", leading.name());
+    }
+}
+
+#[test]
+fn test_screens_match_tailwind() {
+    let screens = theme("screens");
+    for bp in Breakpoint::ALL {
+        assert_eq!(
+            bp.min_width(),
+            to_px(value(&screens, bp.name())),
+            "{}",
+            bp.name()
+        );
+    }
+    assert_eq!(screens.as_object().unwrap().len(), Breakpoint::ALL.len());
+}
+
+/// Floem draws one shadow per preset, so only Tailwind's first layer is compared
+#[test]
+fn test_shadows_match_tailwind() {
+    let shadows = theme("boxShadow");
+    for &preset in Shadow::ALL {
+        let shadow: Option<BoxShadow> = match preset {
+            Shadow::Sm => Some(shadow::shadow_sm()),
+            Shadow::Default => Some(shadow::shadow_default()),
+            Shadow::Md => Some(shadow::shadow_md()),
+            Shadow::Lg => Some(shadow::shadow_lg()),
+            Shadow::Xl => Some(shadow::shadow_xl()),
+            Shadow::Xl2 => Some(shadow::shadow_2xl()),
+            Shadow::None => None,
+        };
+        let expected = value(&shadows, preset.name());
+        let Some(shadow) = shadow else {
+            assert_eq!(expected, "none");
+            continue;
+        };
+        let (lengths, alpha) = first_layer(expected);
+        let actual = [
+            shadow.h_offset,
+            shadow.v_offset,
+            shadow.blur_radius,
+            shadow.spread,
+        ];
+        assert_eq!(actual.map(px), lengths, "shadow-{}", preset.name());
+        // Alpha is stored in 8 bits; allow one step of rounding
+        let actual_alpha = shadow.color.to_rgba8().a as f64 / 255.0;
+        assert!(
+            (actual_alpha - alpha).abs() <= 1.0 / 255.0,
+            "shadow-{} alpha",
+            preset.name()
+        );
+    }
+}
+
+#[test]
+fn test_drop_shadows_match_tailwind() {
+    let shadows = theme("dropShadow");
+    for &preset in DropShadow::ALL {
+        let name = if preset.name().is_empty() {
+            "DEFAULT"
+        } else {
+            preset.name()
+        };
+        let layers: Vec<&str> = match &shadows[name] {
+            Value::Array(layers) => layers.iter().map(|l| l.as_str().unwrap()).collect(),
+            layer => vec![layer.as_str().unwrap()],
+        };
+        if preset == DropShadow::None {
+            assert_eq!(layers, ["0 0 #0000"]);
+            assert!(preset.to_shadows().is_empty());
+            continue;
+        }
+        let actual = preset.to_shadows();
+        assert_eq!(actual.len(), layers.len(), "drop-shadow-{name}");
+        for (shadow, expected) in actual.iter().zip(layers) {
+            let (lengths, alpha) = layer(expected);
+            let lengths: [f64; 3] = lengths.try_into().unwrap();
+            let actual = [shadow.h_offset, shadow.v_offset, shadow.blur_radius];
+            assert_eq!(actual.map(px), lengths, "drop-shadow-{name}");
+            assert_eq!(px(shadow.spread), 0.0);
+            let actual_alpha = shadow.color.to_rgba8().a as f64 / 255.0;
+            assert!(
+                (actual_alpha - alpha).abs() <= 1.0 / 255.0,
+                "drop-shadow-{name} alpha"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transitions_match_tailwind() {
+    let durations = theme("transitionDuration");
+    for (key, duration) in [
+        ("0", duration::DURATION_0),
+        ("75", duration::DURATION_75),
+        ("100", duration::DURATION_100),
+        ("150", duration::DURATION_150),
+        ("200", duration::DURATION_200),
+        ("300", duration::DURATION_300),
+        ("500", duration::DURATION_500),
+        ("700", duration::DURATION_700),
+        ("1000", duration::DURATION_1000),
+    ] {
+        let expected = value(&durations, key);
+        let ms = match expected.strip_suffix("ms") {
+            Some(ms) => ms.parse().unwrap(),
+            None => expected.strip_suffix('s').unwrap().parse::<u64>().unwrap() * 1000,
+        };
+        assert_eq!(duration.as_millis() as u64, ms, "duration-{key}");
+    }
+
+    let timing = theme("transitionTimingFunction");
+    for (key, ease) in [
+        ("linear", easing::LINEAR),
+        ("in", easing::EASE_IN),
+        ("out", easing::EASE_OUT),
+        ("in-out", easing::EASE_IN_OUT),
+    ] {
+        let expected = match value(&timing, key) {
+            "linear" => "cubic-bezier(0, 0, 1, 1)".to_string(),
+            curve => curve.to_string(),
+        };
+        let actual = format!(
+            "cubic-bezier({}, {}, {}, {})",
+            ease.0, ease.1, ease.2, ease.3
+        );
+        assert_eq!(actual, expected, "ease-{key}");
+    }
+}
@@ -0,0 +1,870 @@
+//! Ready-made style presets
+//!
+//! Presets are plain functions returning a `Style`, so they can be returned
+//! directly from a style closure or extended further with utility methods.
+//! Each one is built once per thread for its arguments and the current theme,
+//! contrast, rem, density, and DPI settings, so calling it on every restyle
+//! is cheap.
+//!
+//! ```rust
+//! use floem::prelude::*;
+//! use floem_tailwind::presets;
+//!
+//! let placeholder = empty()
+//!     .style(|_| presets::skeleton_size(256.0, 16.0))
+//!     .animation(presets::pulse);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use floem::animate::Animation;
+use floem::easing::Bezier;
+use floem::style::{self as fs, CursorStyle, Style, Transition};
+use floem::unit::{Pct, PxPctAuto};
+use peniko::{Brush, Color};
+
+use crate::colors::{self, Family};
+use crate::{
+    TailwindExt, color_math, contrast, density, dpi, duration, easing, font_size, font_weight,
+    radius, rem, spacing, theme,
+};
+
+/// skeleton: gray rounded placeholder block (bg-gray-200 rounded-md)
+///
+/// Pair with [`pulse`] on the view to get Tailwind's `animate-pulse` effect.
+pub fn skeleton() -> Style {
+    cached(Preset::Skeleton, || {
+        Style::new().background(colors::gray::C200).rounded_md()
+    })
+}
+
+/// skeleton with a fixed width
+pub fn skeleton_w(width: impl Into<PxPctAuto>) -> Style {
+    skeleton().width(width)
+}
+
+/// skeleton with a fixed height
+pub fn skeleton_h(height: impl Into<PxPctAuto>) -> Style {
+    skeleton().height(height)
+}
+
+/// skeleton with a fixed width and height
+pub fn skeleton_size(width: impl Into<PxPctAuto>, height: impl Into<PxPctAuto>) -> Style {
+    skeleton().width(width).height(height)
+}
+
+/// Single line of placeholder text (w-full h-4)
+pub fn skeleton_text() -> Style {
+    cached(Preset::SkeletonText, || {
+        skeleton().width_full().height(crate::spacing::SPACING_4)
+    })
+}
+
+/// Circular placeholder for avatars and icons
+pub fn skeleton_circle(size: f64) -> Style {
+    skeleton().width(size).height(size).rounded_full()
+}
+
+/// cubic-bezier(0.4, 0, 0.6, 1), the timing function of Tailwind's `animate-pulse`
+const PULSE_EASE: Bezier = Bezier(0.4, 0.0, 0.6, 1.0);
+
+/// animate-pulse: opacity 1 → 0.5 → 1 over 2s, repeating forever
+///
+/// Takes and returns an `Animation` so it can be passed straight to a view's
+/// `.animation(...)` method.
+pub fn pulse(animation: Animation) -> Animation {
+    animation
+        .duration(Duration::from_secs(2))
+        .repeat(true)
+        .keyframe(0, |f| f.style(|s| s.opacity_100()).ease(PULSE_EASE))
+        .keyframe(50, |f| f.style(|s| s.opacity_50()).ease(PULSE_EASE))
+        .keyframe(100, |f| f.style(|s| s.opacity_100()).ease(PULSE_EASE))
+}
+
+/// Runs `animation` when the view is created, from `from` to the view's style
+fn enter(animation: Animation, from: impl Fn(Style) -> Style) -> Animation {
+    animation
+        .duration(duration::DURATION_150)
+        .only_on_create()
+        .keyframe(0, |f| f.style(&from))
+        .keyframe(100, |f| f.computed_style().ease(easing::EASE_OUT))
+}
+
+/// Runs `animation` when the view is removed, from the view's style to `to`
+fn exit(animation: Animation, to: impl Fn(Style) -> Style) -> Animation {
+    animation
+        .duration(duration::DURATION_150)
+        .only_on_remove()
+        .keyframe(0, |f| f.computed_style())
+        .keyframe(100, |f| f.style(&to).ease(easing::EASE_IN))
+}
+
+/// The offset of the `slide-*-2` presets
+fn slide_offset() -> f64 {
+    crate::rem::scale(crate::spacing::SPACING_2)
+}
+
+// === Enter/Exit Animations ===
+// The vocabulary of tailwindcss-animate: each preset sets one keyframe of a
+// 150ms mount or unmount animation, so presets of the same direction combine,
+// e.g. `.animation(|a| presets::zoom_in_95(presets::fade_in(a)))`.
+
+/// animate-in fade-in: opacity 0 → 1 when the view is created
+pub fn fade_in(animation: Animation) -> Animation {
+    enter(animation, |s| s.opacity_0())
+}
+
+/// animate-out fade-out: opacity 1 → 0 when the view is removed
+pub fn fade_out(animation: Animation) -> Animation {
+    exit(animation, |s| s.opacity_0())
+}
+
+/// animate-in zoom-in-95: scale 95% → 100% when the view is created
+pub fn zoom_in_95(animation: Animation) -> Animation {
+    enter(animation, |s| s.scale(Pct(95.0)))
+}
+
+/// animate-out zoom-out-95: scale 100% → 95% when the view is removed
+pub fn zoom_out_95(animation: Animation) -> Animation {
+    exit(animation, |s| s.scale(Pct(95.0)))
+}
+
+/// animate-in slide-in-from-top-2: moves down 0.5rem into place when the view is created
+pub fn slide_in_from_top_2(animation: Animation) -> Animation {
+    enter(animation, |s| s.translate_y(-slide_offset()))
+}
+
+/// animate-in slide-in-from-bottom-2: moves up 0.5rem into place when the view is created
+pub fn slide_in_from_bottom_2(animation: Animation) -> Animation {
+    enter(animation, |s| s.translate_y(slide_offset()))
+}
+
+/// animate-in slide-in-from-left-2: moves right 0.5rem into place when the view is created
+pub fn slide_in_from_left_2(animation: Animation) -> Animation {
+    enter(animation, |s| s.translate_x(-slide_offset()))
+}
+
+/// animate-in slide-in-from-right-2: moves left 0.5rem into place when the view is created
+pub fn slide_in_from_right_2(animation: Animation) -> Animation {
+    enter(animation, |s| s.translate_x(slide_offset()))
+}
+
+/// animate-out slide-out-to-top-2: moves up 0.5rem when the view is removed
+pub fn slide_out_to_top_2(animation: Animation) -> Animation {
+    exit(animation, |s| s.translate_y(-slide_offset()))
+}
+
+/// animate-out slide-out-to-bottom-2: moves down 0.5rem when the view is removed
+pub fn slide_out_to_bottom_2(animation: Animation) -> Animation {
+    exit(animation, |s| s.translate_y(slide_offset()))
+}
+
+/// animate-out slide-out-to-left-2: moves left 0.5rem when the view is removed
+pub fn slide_out_to_left_2(animation: Animation) -> Animation {
+    exit(animation, |s| s.translate_x(-slide_offset()))
+}
+
+/// animate-out slide-out-to-right-2: moves right 0.5rem when the view is removed
+pub fn slide_out_to_right_2(animation: Animation) -> Animation {
+    exit(animation, |s| s.translate_x(slide_offset()))
+}
+
+/// Material-style elevation: surface background, shadow, and optional hairline border
+///
+/// | level | background | shadow      | border       |
+/// |-------|------------|-------------|--------------|
+/// | 0     | gray-50    | none        | 1px gray-200 |
+/// | 1     | white      | shadow-sm   | 1px gray-200 |
+/// | 2     | white      | shadow      | 1px gray-100 |
+/// | 3     | white      | shadow-md   | none         |
+/// | 4     | white      | shadow-lg   | none         |
+/// | 5     | white      | shadow-xl   | none         |
+///
+/// Levels above 5 are clamped to 5. Borders follow the
+/// [`contrast`](crate::contrast) mode.
+pub fn elevation(level: u8) -> Style {
+    cached(Preset::Elevation(level.min(5)), || {
+        let s = Style::new();
+        let s = match level {
+            0 => s
+                .background(colors::gray::C50)
+                .shadow_none()
+                .border_1()
+                .border_color(colors::gray::C200),
+            1 => s
+                .background(colors::WHITE)
+                .shadow_sm()
+                .border_1()
+                .border_color(colors::gray::C200),
+            2 => s
+                .background(colors::WHITE)
+                .shadow()
+                .border_1()
+                .border_color(colors::gray::C100),
+            3 => s.background(colors::WHITE).shadow_md().border_0(),
+            4 => s.background(colors::WHITE).shadow_lg().border_0(),
+            _ => s.background(colors::WHITE).shadow_xl().border_0(),
+        };
+        crate::contrast::apply(s)
+    })
+}
+
+/// The hover and active backgrounds for a background, `shift` and twice
+/// `shift` in OKLCH lightness away from it
+///
+/// Light colors darken and dark colors lighten; without a background the
+/// states are translucent black.
+fn interaction_colors(background: Option<Color>, shift: f32) -> (Color, Color) {
+    match background {
+        Some(bg) if contrast::luminance(bg) < 0.2 => (
+            color_math::lighten(bg, shift),
+            color_math::lighten(bg, shift * 2.0),
+        ),
+        Some(bg) => (
+            color_math::darken(bg, shift),
+            color_math::darken(bg, shift * 2.0),
+        ),
+        None => (
+            colors::alpha(colors::BLACK, shift),
+            colors::alpha(colors::BLACK, shift * 2.0),
+        ),
+    }
+}
+
+fn interactive_with(s: Style, shift: f32) -> Style {
+    let background = match s.get(fs::Background) {
+        Some(Brush::Solid(color)) => Some(color),
+        _ => None,
+    };
+    let (hover, active) = interaction_colors(background, shift);
+    s.cursor(CursorStyle::Pointer)
+        .transition_background(Transition::new(duration::DURATION_150, easing::EASE_IN_OUT))
+        .transition_color(Transition::new(duration::DURATION_150, easing::EASE_IN_OUT))
+        .hover(|s| s.background(hover))
+        .active(|s| s.background(active))
+        .focus_ring()
+}
+
+/// Clickable element: pointer cursor, hover and active backgrounds, a
+/// color transition, and a focus ring for keyboard focus (cursor-pointer
+/// transition-colors focus-visible:ring-2)
+///
+/// Chain [`no_focus_ring`](TailwindExt::no_focus_ring) for views that show
+/// focus another way.
+///
+/// The hover and active backgrounds are derived from the background already
+/// set on `s`, so set it first:
+///
+/// ```rust
+/// use floem::prelude::*;
+/// use floem_tailwind::prelude::*;
+/// use floem_tailwind::presets;
+///
+/// let save = button("Save").style(|s| presets::interactive(s.bg_blue_500().text_white()));
+/// ```
+pub fn interactive(s: Style) -> Style {
+    interactive_with(s, 0.05)
+}
+
+/// [`interactive`] with half the background shift, for list rows and ghost buttons
+pub fn interactive_subtle(s: Style) -> Style {
+    interactive_with(s, 0.025)
+}
+
+/// Row of a striped table or list at `index`, counting from 0
+/// (odd:bg-white even:bg-gray-50)
+///
+/// Dark mode stripes gray-900 and gray-800. Works for virtual lists too,
+/// since each row gets its index from the list rather than its position
+/// among the views built so far.
+pub fn striped_row(index: usize) -> Style {
+    cached(Preset::StripedRow(!index.is_multiple_of(2)), || {
+        Style::new()
+            .background(colors::WHITE)
+            .even(index, |s| s.background(colors::gray::C50))
+            .dark(|s| {
+                s.background(colors::gray::C900)
+                    .even(index, |s| s.background(colors::gray::C800))
+            })
+    })
+}
+
+/// How strongly a [`badge`] is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// Tinted background with darker text of the same family
+    #[default]
+    Soft,
+    /// Full-strength background with white text
+    Solid,
+    /// Colored border and text on no background
+    Outline,
+}
+
+/// Small status chip in a palette family
+/// (px-2 py-0.5 rounded-full text-xs font-medium)
+///
+/// | variant | light                         | dark                         |
+/// |---------|-------------------------------|------------------------------|
+/// | soft    | bg-{family}-100 text-800      | bg-{family}-400/10 text-400  |
+/// | solid   | bg-{family}-600 text-white    | bg-{family}-500 text-white   |
+/// | outline | border-{family}-500 text-700  | border-{family}-400 text-400 |
+///
+/// ```rust
+/// use floem::prelude::*;
+/// use floem_tailwind::colors::Family;
+/// use floem_tailwind::presets::{self, Variant};
+///
+/// let status = label(|| "Failed").style(|_| presets::badge(Family::Red, Variant::Soft));
+/// ```
+///
+/// Colors follow the [`contrast`](crate::contrast) mode.
+pub fn badge(family: Family, variant: Variant) -> Style {
+    cached(Preset::Badge(family, variant), || {
+        let shade = |shade| colors::shade(family, shade);
+        let s = Style::new()
+            .padding_horiz(rem::scale(spacing::SPACING_2))
+            .padding_vert(rem::scale(spacing::SPACING_0_5))
+            .rounded_full()
+            .font_size(rem::scale_f32(font_size::TEXT_XS))
+            .font_weight(font_weight::MEDIUM);
+        let s = match variant {
+            Variant::Soft => s.background(shade(100)).color(shade(800)).dark(|s| {
+                s.background(colors::alpha(shade(400), 0.1))
+                    .color(shade(400))
+            }),
+            Variant::Solid => s
+                .background(shade(600))
+                .color(colors::WHITE)
+                .dark(|s| s.background(shade(500))),
+            Variant::Outline => s
+                .border_1()
+                .border_color(shade(500))
+                .color(shade(700))
+                .dark(|s| s.border_color(shade(400)).color(shade(400))),
+        };
+        contrast::apply(s)
+    })
+}
+
+/// How a [`button`] is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ButtonVariant {
+    /// Filled background with white text, for the primary action
+    #[default]
+    Solid,
+    /// Colored border and text
+    Outline,
+    /// Colored text with a background only on hover
+    Ghost,
+    /// Colored text without padding, like a hyperlink
+    Link,
+}
+
+/// The size of a [`button`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Size {
+    /// px-3 py-1.5 text-sm rounded-md
+    Sm,
+    /// px-4 py-2 text-sm rounded-md
+    #[default]
+    Md,
+    /// px-5 py-2.5 text-base rounded-lg
+    Lg,
+}
+
+/// Button in a palette family, with hover, active, focus, and disabled states
+///
+/// | variant | light                                    | dark                                |
+/// |---------|------------------------------------------|-------------------------------------|
+/// | solid   | bg-{family}-600 text-white hover:bg-700  | bg-{family}-500 hover:bg-400        |
+/// | outline | border-{family}-600 text-600 hover:bg-50 | border-400 text-400 hover:bg-400/10 |
+/// | ghost   | text-{family}-600 hover:bg-50            | text-400 hover:bg-400/10            |
+/// | link    | text-{family}-600 hover:text-800         | text-400 hover:text-300             |
+///
+/// Active states are a shade stronger than hover. Every variant has a
+/// focus ring in the family's 500 shade, and disabled buttons are half
+/// transparent with a not-allowed cursor.
+///
+/// ```rust
+/// use floem::prelude::*;
+/// use floem_tailwind::colors::Family;
+/// use floem_tailwind::presets::{self, ButtonVariant, Size};
+///
+/// let save = button("Save")
+///     .style(|_| presets::button(Family::Blue, ButtonVariant::Solid, Size::Md));
+/// let cancel = button("Cancel")
+///     .style(|_| presets::button(Family::Gray, ButtonVariant::Ghost, Size::Md));
+/// ```
+///
+/// Colors follow the [`contrast`](crate::contrast) mode.
+pub fn button(family: Family, variant: ButtonVariant, size: Size) -> Style {
+    cached(Preset::Button(family, variant, size), || {
+        let shade = |shade| colors::shade(family, shade);
+        let tint = |shade: u16| colors::alpha(colors::shade(family, shade), 0.1);
+        let (px, py, text, radius) = match size {
+            Size::Sm => (
+                spacing::SPACING_3,
+                spacing::SPACING_1_5,
+                font_size::TEXT_SM,
+                radius::ROUNDED_MD,
+            ),
+            Size::Md => (
+                spacing::SPACING_4,
+                spacing::SPACING_2,
+                font_size::TEXT_SM,
+                radius::ROUNDED_MD,
+            ),
+            Size::Lg => (
+                spacing::SPACING_5,
+                spacing::SPACING_2_5,
+                font_size::TEXT_BASE,
+                radius::ROUNDED_LG,
+            ),
+        };
+        let s = Style::new()
+            .font_size(rem::scale_f32(text))
+            .font_weight(font_weight::MEDIUM)
+            .border_radius(radius)
+            .cursor(CursorStyle::Pointer)
+            .transition_background(Transition::new(duration::DURATION_150, easing::EASE_IN_OUT))
+            .transition_color(Transition::new(duration::DURATION_150, easing::EASE_IN_OUT));
+        let s = match variant {
+            ButtonVariant::Link => s,
+            _ => s.padding_horiz(rem::scale(px)).padding_vert(rem::scale(py)),
+        };
+        let s = match variant {
+            ButtonVariant::Solid => s
+                .background(shade(600))
+                .color(colors::WHITE)
+                .hover(|s| s.background(shade(700)))
+                .active(|s| s.background(shade(800)))
+                .dark(|s| {
+                    s.background(shade(500))
+                        .hover(|s| s.background(shade(400)))
+                        .active(|s| s.background(shade(300)))
+                }),
+            ButtonVariant::Outline => s
+                .border_1()
+                .border_color(shade(600))
+                .color(shade(600))
+                .hover(|s| s.background(shade(50)))
+                .active(|s| s.background(shade(100)))
+                .dark(|s| {
+                    s.border_color(shade(400))
+                        .color(shade(400))
+                        .hover(|s| s.background(tint(400)))
+                        .active(|s| s.background(tint(300)))
+                }),
+            ButtonVariant::Ghost => s
+                .color(shade(600))
+                .hover(|s| s.background(shade(50)))
+                .active(|s| s.background(shade(100)))
+                .dark(|s| {
+                    s.color(shade(400))
+                        .hover(|s| s.background(tint(400)))
+                        .active(|s| s.background(tint(300)))
+                }),
+            ButtonVariant::Link => s
+                .color(shade(600))
+                .hover(|s| s.color(shade(800)))
+                .active(|s| s.color(shade(900)))
+                .dark(|s| {
+                    s.color(shade(400))
+                        .hover(|s| s.color(shade(300)))
+                        .active(|s| s.color(shade(200)))
+                }),
+        };
+        let s = s
+            .focus_ring_color(shade(500))
+            .disabled(|s| s.opacity_50().cursor(CursorStyle::NotAllowed));
+        contrast::apply(s)
+    })
+}
+
+// === Cards ===
+// A card is a bordered surface whose sections pad themselves, so a header
+// and footer can span its full width with dividers between sections.
+// Floem doesn't clip children to their parent's corners, so the header and
+// footer round their own outer corners to match the card.
+
+/// The corner radius of [`card`] and its sections (rounded-lg)
+const CARD_RADIUS: f64 = radius::ROUNDED_LG;
+
+/// Horizontal padding of card sections (px-6)
+fn card_section() -> Style {
+    Style::new().padding_horiz(rem::scale(spacing::SPACING_6))
+}
+
+/// card: surface for [`card_header`], [`card_body`], and [`card_footer`]
+/// (bg-white border border-gray-200 rounded-lg shadow-sm)
+///
+/// ```rust
+/// use floem::prelude::*;
+/// use floem_tailwind::presets;
+///
+/// let card = v_stack((
+///     label(|| "Team").style(|_| presets::card_header()),
+///     label(|| "Four members").style(|_| presets::card_body()),
+///     button("Invite").style(|_| presets::card_footer()),
+/// ))
+/// .style(|_| presets::card());
+/// ```
+///
+/// Dark mode uses a gray-800 surface; borders follow the
+/// [`contrast`](crate::contrast) mode in all card styles.
+pub fn card() -> Style {
+    cached(Preset::Card, || {
+        let s = Style::new()
+            .background(colors::WHITE)
+            .border_1()
+            .border_color(colors::gray::C200)
+            .border_radius(CARD_RADIUS)
+            .shadow_sm()
+            .dark(|s| {
+                s.background(colors::gray::C800)
+                    .border_color(colors::gray::C700)
+            });
+        contrast::apply(s)
+    })
+}
+
+/// card header: title row above a divider, with the card's top corners
+/// (px-6 py-4 border-b border-gray-200 rounded-t-lg font-semibold)
+pub fn card_header() -> Style {
+    cached(Preset::CardHeader, || {
+        let s = card_section()
+            .padding_vert(rem::scale(spacing::SPACING_4))
+            .border_bottom(dpi::snap(1.0))
+            .border_color(colors::gray::C200)
+            .border_top_left_radius(CARD_RADIUS)
+            .border_top_right_radius(CARD_RADIUS)
+            .font_weight(font_weight::SEMIBOLD)
+            .dark(|s| s.border_color(colors::gray::C700));
+        contrast::apply(s)
+    })
+}
+
+/// card body: the card's content (px-6 py-5)
+pub fn card_body() -> Style {
+    cached(Preset::CardBody, || {
+        card_section().padding_vert(rem::scale(spacing::SPACING_5))
+    })
+}
+
+/// card footer: tinted action row below a divider, with the card's bottom
+/// corners (px-6 py-4 bg-gray-50 border-t border-gray-200 rounded-b-lg)
+pub fn card_footer() -> Style {
+    cached(Preset::CardFooter, || {
+        let s = card_section()
+            .padding_vert(rem::scale(spacing::SPACING_4))
+            .background(colors::gray::C50)
+            .border_top(dpi::snap(1.0))
+            .border_color(colors::gray::C200)
+            .border_bottom_left_radius(CARD_RADIUS)
+            .border_bottom_right_radius(CARD_RADIUS)
+            .dark(|s| {
+                s.background(colors::gray::C900)
+                    .border_color(colors::gray::C700)
+            });
+        contrast::apply(s)
+    })
+}
+
+// === Overlays ===
+// Surfaces that float above the page. Popovers and menus are white, or
+// gray-800 in dark mode; tooltips are dark in both themes. Borders follow the
+// contrast mode.
+
+/// Surface of a floating panel: background, border, rounded corners, shadow-lg
+fn overlay_surface(radius: f64) -> Style {
+    let s = Style::new()
+        .background(colors::WHITE)
+        .border_1()
+        .border_color(colors::gray::C200)
+        .border_radius(radius)
+        .shadow_lg()
+        .dark(|s| {
+            s.background(colors::gray::C800)
+                .border_color(colors::gray::C700)
+        });
+    contrast::apply(s)
+}
+
+/// tooltip: short hint text on a dark surface
+/// (bg-gray-900 text-white text-xs px-2 py-1 rounded-md shadow-lg)
+pub fn tooltip() -> Style {
+    cached(Preset::Tooltip, || {
+        Style::new()
+            .background(colors::gray::C900)
+            .color(colors::WHITE)
+            .font_size(rem::scale_f32(font_size::TEXT_XS))
+            .padding_horiz(rem::scale(spacing::SPACING_2))
+            .padding_vert(rem::scale(spacing::SPACING_1))
+            .rounded_md()
+            .shadow_lg()
+    })
+}
+
+/// popover: panel of content anchored to a control
+/// (bg-white border border-gray-200 rounded-lg shadow-lg p-4)
+pub fn popover() -> Style {
+    cached(Preset::Popover, || {
+        overlay_surface(radius::ROUNDED_LG).padding(rem::scale(spacing::SPACING_4))
+    })
+}
+
+/// menu: list of [`menu_item`]s
+/// (bg-white border border-gray-200 rounded-md shadow-lg py-1 min-w-32)
+pub fn menu() -> Style {
+    cached(Preset::Menu, || {
+        overlay_surface(radius::ROUNDED_MD)
+            .padding_vert(rem::scale(spacing::SPACING_1))
+            .min_width(rem::scale(spacing::SPACING_32))
+    })
+}
+
+/// menu item: row of a [`menu`], highlighted on hover
+/// (px-3 py-1.5 text-sm text-gray-700 cursor-pointer hover:bg-gray-100)
+///
+/// ```rust
+/// use floem::prelude::*;
+/// use floem_tailwind::presets;
+///
+/// let menu = v_stack((
+///     label(|| "Rename").style(|_| presets::menu_item()),
+///     label(|| "Delete").style(|_| presets::menu_item()),
+/// ))
+/// .style(|_| presets::menu());
+/// ```
+pub fn menu_item() -> Style {
+    cached(Preset::MenuItem, || {
+        Style::new()
+            .padding_horiz(rem::scale(spacing::SPACING_3))
+            .padding_vert(rem::scale(spacing::SPACING_1_5))
+            .font_size(rem::scale_f32(font_size::TEXT_SM))
+            .color(colors::gray::C700)
+            .cursor(CursorStyle::Pointer)
+            .hover(|s| s.background(colors::gray::C100).color(colors::gray::C900))
+            .dark(|s| {
+                s.color(colors::gray::C200)
+                    .hover(|s| s.background(colors::gray::C700).color(colors::WHITE))
+            })
+    })
+}
+
+// === Caching ===
+// Presets are called from style closures, which rerun on every restyle, so
+// the ready-made ones are built once for each combination of arguments and
+// of the settings they read, then returned as clones. Cloning a Floem style
+// shares its property map instead of copying it.
+
+/// A cached preset and its arguments
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Preset {
+    Skeleton,
+    SkeletonText,
+    Elevation(u8),
+    /// Whether the row is even
+    StripedRow(bool),
+    Badge(Family, Variant),
+    Button(Family, ButtonVariant, Size),
+    Card,
+    CardHeader,
+    CardBody,
+    CardFooter,
+    Tooltip,
+    Popover,
+    Menu,
+    MenuItem,
+}
+
+impl Preset {
+    /// Whether the preset has [`dark`](TailwindExt::dark) styles
+    fn reads_theme(self) -> bool {
+        !matches!(
+            self,
+            Preset::Skeleton | Preset::SkeletonText | Preset::Elevation(_)
+        )
+    }
+}
+
+/// The settings a preset is built with
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Settings {
+    dark: bool,
+    high_contrast: bool,
+    /// The rem root, as `f64` bits
+    root: u64,
+    /// The density factor, as `f64` bits
+    density: u64,
+    /// The DPI scale factor, as `f64` bits
+    scale_factor: Option<u64>,
+}
+
+impl Settings {
+    /// The current settings for `preset`, subscribing the caller to the
+    /// theme if the preset reads it, as building it would
+    fn current(preset: Preset) -> Self {
+        Settings {
+            dark: preset.reads_theme() && theme::is_dark(),
+            high_contrast: contrast::is_high_contrast(),
+            root: rem::root().to_bits(),
+            density: density::factor().to_bits(),
+            scale_factor: dpi::scale_factor().map(f64::to_bits),
+        }
+    }
+}
+
+thread_local! {
+    static CACHE: RefCell<HashMap<(Preset, Settings), Style>> = RefCell::new(HashMap::new());
+}
+
+/// The style of `preset`, built with `build` the first time it's needed
+/// with the current settings
+fn cached(preset: Preset, build: impl FnOnce() -> Style) -> Style {
+    let key = (preset, Settings::current(preset));
+    if let Some(s) = CACHE.with_borrow(|cache| cache.get(&key).cloned()) {
+        return s;
+    }
+    // Built outside the borrow, since presets build on other presets
+    let s = build();
+    CACHE.with_borrow_mut(|cache| cache.insert(key, s.clone()));
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use peniko::color::Oklch;
+
+    use super::*;
+
+    #[test]
+    fn test_interaction_colors() {
+        let lightness = |color: Color| color.convert::<Oklch>().components[0];
+        let (hover, active) = interaction_colors(Some(colors::blue::C500), 0.05);
+        let base = lightness(colors::blue::C500);
+        assert!(lightness(active) < lightness(hover) && lightness(hover) < base);
+
+        let (hover, _) = interaction_colors(Some(colors::slate::C900), 0.05);
+        assert!(lightness(hover) > lightness(colors::slate::C900));
+
+        let (hover, _) = interaction_colors(None, 0.05);
+        assert_eq!(hover, colors::alpha(colors::BLACK, 0.05));
+    }
+
+    #[test]
+    fn test_striped_row() {
+        use crate::theme::{Theme, with_theme};
+
+        let background =
+            |index, theme| with_theme(theme, || striped_row(index)).get(fs::Background);
+        assert_eq!(
+            background(0, Theme::Light),
+            Some(Brush::Solid(colors::WHITE))
+        );
+        assert_eq!(
+            background(1, Theme::Light),
+            Some(Brush::Solid(colors::gray::C50))
+        );
+        assert_eq!(
+            background(3, Theme::Dark),
+            Some(Brush::Solid(colors::gray::C800))
+        );
+    }
+
+    #[test]
+    fn test_badge() {
+        use crate::theme::{Theme, with_theme};
+
+        let soft = with_theme(Theme::Light, || badge(Family::Green, Variant::Soft));
+        assert_eq!(
+            soft.get(fs::Background),
+            Some(Brush::Solid(colors::green::C100))
+        );
+        assert_eq!(soft.get(fs::TextColor), Some(colors::green::C800));
+        let solid = with_theme(Theme::Dark, || badge(Family::Green, Variant::Solid));
+        assert_eq!(
+            solid.get(fs::Background),
+            Some(Brush::Solid(colors::green::C500))
+        );
+        let outline = with_theme(Theme::Light, || badge(Family::Green, Variant::Outline));
+        assert_eq!(outline.get(fs::Background), None);
+    }
+
+    #[test]
+    fn test_button() {
+        use floem::unit::PxPct;
+
+        use crate::theme::{Theme, with_theme};
+
+        let solid = with_theme(Theme::Light, || {
+            button(Family::Blue, ButtonVariant::Solid, Size::Lg)
+        });
+        assert_eq!(
+            solid.get(fs::Background),
+            Some(Brush::Solid(colors::blue::C600))
+        );
+        assert_eq!(solid.get(fs::PaddingLeft), PxPct::Px(spacing::SPACING_5));
+        let link = with_theme(Theme::Light, || {
+            button(Family::Blue, ButtonVariant::Link, Size::Lg)
+        });
+        assert_eq!(link.get(fs::PaddingLeft), PxPct::Px(0.0));
+        assert_eq!(link.get(fs::TextColor), Some(colors::blue::C600));
+    }
+
+    #[test]
+    fn test_cached_presets_follow_density() {
+        let padding = |factor| {
+            density::with_factor(factor, || {
+                button(Family::Blue, ButtonVariant::Solid, Size::Md).get(fs::PaddingLeft)
+            })
+        };
+        let comfortable = padding(density::COMFORTABLE);
+        assert_ne!(padding(density::COMPACT), comfortable);
+        assert_eq!(padding(density::COMFORTABLE), comfortable);
+    }
+
+    #[test]
+    fn test_card_sections_round_outer_corners() {
+        use floem::unit::PxPct;
+
+        use crate::theme::{Theme, with_theme};
+
+        let (header, footer) = with_theme(Theme::Light, || (card_header(), card_footer()));
+        assert_eq!(header.get(fs::BorderTopLeftRadius), PxPct::Px(CARD_RADIUS));
+        assert_eq!(header.get(fs::BorderBottomLeftRadius), PxPct::Px(0.0));
+        assert_eq!(
+            footer.get(fs::BorderBottomRightRadius),
+            PxPct::Px(CARD_RADIUS)
+        );
+        assert_eq!(footer.get(fs::BorderTopRightRadius), PxPct::Px(0.0));
+    }
+
+    #[test]
+    fn test_cached_presets_follow_settings() {
+        use crate::theme::{Theme, with_theme};
+
+        let background = |theme| with_theme(theme, card).get(fs::Background);
+        assert_eq!(background(Theme::Light), Some(Brush::Solid(colors::WHITE)));
+        assert_eq!(
+            background(Theme::Dark),
+            Some(Brush::Solid(colors::gray::C800))
+        );
+        assert_eq!(background(Theme::Light), Some(Brush::Solid(colors::WHITE)));
+        CACHE.with_borrow(|cache| {
+            assert_eq!(
+                cache
+                    .keys()
+                    .filter(|(preset, _)| *preset == Preset::Card)
+                    .count(),
+                2
+            )
+        });
+    }
+}
@@ -0,0 +1,419 @@
+//! Runtime registry of utilities by class name
+//!
+//! The registry starts with every utility method of the enabled traits (see
+//! [`utilities`](crate::utilities)) and can be extended with project-specific
+//! utilities. The class parser falls back to it for names it doesn't know, so
+//! registered utilities work in class strings, `tw_class!`, and stylesheets:
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::{TwClass, registry};
+//!
+//! registry::register("text-danger", |s| s.color(floem_tailwind::colors::red::C600));
+//!
+//! let class: TwClass = "text-danger".parse().unwrap();
+//! let style = class.apply(Style::new());
+//! assert!(registry::all().iter().any(|utility| utility.name() == "w-4"));
+//! ```
+//!
+//! [`define_utility!`](crate::define_utility) defines a custom utility as an
+//! extension method too, and [`extend_spacing!`](crate::extend_spacing) adds
+//! steps to the spacing scale.
+//!
+//! Style inspectors and visual editors can list [`all`] utilities to offer
+//! them by name.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{LazyLock, RwLock};
+
+use floem::style::Style;
+
+/// A utility known by class name
+#[derive(Clone, Copy)]
+pub struct Utility {
+    name: &'static str,
+    apply: fn(Style) -> Style,
+}
+
+impl Utility {
+    /// The utility's class name
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Applies this utility to a style
+    pub fn apply(self, s: Style) -> Style {
+        (self.apply)(s)
+    }
+}
+
+impl PartialEq for Utility {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Utility {}
+
+impl Hash for Utility {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl fmt::Debug for Utility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Utility").field(&self.name).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Utility {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Utility {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        lookup(&name).ok_or_else(|| serde::de::Error::custom(format!("unknown utility `{name}`")))
+    }
+}
+
+/// Utilities in registration order, indexed by name
+struct Registry {
+    utilities: Vec<Utility>,
+    index: HashMap<&'static str, usize>,
+}
+
+impl Registry {
+    fn insert(&mut self, utility: Utility) -> bool {
+        if self.index.contains_key(utility.name) {
+            return false;
+        }
+        self.index.insert(utility.name, self.utilities.len());
+        self.utilities.push(utility);
+        true
+    }
+}
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(|| {
+    let mut registry = Registry {
+        utilities: Vec::new(),
+        index: HashMap::new(),
+    };
+    for (name, apply) in crate::utilities() {
+        registry.insert(Utility { name, apply });
+    }
+    RwLock::new(registry)
+});
+
+/// Registers a custom utility under a class name
+///
+/// Returns `false`, leaving the registry unchanged, if the name is already a
+/// utility or isn't a single class name (empty or containing whitespace).
+/// Registered names live for the rest of the program.
+pub fn register(name: &str, apply: fn(Style) -> Style) -> bool {
+    if name.is_empty()
+        || name.contains(char::is_whitespace)
+        || crate::class::parse_class(name).is_some()
+    {
+        return false;
+    }
+    let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+    if registry.index.contains_key(name) {
+        return false;
+    }
+    let name = Box::leak(name.to_string().into_boxed_str());
+    let inserted = registry.insert(Utility { name, apply });
+    crate::intern::clear_unknown();
+    inserted
+}
+
+/// Defines custom utilities as functions and extension methods
+///
+/// Each `name => |s| ...` entry defines a `fn name(Style) -> Style` and a
+/// trait `name` with a `name()` method for every [`StyleBuilder`](crate::StyleBuilder).
+/// Importing the name brings both into scope; [`register_utilities!`](crate::register_utilities) makes
+/// them available in class strings under the same name:
+///
+/// ```rust
+/// use floem::style::Style;
+/// use floem_tailwind::prelude::*;
+/// use floem_tailwind::{TwClass, define_utility, register_utilities};
+///
+/// define_utility! {
+///     /// Button padding
+///     pub btn_pad => |s| s.px_4().py_2();
+///     pub card_pad => |s| s.p_6();
+/// }
+///
+/// register_utilities!(btn_pad, card_pad);
+/// let style = Style::new().btn_pad().rounded_md();
+/// let class: TwClass = "btn_pad".parse().unwrap();
+/// ```
+#[macro_export]
+macro_rules! define_utility {
+    ($($(#[$meta:meta])* $vis:vis $name:ident => $apply:expr);+ $(;)?) => {
+        $(
+            $(#[$meta])*
+            $vis fn $name(s: $crate::__floem::style::Style) -> $crate::__floem::style::Style {
+                let apply: fn($crate::__floem::style::Style) -> $crate::__floem::style::Style =
+                    $apply;
+                apply(s)
+            }
+
+            $(#[$meta])*
+            #[allow(non_camel_case_types)]
+            $vis trait $name: $crate::StyleBuilder {
+                $(#[$meta])*
+                fn $name(self) -> Self {
+                    self.map_style($name)
+                }
+            }
+
+            impl<T: $crate::StyleBuilder> $name for T {}
+        )+
+    };
+}
+
+/// Registers utilities defined with [`define_utility!`] under their names
+///
+/// Call once at startup, before parsing class strings that use them. Names
+/// that are already utilities are skipped, as with [`register`].
+#[macro_export]
+macro_rules! register_utilities {
+    ($($name:ident),+ $(,)?) => {
+        $($crate::registry::register(::core::stringify!($name), $name);)+
+    };
+}
+
+/// Adds steps to the spacing scale, like `theme.extend.spacing` in a
+/// Tailwind config
+///
+/// Each entry gives a step, its value in pixels at the 16px
+/// [`rem`](crate::rem) root, and the methods of the step, each with the
+/// spacing utility it sets. A method is named for its utility, as with the
+/// built-in ones: `w_13` for `w-13`, and `p_4p5` for `p-4.5`. The macro
+/// defines a trait with the methods for every
+/// [`StyleBuilder`](crate::StyleBuilder), and a function that registers their
+/// class names; call it once at startup, before parsing class strings that
+/// use them.
+///
+/// ```rust
+/// use floem::style::Style;
+/// use floem_tailwind::{TwClass, extend_spacing};
+///
+/// extend_spacing! {
+///     /// Steps of the brand design system
+///     pub trait BrandSpacing => register_brand_spacing {
+///         13 => 52.0 { w_13: w, h_13: h, gap_13: gap }
+///         4.5 => 18.0 { p_4p5: p, translate_x_4p5: translate_x }
+///     }
+/// }
+///
+/// register_brand_spacing();
+/// let style = Style::new().w_13().p_4p5();
+/// let class: TwClass = "gap-13".parse().unwrap();
+/// ```
+///
+/// The utilities are `w`, `h`, `size`, `min_w`, `max_w`, the padding and
+/// margin ones (`p`, `px`, ..., `ml`), `gap`, `inset`, `top`, `right`,
+/// `bottom`, `left`, `translate_x`, and `translate_y`; any other is a compile
+/// error. Values follow the [`rem`](crate::rem) root and the
+/// [`density`](crate::density) factor like the built-in steps.
+#[macro_export]
+macro_rules! extend_spacing {
+    (
+        $(#[$meta:meta])*
+        $vis:vis trait $trait:ident => $register:ident {
+            $($step:literal => $px:literal { $($method:ident: $utility:ident),+ $(,)? })*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis trait $trait: $crate::StyleBuilder {
+            $($(
+                #[doc = ::core::concat!(
+                    "`", $crate::__spacing_utility!(class $utility), "-", ::core::stringify!($step),
+                    "`: ", ::core::stringify!($px), "px",
+                )]
+                fn $method(self) -> Self {
+                    self.map_style(|s| {
+                        $crate::__spacing_utility!(apply $utility, s, $crate::rem::scale($px))
+                    })
+                }
+            )+)*
+        }
+
+        impl<T: $crate::StyleBuilder> $trait for T {}
+
+        #[doc = ::core::concat!("Registers the class names of [`", ::core::stringify!($trait), "`]")]
+        $vis fn $register() {
+            $($(
+                $crate::registry::register(
+                    ::core::concat!(
+                        $crate::__spacing_utility!(class $utility),
+                        "-",
+                        ::core::stringify!($step),
+                    ),
+                    |s| $crate::__spacing_utility!(apply $utility, s, $crate::rem::scale($px)),
+                );
+            )+)*
+        }
+    };
+}
+
+/// The class prefix and setter of an [`extend_spacing!`] utility
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __spacing_utility {
+    (class w) => { "w" };
+    (class h) => { "h" };
+    (class size) => { "size" };
+    (class min_w) => { "min-w" };
+    (class max_w) => { "max-w" };
+    (class p) => { "p" };
+    (class px) => { "px" };
+    (class py) => { "py" };
+    (class pt) => { "pt" };
+    (class pr) => { "pr" };
+    (class pb) => { "pb" };
+    (class pl) => { "pl" };
+    (class m) => { "m" };
+    (class mx) => { "mx" };
+    (class my) => { "my" };
+    (class mt) => { "mt" };
+    (class mr) => { "mr" };
+    (class mb) => { "mb" };
+    (class ml) => { "ml" };
+    (class gap) => { "gap" };
+    (class inset) => { "inset" };
+    (class top) => { "top" };
+    (class right) => { "right" };
+    (class bottom) => { "bottom" };
+    (class left) => { "left" };
+    (class translate_x) => { "translate-x" };
+    (class translate_y) => { "translate-y" };
+    (apply w, $s:ident, $px:expr) => { $s.width($px) };
+    (apply h, $s:ident, $px:expr) => { $s.height($px) };
+    (apply size, $s:ident, $px:expr) => {{
+        let px = $px;
+        $s.width(px).height(px)
+    }};
+    (apply min_w, $s:ident, $px:expr) => { $s.min_width($px) };
+    (apply max_w, $s:ident, $px:expr) => { $s.max_width($px) };
+    (apply p, $s:ident, $px:expr) => { $s.padding($px) };
+    (apply px, $s:ident, $px:expr) => { $s.padding_horiz($px) };
+    (apply py, $s:ident, $px:expr) => { $s.padding_vert($px) };
+    (apply pt, $s:ident, $px:expr) => { $s.padding_top($px) };
+    (apply pr, $s:ident, $px:expr) => { $s.padding_right($px) };
+    (apply pb, $s:ident, $px:expr) => { $s.padding_bottom($px) };
+    (apply pl, $s:ident, $px:expr) => { $s.padding_left($px) };
+    (apply m, $s:ident, $px:expr) => { $s.margin($px) };
+    (apply mx, $s:ident, $px:expr) => { $s.margin_horiz($px) };
+    (apply my, $s:ident, $px:expr) => { $s.margin_vert($px) };
+    (apply mt, $s:ident, $px:expr) => { $s.margin_top($px) };
+    (apply mr, $s:ident, $px:expr) => { $s.margin_right($px) };
+    (apply mb, $s:ident, $px:expr) => { $s.margin_bottom($px) };
+    (apply ml, $s:ident, $px:expr) => { $s.margin_left($px) };
+    (apply gap, $s:ident, $px:expr) => { $s.gap($px) };
+    (apply inset, $s:ident, $px:expr) => { $s.inset($px) };
+    (apply top, $s:ident, $px:expr) => { $s.inset_top($px) };
+    (apply right, $s:ident, $px:expr) => { $s.inset_right($px) };
+    (apply bottom, $s:ident, $px:expr) => { $s.inset_bottom($px) };
+    (apply left, $s:ident, $px:expr) => { $s.inset_left($px) };
+    (apply translate_x, $s:ident, $px:expr) => { $s.translate_x($px) };
+    (apply translate_y, $s:ident, $px:expr) => { $s.translate_y($px) };
+}
+
+/// Looks up a utility by class name
+pub fn lookup(name: &str) -> Option<Utility> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    let &i = registry.index.get(name)?;
+    Some(registry.utilities[i])
+}
+
+/// Every registered utility: the built-in utility methods, then custom ones
+pub fn all() -> Vec<Utility> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    registry.utilities.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TwClass;
+
+    #[test]
+    fn test_register_custom_utility() {
+        assert!(register("test-card", |s| s.padding(12.0)));
+        assert!(!register("test-card", |s| s));
+        assert!(!register("p-4", |s| s));
+        assert!(!register("two names", |s| s));
+
+        let class: TwClass = "test-card".parse().unwrap();
+        assert_eq!(class, TwClass::Utility(lookup("test-card").unwrap()));
+        assert_eq!(class.to_string(), "test-card");
+        assert!(all().iter().any(|utility| utility.name() == "test-card"));
+        assert_eq!(lookup("p-4").map(Utility::name), Some("p-4"));
+    }
+
+    crate::define_utility! {
+        test_pad => |s| s.padding(6.0);
+    }
+
+    crate::extend_spacing! {
+        trait TestSpacing => register_test_spacing {
+            13 => 52.0 { w_13: w, translate_x_13: translate_x }
+            4.25 => 17.0 { p_4p25: p }
+        }
+    }
+
+    #[test]
+    fn test_extend_spacing() {
+        register_test_spacing();
+        let class: TwClass = "p-4.25".parse().unwrap();
+        let value = |s: Style| s.get(floem::style::PaddingTop);
+        assert_eq!(
+            value(class.apply(Style::new())),
+            value(Style::new().p_4p25())
+        );
+        assert_eq!(
+            value(Style::new().p_4p25()),
+            value(Style::new().padding(17.0))
+        );
+        let translate = |s: Style| s.get(floem::style::TranslateX);
+        assert_eq!(
+            translate(lookup("translate-x-13").unwrap().apply(Style::new())),
+            translate(Style::new().translate_x_13())
+        );
+        assert_eq!(
+            Style::new().w_13().get(floem::style::Width),
+            Style::new().width(52.0).get(floem::style::Width)
+        );
+        let compact = crate::density::with_factor(0.5, || Style::new().w_13());
+        assert_eq!(
+            compact.get(floem::style::Width),
+            Style::new().width(26.0).get(floem::style::Width)
+        );
+    }
+
+    #[test]
+    fn test_define_utility() {
+        crate::register_utilities!(test_pad);
+        let class: TwClass = "test_pad".parse().unwrap();
+        let expected = Style::new().padding(6.0);
+        assert_eq!(
+            class.apply(Style::new()).get(floem::style::PaddingTop),
+            expected.get(floem::style::PaddingTop)
+        );
+        assert_eq!(
+            Style::new().test_pad().get(floem::style::PaddingLeft),
+            expected.get(floem::style::PaddingLeft)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Snapshot tests of the Floem properties each utility sets
+//!
+//! `utilities.snap` lists every utility of [`utilities`](crate::utilities)
+//! with the properties it sets on `Style::new()`, one per line. A change in
+//! how a utility maps to Floem, from an edit here or from a Floem upgrade,
+//! fails the test naming the utilities whose properties changed. After an
+//! intended change, regenerate the file and review its diff:
+//!
+//! ```text
+//! UPDATE_SNAPSHOTS=1 cargo test snapshot
+//! ```
+//!
+//! Floem doesn't list the properties of a style, so the snapshot reads the
+//! ones the utilities use; styles nested under states such as `hover` aren't
+//! included.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use floem::style::{
+    AlignItems, AlignItemsProp, AlignSelf, Background, BorderBottom, BorderBottomColor,
+    BorderBottomLeftRadius, BorderBottomRightRadius, BorderLeft, BorderLeftColor, BorderRight,
+    BorderRightColor, BorderTop, BorderTopColor, BorderTopLeftRadius, BorderTopRightRadius,
+    BoxShadow, BoxShadowProp, ColGap, CursorProp, CursorStyle, Display, DisplayProp, FlexDirection,
+    FlexDirectionProp, FlexWrap, FlexWrapProp, FontSize, FontStretch, FontWeight, Height,
+    InsetBottom, InsetLeft, InsetRight, InsetTop, IsFixed, JustifyContent, JustifyContentProp,
+    LineHeight, LineHeightValue, MarginBottom, MarginLeft, MarginRight, MarginTop, MaxHeight,
+    MaxWidth, MinHeight, MinWidth, Opacity, Outline, OverflowX, OverflowY, PaddingBottom,
+    PaddingLeft, PaddingRight, PaddingTop, PointerEvents, PointerEventsProp, Position,
+    PositionProp, RowGap, StrokeWrap, Style, StyleProp, StyleValue, TextColor, TranslateX,
+    TranslateY, Width, ZIndex,
+};
+use floem::taffy::Overflow;
+use floem::text::{Stretch, Weight};
+use floem::unit::{Pct, Px, PxPct, PxPctAuto};
+use peniko::{Brush, Color};
+
+use crate::{FocusRingColor, OutlineOffset};
+
+/// A property value as written in the snapshot
+trait Snap {
+    fn snap(&self) -> String;
+}
+
+/// Implements [`Snap`] with the `Debug` format, for types whose `Debug`
+/// output is stable
+macro_rules! snap_debug {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Snap for $ty {
+                fn snap(&self) -> String {
+                    format!("{self:?}")
+                }
+            }
+        )*
+    };
+}
+
+snap_debug!(
+    bool,
+    f32,
+    f64,
+    i32,
+    Pct,
+    Px,
+    PxPct,
+    PxPctAuto,
+    Display,
+    FlexDirection,
+    FlexWrap,
+    Position,
+    Overflow,
+    AlignItems,
+    JustifyContent,
+    CursorStyle,
+    PointerEvents,
+    Stretch,
+    LineHeightValue,
+);
+
+impl<T: Snap> Snap for Option<T> {
+    fn snap(&self) -> String {
+        self.as_ref().map_or_else(|| "None".to_string(), Snap::snap)
+    }
+}
+
+impl Snap for Color {
+    fn snap(&self) -> String {
+        let [r, g, b, a] = self.to_rgba8().to_u8_array();
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+impl Snap for Brush {
+    fn snap(&self) -> String {
+        match self {
+            Brush::Solid(color) => color.snap(),
+            other => format!("{other:?}"),
+        }
+    }
+}
+
+/// Only the width: the rest of Floem's stroke is never set by utilities
+impl Snap for StrokeWrap {
+    fn snap(&self) -> String {
+        self.0.width.snap()
+    }
+}
+
+impl Snap for Weight {
+    fn snap(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Snap for BoxShadow {
+    fn snap(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.h_offset.snap(),
+            self.v_offset.snap(),
+            self.blur_radius.snap(),
+            self.spread.snap(),
+            self.color.snap()
+        )
+    }
+}
+
+/// `name=value` for a prop set on the style itself
+fn prop<P: StyleProp>(s: &Style, name: &str, prop: P) -> Option<String>
+where
+    P::Type: Snap,
+{
+    match s.get_style_value(prop) {
+        StyleValue::Val(value) | StyleValue::Animated(value) => {
+            Some(format!("{name}={}", value.snap()))
+        }
+        StyleValue::Unset | StyleValue::Base => None,
+    }
+}
+
+/// The set props of a style, in the order listed
+macro_rules! props {
+    ($s:expr; $($prop:ident),* $(,)?) => {
+        [$(prop($s, stringify!($prop), $prop)),*]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+    };
+}
+
+/// The snapshot line of a utility
+fn line(class: &str, s: &Style) -> String {
+    let mut props = props!(s;
+        Width, Height, MinWidth, MinHeight, MaxWidth, MaxHeight,
+        PaddingTop, PaddingRight, PaddingBottom, PaddingLeft,
+        MarginTop, MarginRight, MarginBottom, MarginLeft,
+        RowGap, ColGap,
+        InsetTop, InsetRight, InsetBottom, InsetLeft,
+        DisplayProp, FlexDirectionProp, FlexWrapProp, PositionProp, IsFixed,
+        AlignItemsProp, AlignSelf, JustifyContentProp, OverflowX, OverflowY, ZIndex,
+        BorderTop, BorderRight, BorderBottom, BorderLeft,
+        BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor,
+        BorderTopLeftRadius, BorderTopRightRadius, BorderBottomRightRadius, BorderBottomLeftRadius,
+        Outline, OutlineOffset, FocusRingColor,
+        Background, TextColor, Opacity,
+        FontSize, FontWeight, FontStretch, LineHeight,
+        CursorProp, PointerEventsProp, TranslateX, TranslateY,
+    );
+    if let StyleValue::Val(shadows) = s.get_style_value(BoxShadowProp) {
+        let shadows: Vec<_> = shadows.iter().map(Snap::snap).collect();
+        props.push(format!("BoxShadowProp=[{}]", shadows.join(", ")));
+    }
+    props.insert(0, format!("{class}:"));
+    props.join(" ")
+}
+
+/// Needs every utility group, so the snapshot matches the default features
+#[cfg(all(
+    feature = "spacing",
+    feature = "color",
+    feature = "typography",
+    feature = "layout"
+))]
+#[test]
+fn test_utilities_snapshot() {
+    let actual: Vec<String> = crate::utilities()
+        .map(|(class, apply)| line(class, &apply(Style::new())))
+        .collect();
+    let path = Path::new(file!()).with_file_name("utilities.snap");
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, actual.join("\n") + "\n").unwrap();
+        return;
+    }
+    let expected: HashSet<&str> = include_str!("utilities.snap").lines().collect();
+    let actual_lines: HashSet<&str> = actual.iter().map(String::as_str).collect();
+    let mut changed: Vec<&str> = actual_lines.difference(&expected).copied().collect();
+    let mut removed: Vec<&str> = expected.difference(&actual_lines).copied().collect();
+    changed.sort_unstable();
+    removed.sort_unstable();
+    assert!(
+        changed.is_empty() && removed.is_empty(),
+        "utilities differ from {}; rerun with UPDATE_SNAPSHOTS=1 if intended\n\
+         new or changed:\n  {}\nexpected:\n  {}",
+        path.display(),
+        changed.join("\n  "),
+        removed.join("\n  "),
+    );
+}
@@ -0,0 +1,209 @@
+//! Semantic status colors
+//!
+//! [`Status`] names the palette family of each kind of status UI, so badges,
+//! alerts, and validation messages pick their colors by meaning rather than by
+//! hue. The default families are red, amber, green, and blue; the
+//! colorblind-safe palette replaces green with blue for success, keeps red
+//! and a yellow warning, and makes info a neutral slate:
+//!
+//! | status  | standard | colorblind-safe |
+//! |---------|----------|-----------------|
+//! | danger  | red      | red             |
+//! | warning | amber    | yellow          |
+//! | success | green    | blue            |
+//! | info    | blue     | slate           |
+//!
+//! At shades 500 and 600, the solid badge and text shades, every pair of
+//! colorblind-safe colors stays apart under simulated protanopia,
+//! deuteranopia, and tritanopia. Lighter and darker shades come closer, so
+//! pair them with a label or icon.
+//!
+//! ```rust
+//! use floem::style::Style;
+//! use floem_tailwind::prelude::*;
+//! use floem_tailwind::status::{self, Status, StatusPalette};
+//!
+//! status::set_palette(StatusPalette::ColorblindSafe);
+//! let alert = Style::new()
+//!     .bg(Status::Danger.shade(50))
+//!     .text(Status::Danger.shade(800));
+//! ```
+//!
+//! The palette is kept by the [`theme`](crate::theme) layer in a signal, so
+//! styles that read status colors rerun when it's switched.
+
+use peniko::Color;
+
+use crate::colors::Family;
+use crate::theme;
+
+/// A kind of status UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    Danger,
+    Warning,
+    Success,
+    Info,
+}
+
+/// The families used for status colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusPalette {
+    /// Red, amber, green, and blue
+    #[default]
+    Standard,
+    /// Red, yellow, blue, and slate
+    ColorblindSafe,
+}
+
+/// Selects the palette for status colors, see
+/// [`theme::set_status_palette`]
+pub fn set_palette(palette: StatusPalette) {
+    theme::set_status_palette(palette);
+}
+
+/// The selected status palette, subscribing the caller to changes
+pub fn palette() -> StatusPalette {
+    theme::status_palette()
+}
+
+impl Status {
+    /// Every status
+    pub const ALL: [Status; 4] = [
+        Status::Danger,
+        Status::Warning,
+        Status::Success,
+        Status::Info,
+    ];
+
+    /// The status's name (e.g. `"danger"`)
+    pub const fn name(self) -> &'static str {
+        match self {
+            Status::Danger => "danger",
+            Status::Warning => "warning",
+            Status::Success => "success",
+            Status::Info => "info",
+        }
+    }
+
+    /// The status's family in `palette`
+    pub const fn family_in(self, palette: StatusPalette) -> Family {
+        match (palette, self) {
+            (StatusPalette::Standard, Status::Danger) => Family::Red,
+            (StatusPalette::Standard, Status::Warning) => Family::Amber,
+            (StatusPalette::Standard, Status::Success) => Family::Green,
+            (StatusPalette::Standard, Status::Info) => Family::Blue,
+            (StatusPalette::ColorblindSafe, Status::Danger) => Family::Red,
+            (StatusPalette::ColorblindSafe, Status::Warning) => Family::Yellow,
+            (StatusPalette::ColorblindSafe, Status::Success) => Family::Blue,
+            (StatusPalette::ColorblindSafe, Status::Info) => Family::Slate,
+        }
+    }
+
+    /// The status's family in the selected palette
+    pub fn family(self) -> Family {
+        self.family_in(palette())
+    }
+
+    /// A shade (50, 100, ... 950) of the status's family in the selected palette
+    ///
+    /// Other shades fall back to 500.
+    pub fn shade(self, shade: u16) -> Color {
+        let family = self.family();
+        family
+            .shade(shade)
+            .or_else(|| family.shade(500))
+            .expect("every family has shade 500")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use peniko::color::{AlphaColor, LinearSrgb, Oklab};
+
+    use super::*;
+
+    /// Color vision deficiency simulations (Machado et al. 2009, full
+    /// severity), applied in linear sRGB
+    const SIMULATIONS: [(&str, [[f32; 3]; 3]); 3] = [
+        (
+            "protanopia",
+            [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+        ),
+        (
+            "deuteranopia",
+            [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+        ),
+        (
+            "tritanopia",
+            [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        ),
+    ];
+
+    /// `color` as seen through the simulation `m`
+    fn simulate(color: Color, m: [[f32; 3]; 3]) -> AlphaColor<Oklab> {
+        let [r, g, b, alpha] = color.convert::<LinearSrgb>().components;
+        let [r, g, b] = m.map(|row| (row[0] * r + row[1] * g + row[2] * b).clamp(0.0, 1.0));
+        AlphaColor::<LinearSrgb>::new([r, g, b, alpha]).convert::<Oklab>()
+    }
+
+    #[test]
+    fn test_colorblind_safe_distance() {
+        for (name, m) in SIMULATIONS {
+            for shade in [500, 600] {
+                let colors = Status::ALL.map(|status| {
+                    let family = status.family_in(StatusPalette::ColorblindSafe);
+                    simulate(family.shade(shade).unwrap(), m)
+                });
+                for (i, a) in colors.iter().enumerate() {
+                    for (j, b) in colors.iter().enumerate().skip(i + 1) {
+                        let [l, x, y, _] = a.components;
+                        let [l2, x2, y2, _] = b.components;
+                        let distance =
+                            ((l - l2).powi(2) + (x - x2).powi(2) + (y - y2).powi(2)).sqrt();
+                        assert!(
+                            distance > 0.09,
+                            "{:?} and {:?} at {shade} with {name}: {distance}",
+                            Status::ALL[i],
+                            Status::ALL[j]
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_palette_signal() {
+        set_palette(StatusPalette::ColorblindSafe);
+        assert_eq!(Status::Success.family(), Family::Blue);
+        set_palette(StatusPalette::Standard);
+        assert_eq!(Status::Success.family(), Family::Green);
+    }
+
+    #[test]
+    fn test_palettes_are_distinct() {
+        for palette in [StatusPalette::Standard, StatusPalette::ColorblindSafe] {
+            let families = Status::ALL.map(|status| status.family_in(palette));
+            for (i, family) in families.iter().enumerate() {
+                assert!(!families[i + 1..].contains(family), "{palette:?}");
+            }
+        }
+        let safe = Status::ALL.map(|status| status.family_in(StatusPalette::ColorblindSafe));
+        assert!(!safe.contains(&Family::Red) && !safe.contains(&Family::Green));
+    }
+}
@@ -0,0 +1,281 @@
+//! App-level stylesheets
+//!
+//! A [`StyleSheet`] names bundles of utilities, so views refer to their role
+//! (`card.header`) and the classes behind it live in one place. Names are
+//! free-form; dots group related entries, and nested tables in a TOML or JSON
+//! sheet flatten to dotted names:
+//!
+//! ```toml
+//! button = "px-4 py-2 rounded-md font-medium"
+//!
+//! [card]
+//! body = "p-6 bg-white"
+//! header = "px-6 py-4 border-b border-gray-200 font-semibold"
+//! ```
+//!
+//! A sheet can be used directly, or set as the app's stylesheet at startup
+//! and looked up with [`style_of`]. Style closures that look up the app's
+//! stylesheet re-run when it's replaced or updated:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::StyleSheet;
+//! use floem_tailwind::stylesheet::{self, style_of};
+//!
+//! let mut sheet = StyleSheet::new();
+//! sheet.insert("card.header", "px-6 py-4 font-semibold").unwrap();
+//! stylesheet::set_stylesheet(sheet);
+//!
+//! let header = label(|| "Settings").style(|s| s.apply(style_of("card.header")));
+//! // Later, e.g. when the user picks another theme
+//! stylesheet::update_stylesheet(|sheet| {
+//!     sheet.insert("card.header", "px-4 py-2 font-bold").unwrap();
+//! });
+//! ```
+//!
+//! A stylesheet doesn't watch its file; reload one by setting it again, or
+//! use [`hot_reload`](crate::hot_reload) in development.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use floem::reactive::{RwSignal, Scope, SignalUpdate, SignalWith};
+use floem::style::Style;
+
+use crate::class::{self, ParseClassError, TwClass};
+
+/// Error returned when a stylesheet fails to load
+#[derive(Debug)]
+pub enum StyleSheetError {
+    /// The file couldn't be read
+    Io(std::io::Error),
+    /// The source isn't a TOML table of strings and tables
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// The source isn't a JSON object of strings and objects
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// A class string contains an unknown utility
+    Class {
+        /// The stylesheet entry containing the class
+        name: String,
+        error: ParseClassError,
+    },
+}
+
+impl fmt::Display for StyleSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StyleSheetError::Io(error) => write!(f, "failed to read stylesheet: {error}"),
+            #[cfg(feature = "toml")]
+            StyleSheetError::Toml(error) => write!(f, "invalid stylesheet: {error}"),
+            #[cfg(feature = "json")]
+            StyleSheetError::Json(error) => write!(f, "invalid stylesheet: {error}"),
+            StyleSheetError::Class { name, error } => write!(f, "in `{name}`: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for StyleSheetError {}
+
+/// An entry of a stylesheet source: classes or a group of entries
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    Classes(String),
+    Group(BTreeMap<String, Entry>),
+}
+
+/// Named utility bundles
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StyleSheet {
+    entries: BTreeMap<String, Vec<TwClass>>,
+}
+
+impl StyleSheet {
+    /// An empty stylesheet
+    pub fn new() -> Self {
+        StyleSheet::default()
+    }
+
+    /// Parses a stylesheet from TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, StyleSheetError> {
+        let entries = toml::from_str(source).map_err(StyleSheetError::Toml)?;
+        StyleSheet::from_entries(entries)
+    }
+
+    /// Reads and parses a TOML stylesheet file
+    #[cfg(feature = "toml")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, StyleSheetError> {
+        let source = std::fs::read_to_string(path).map_err(StyleSheetError::Io)?;
+        StyleSheet::from_toml(&source)
+    }
+
+    /// Parses a stylesheet from JSON
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<Self, StyleSheetError> {
+        let entries = serde_json::from_str(source).map_err(StyleSheetError::Json)?;
+        StyleSheet::from_entries(entries)
+    }
+
+    #[cfg(feature = "serde")]
+    fn from_entries(entries: BTreeMap<String, Entry>) -> Result<Self, StyleSheetError> {
+        fn flatten(
+            sheet: &mut StyleSheet,
+            prefix: &str,
+            entries: BTreeMap<String, Entry>,
+        ) -> Result<(), StyleSheetError> {
+            for (key, entry) in entries {
+                let name = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                match entry {
+                    Entry::Classes(classes) => {
+                        if let Err(error) = sheet.insert(name.clone(), &classes) {
+                            return Err(StyleSheetError::Class { name, error });
+                        }
+                    }
+                    Entry::Group(entries) => flatten(sheet, &name, entries)?,
+                }
+            }
+            Ok(())
+        }
+        let mut sheet = StyleSheet::new();
+        flatten(&mut sheet, "", entries)?;
+        Ok(sheet)
+    }
+
+    /// Sets a named entry, replacing any previous one
+    ///
+    /// On error the stylesheet is unchanged.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        classes: &str,
+    ) -> Result<(), ParseClassError> {
+        let classes = class::parse_classes(classes)?;
+        self.entries.insert(name.into(), classes);
+        Ok(())
+    }
+
+    /// Removes a named entry, returning its utilities
+    pub fn remove(&mut self, name: &str) -> Option<Vec<TwClass>> {
+        self.entries.remove(name)
+    }
+
+    /// Adds the entries of `other`, replacing entries of the same name
+    pub fn extend(&mut self, other: StyleSheet) {
+        self.entries.extend(other.entries);
+    }
+
+    /// The utilities of a named entry
+    pub fn get(&self, name: &str) -> Option<&[TwClass]> {
+        self.entries.get(name).map(Vec::as_slice)
+    }
+
+    /// The entry names, in order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Applies a named entry to a style; unknown names leave it unchanged
+    pub fn apply(&self, s: Style, name: &str) -> Style {
+        self.get(name)
+            .unwrap_or_default()
+            .iter()
+            .fold(s, |s, class| class.apply(s))
+    }
+
+    /// The style of a named entry; empty for unknown names
+    pub fn style_of(&self, name: &str) -> Style {
+        self.apply(Style::new(), name)
+    }
+}
+
+thread_local! {
+    /// Created in its own root scope so no view's disposal drops it
+    static APP: RwSignal<StyleSheet> = Scope::new().create_rw_signal(StyleSheet::new());
+}
+
+/// Replaces the app's stylesheet
+pub fn set_stylesheet(sheet: StyleSheet) {
+    APP.with(|app| app.set(sheet));
+}
+
+/// Edits the app's stylesheet in place
+pub fn update_stylesheet(f: impl FnOnce(&mut StyleSheet)) {
+    APP.with(|app| app.update(f));
+}
+
+/// Reads the app's stylesheet, subscribing the caller to changes
+pub fn with_stylesheet<O>(f: impl FnOnce(&StyleSheet) -> O) -> O {
+    APP.with(|app| app.with(f))
+}
+
+/// The style of a named entry of the app's stylesheet, subscribing the
+/// caller to changes; empty for unknown names
+pub fn style_of(name: &str) -> Style {
+    with_stylesheet(|sheet| sheet.style_of(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspect::to_class_string;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_style_sheet() {
+        let mut sheet = StyleSheet::new();
+        sheet
+            .insert("card.header", "px-6 py-4 font-semibold")
+            .unwrap();
+        sheet.insert("card.body", "p-6").unwrap();
+        assert!(sheet.insert("card.body", "p-6 bogus").is_err());
+        assert_eq!(
+            sheet.names().collect::<Vec<_>>(),
+            ["card.body", "card.header"]
+        );
+        assert_eq!(to_class_string(&sheet.style_of("card.body")), "p-6");
+        assert_eq!(
+            to_class_string(&sheet.apply(Style::new().w_4(), "card.header")),
+            "w-4 px-6 py-4 font-semibold"
+        );
+        assert!(sheet.get("card").is_none());
+        assert_eq!(to_class_string(&sheet.style_of("missing")), "");
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_style_sheet_from_toml() {
+        let sheet = StyleSheet::from_toml(
+            r#"
+            button = "px-4 rounded"
+
+            [card]
+            header = "px-6 py-4"
+            footer.actions = "flex gap-2"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            sheet.names().collect::<Vec<_>>(),
+            ["button", "card.footer.actions", "card.header"]
+        );
+        assert!(matches!(
+            StyleSheet::from_toml("[card]\nheader = \"px-nope\""),
+            Err(StyleSheetError::Class { name, .. }) if name == "card.header"
+        ));
+
+        let path = std::env::temp_dir().join("floem-tailwind-test-stylesheet.toml");
+        std::fs::write(&path, "[card]\nheader = \"px-6\"").unwrap();
+        let loaded = StyleSheet::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap().names().collect::<Vec<_>>(), ["card.header"]);
+        assert!(matches!(StyleSheet::load(&path), Err(StyleSheetError::Io(_))));
+    }
+}
@@ -0,0 +1,324 @@
+//! Light and dark mode
+//!
+//! The theme decides whether [`dark`](crate::TailwindExt::dark) styles, the
+//! counterpart of Tailwind's `dark:` prefix, apply. It is light by default,
+//! can be fixed to light or dark, or can follow the OS preference, which is
+//! tracked from the root view of the window:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::prelude::*;
+//! use floem_tailwind::theme::{self, Theme};
+//!
+//! theme::set_theme(Theme::follow_system());
+//! let app = v_stack((label(|| "Inbox"), label(|| "Archive")))
+//!     .style(|s| s.bg_white().dark(|s| s.bg_gray_900()))
+//!     .track_system_theme();
+//! ```
+//!
+//! The theme is kept in a signal, so styles that read it rerun when it is set
+//! or, when following the system, when the OS switches between light and
+//! dark.
+//!
+//! [`themed`] overrides the theme for a subtree, e.g. a dark terminal pane in
+//! a light app:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::prelude::*;
+//! use floem_tailwind::theme::{Theme, themed};
+//!
+//! let terminal = themed(Theme::Dark, || {
+//!     label(|| "$ cargo build").tw_style(|s| s.text_gray_900().dark(|s| s.text_gray_100()))
+//! });
+//! ```
+//!
+//! [`TailwindViewExt`](crate::TailwindViewExt) methods follow the subtree's
+//! theme; style closures written by hand can use [`with_theme`].
+//!
+//! The theme also picks the palette values: Tailwind v3's by default, or
+//! v4's, so an app can move to v4 one screen at a time or compare the two
+//! live:
+//!
+//! ```rust,no_run
+//! use floem_tailwind::theme::{self, PaletteVersion};
+//!
+//! theme::set_palette_version(PaletteVersion::V4);
+//! ```
+//!
+//! With v4, the top-level background, text, border, and outline colors that
+//! are v3 palette shades become their v4 counterparts in the
+//! [`TailwindViewExt::tw`](crate::TailwindViewExt::tw) and
+//! [`TailwindViewExt::tw_style`](crate::TailwindViewExt::tw_style) styles and
+//! the [`tw`](crate::tw) classes only; [`to_palette`] converts other styles.
+//! Colors nested under `hover`, `active`, `focus_visible`, and other
+//! selectors keep their v3 values; [`dark`](crate::TailwindExt::dark) styles
+//! are applied at the top level, so they're converted.
+//!
+//! The theme also holds the [`status`](crate::status) palette, so status UI
+//! can switch to colorblind-safe colors live:
+//!
+//! ```rust,no_run
+//! use floem_tailwind::status::StatusPalette;
+//! use floem_tailwind::theme;
+//!
+//! theme::set_status_palette(StatusPalette::ColorblindSafe);
+//! ```
+
+use std::cell::Cell;
+
+use floem::reactive::{RwSignal, Scope, SignalGet, SignalUpdate, provide_context, use_context};
+use floem::style::{self as fs, Style, StyleProp, StyleValue};
+use peniko::Brush;
+
+use crate::colors;
+use crate::status::StatusPalette;
+
+/// Whether the app is light, dark, or follows the OS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Theme {
+    /// Always light
+    #[default]
+    Light,
+    /// Always dark
+    Dark,
+    /// Light or dark with the OS preference
+    System,
+}
+
+impl Theme {
+    /// The theme that follows the OS preference, [`Theme::System`]
+    pub const fn follow_system() -> Self {
+        Theme::System
+    }
+}
+
+thread_local! {
+    /// Created in its own root scope so no view's disposal drops it
+    static THEME: RwSignal<Theme> = Scope::new().create_rw_signal(Theme::Light);
+    /// The OS preference, as last reported to a tracking view
+    static SYSTEM_DARK: RwSignal<bool> = Scope::new().create_rw_signal(false);
+    /// The theme of the [`with_theme`] call being run, if any
+    static SCOPED: Cell<Option<Theme>> = const { Cell::new(None) };
+    static PALETTE: RwSignal<PaletteVersion> = Scope::new().create_rw_signal(PaletteVersion::V3);
+    /// The version of the [`with_palette_version`] call being run, if any
+    static SCOPED_PALETTE: Cell<Option<PaletteVersion>> = const { Cell::new(None) };
+    static STATUS_PALETTE: RwSignal<StatusPalette> =
+        Scope::new().create_rw_signal(StatusPalette::Standard);
+}
+
+/// Sets the app's theme
+pub fn set_theme(theme: Theme) {
+    THEME.with(|signal| signal.set(theme));
+}
+
+/// The app's theme, subscribing the caller to changes
+pub fn theme() -> Theme {
+    THEME.with(|signal| signal.get())
+}
+
+/// Whether the OS prefers dark mode, subscribing the caller to changes
+///
+/// Light until a view tracking the system theme receives the OS preference;
+/// see [`TailwindViewExt::track_system_theme`](crate::TailwindViewExt::track_system_theme).
+pub fn system_is_dark() -> bool {
+    SYSTEM_DARK.with(|signal| signal.get())
+}
+
+/// Whether `theme` is dark, subscribing the caller to the OS preference for
+/// [`Theme::System`]
+fn resolve(theme: Theme) -> bool {
+    match theme {
+        Theme::Light => false,
+        Theme::Dark => true,
+        Theme::System => system_is_dark(),
+    }
+}
+
+/// Whether dark mode is in effect, subscribing the caller to changes: the
+/// [`with_theme`] theme, or the app's
+pub fn is_dark() -> bool {
+    resolve(SCOPED.get().unwrap_or_else(theme))
+}
+
+/// Runs `f` with `theme` in effect, e.g. to build a style for a subtree
+pub fn with_theme<R>(theme: Theme, f: impl FnOnce() -> R) -> R {
+    /// Restores the outer theme, even if `f` panics
+    struct Restore(Option<Theme>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.set(self.0);
+        }
+    }
+    let _restore = Restore(SCOPED.replace(Some(theme)));
+    f()
+}
+
+/// The Tailwind release whose palette values colors take
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PaletteVersion {
+    /// Tailwind v3's palette, the values of the [`colors`] constants
+    #[default]
+    V3,
+    /// Tailwind v4's palette, see [`colors::v4_shade`]
+    V4,
+}
+
+/// Sets the app's palette version
+pub fn set_palette_version(version: PaletteVersion) {
+    PALETTE.with(|signal| signal.set(version));
+}
+
+/// The palette version in effect, subscribing the caller to changes: the
+/// [`with_palette_version`] version, or the app's
+pub fn palette_version() -> PaletteVersion {
+    SCOPED_PALETTE
+        .get()
+        .unwrap_or_else(|| PALETTE.with(|signal| signal.get()))
+}
+
+/// Runs `f` with `version` in effect
+pub fn with_palette_version<R>(version: PaletteVersion, f: impl FnOnce() -> R) -> R {
+    /// Restores the outer version, even if `f` panics
+    struct Restore(Option<PaletteVersion>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_PALETTE.set(self.0);
+        }
+    }
+    let _restore = Restore(SCOPED_PALETTE.replace(Some(version)));
+    f()
+}
+
+/// Sets the app's [`status`](crate::status) palette
+pub fn set_status_palette(palette: StatusPalette) {
+    STATUS_PALETTE.with(|signal| signal.set(palette));
+}
+
+/// The app's status palette, subscribing the caller to changes
+pub fn status_palette() -> StatusPalette {
+    STATUS_PALETTE.with(|signal| signal.get())
+}
+
+fn map_brush<P: StyleProp<Type = Option<Brush>>>(s: Style, prop: P) -> Style {
+    match s.get_style_value(prop) {
+        StyleValue::Val(Some(Brush::Solid(color))) => {
+            s.set(prop, Some(Brush::Solid(colors::to_v4(color))))
+        }
+        _ => s,
+    }
+}
+
+/// Converts the top-level palette colors set on `s` to `version`'s values
+///
+/// Styles are written with v3 colors, so [`PaletteVersion::V3`] leaves `s`
+/// unchanged. Colors in nested styles, such as those of `hover`, `active`,
+/// and `focus_visible`, aren't converted.
+pub fn to_palette(s: Style, version: PaletteVersion) -> Style {
+    if version == PaletteVersion::V3 {
+        return s;
+    }
+    let s = match s.get_style_value(fs::TextColor) {
+        StyleValue::Val(Some(color)) => s.color(colors::to_v4(color)),
+        _ => s,
+    };
+    let s = match s.get_style_value(fs::OutlineColor) {
+        StyleValue::Val(Brush::Solid(color)) => s.outline_color(colors::to_v4(color)),
+        _ => s,
+    };
+    let s = map_brush(s, fs::Background);
+    let s = map_brush(s, fs::BorderTopColor);
+    let s = map_brush(s, fs::BorderRightColor);
+    let s = map_brush(s, fs::BorderBottomColor);
+    map_brush(s, fs::BorderLeftColor)
+}
+
+/// Applies [`to_palette`] with the version in effect
+pub(crate) fn apply_palette(s: Style) -> Style {
+    to_palette(s, palette_version())
+}
+
+/// The theme provided to a scope
+#[derive(Clone, Copy)]
+struct Provided(Theme);
+
+/// Builds a subtree with `theme` instead of the app's theme
+///
+/// Views created by `view` see the theme through the reactive context, so
+/// it covers their children too, and a nested `themed` overrides it again.
+pub fn themed<V: 'static>(theme: Theme, view: impl FnOnce() -> V) -> V {
+    Scope::current().create_child().enter(|| {
+        provide_context(Provided(theme));
+        view()
+    })
+}
+
+/// The theme in effect where a view is created
+#[derive(Clone, Copy)]
+pub(crate) struct Source(Option<Theme>);
+
+impl Source {
+    /// Captures the provided theme, if any, of the current scope
+    pub(crate) fn current() -> Self {
+        Source(use_context::<Provided>().map(|provided| provided.0))
+    }
+
+    /// Runs `f` with the captured theme in effect
+    pub(crate) fn with<R>(self, f: impl FnOnce() -> R) -> R {
+        match self.0 {
+            Some(theme) => with_theme(theme, f),
+            None => f(),
+        }
+    }
+}
+
+/// Records the OS preference
+pub(crate) fn set_system(theme: floem::window::Theme) {
+    SYSTEM_DARK.with(|signal| signal.set(theme == floem::window::Theme::Dark));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspect::to_class_string;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_with_theme() {
+        let style = || floem::style::Style::new().p_4().dark(|s| s.bg_gray_900());
+        assert_eq!(
+            to_class_string(&with_theme(Theme::Dark, style)),
+            "p-4 bg-gray-900"
+        );
+        let light = with_theme(Theme::Dark, || with_theme(Theme::Light, style));
+        assert_eq!(to_class_string(&light), "p-4");
+        assert_eq!(SCOPED.get(), None);
+    }
+
+    #[test]
+    fn test_to_palette() {
+        let s = floem::style::Style::new()
+            .bg_blue_500()
+            .text_white()
+            .border_gray_200();
+        assert_eq!(
+            to_palette(s.clone(), PaletteVersion::V3).get_style_value(fs::Background),
+            StyleValue::Val(Some(Brush::Solid(colors::blue::C500)))
+        );
+        let v4 = with_palette_version(PaletteVersion::V4, || apply_palette(s));
+        assert_eq!(
+            v4.get_style_value(fs::Background),
+            StyleValue::Val(colors::v4_shade(colors::Family::Blue, 500).map(Brush::Solid))
+        );
+        assert_eq!(
+            v4.get_style_value(fs::TextColor),
+            StyleValue::Val(Some(colors::WHITE))
+        );
+        assert_eq!(
+            v4.get_style_value(fs::BorderLeftColor),
+            StyleValue::Val(colors::v4_shade(colors::Family::Gray, 200).map(Brush::Solid))
+        );
+    }
+}
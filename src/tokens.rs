@@ -0,0 +1,440 @@
+//! Design token import
+//!
+//! Reads design tokens into a [`TailwindConfig`], so a designer's color,
+//! spacing, radius, and typography tokens become classes like `bg-brand-500`
+//! and `p-18`. Two formats are supported: the JSON that Figma Tokens / Tokens
+//! Studio exports ([`from_figma`]),
+//!
+//! ```json
+//! {
+//!   "colors": {
+//!     "accent": { "value": "#f97316", "type": "color" },
+//!     "brand": { "500": { "value": "#6366f1", "type": "color" } }
+//!   },
+//!   "spacing": { "18": { "value": "72px", "type": "spacing" } },
+//!   "borderRadius": { "DEFAULT": { "value": "6", "type": "borderRadius" } }
+//! }
+//! ```
+//!
+//! and the W3C Design Tokens Community Group format ([`from_dtcg`]), where
+//! `$type` may be set on a group for all the tokens in it:
+//!
+//! ```json
+//! {
+//!   "color": {
+//!     "$type": "color",
+//!     "brand": { "500": { "$value": "#6366f1" } }
+//!   },
+//!   "spacing": {
+//!     "$type": "dimension",
+//!     "18": { "$value": { "value": 72, "unit": "px" } }
+//!   }
+//! }
+//! ```
+//!
+//! A token's name is its path joined with `-`, without a leading category
+//! group such as `colors` or `spacing`. Colors with a path of two or more
+//! segments become shades of a family (`brand.500` → `brand-500`). Aliases
+//! (`{colors.brand.500}`) are resolved, lengths in `px` or `rem` are converted
+//! to pixels, and typography tokens contribute their font size. Dimension
+//! tokens go to the scale their category group names (`radius`,
+//! `fontSize`, ...), or to spacing. Tokens of other types, and values that
+//! can't be resolved, such as math expressions, are skipped.
+//!
+//! A multi-set Tokens Studio export with `$metadata.tokenSetOrder` merges its
+//! sets in that order, later sets overriding earlier ones.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+
+use crate::config::{ColorValue, TailwindConfig};
+use crate::rem;
+
+/// Groups that name a token category rather than part of the token name
+const CATEGORY_GROUPS: &[&str] = &[
+    "color",
+    "colors",
+    "spacing",
+    "sizing",
+    "dimension",
+    "dimensions",
+    "borderRadius",
+    "radius",
+    "radii",
+    "fontSize",
+    "fontSizes",
+    "typography",
+];
+
+/// Category groups of border radius tokens
+const RADIUS_GROUPS: &[&str] = &["borderRadius", "radius", "radii"];
+
+/// Category groups of font size tokens
+const FONT_SIZE_GROUPS: &[&str] = &["fontSize", "fontSizes"];
+
+/// How many aliases are followed before a token is treated as unresolvable
+const MAX_ALIAS_DEPTH: usize = 16;
+
+/// A design token file format
+#[derive(Clone, Copy)]
+enum Format {
+    /// Figma Tokens / Tokens Studio: `value` and `type` on each token
+    Figma,
+    /// W3C DTCG: `$value` on each token, `$type` on it or an enclosing group
+    Dtcg,
+}
+
+impl Format {
+    fn value_key(self) -> &'static str {
+        match self {
+            Format::Figma => "value",
+            Format::Dtcg => "$value",
+        }
+    }
+
+    fn type_key(self) -> &'static str {
+        match self {
+            Format::Figma => "type",
+            Format::Dtcg => "$type",
+        }
+    }
+}
+
+/// A token leaf: its path within its set, its type, and its raw value
+struct Token<'a> {
+    path: Vec<&'a str>,
+    kind: &'a str,
+    value: &'a Value,
+}
+
+impl Token<'_> {
+    /// The token's name in the config, or `None` for a bare category group
+    fn name(&self) -> Option<String> {
+        let path = match self.path.split_first() {
+            Some((first, rest)) if CATEGORY_GROUPS.contains(first) => rest,
+            _ => &self.path[..],
+        };
+        (!path.is_empty()).then(|| path.join("-"))
+    }
+
+    /// Whether the token's leading group is one of `groups`
+    fn in_group(&self, groups: &[&str]) -> bool {
+        self.path.first().is_some_and(|first| groups.contains(first))
+    }
+}
+
+/// Parses a Figma Tokens / Tokens Studio export
+pub fn from_figma(source: &str) -> Result<TailwindConfig, serde_json::Error> {
+    let root: Value = serde_json::from_str(source)?;
+    Ok(import_sets(token_sets(&root), Format::Figma))
+}
+
+/// Parses a W3C Design Tokens Community Group (DTCG) file
+pub fn from_dtcg(source: &str) -> Result<TailwindConfig, serde_json::Error> {
+    let root: Value = serde_json::from_str(source)?;
+    Ok(import_sets(vec![&root], Format::Dtcg))
+}
+
+/// Builds a config from token sets, later sets overriding earlier ones
+fn import_sets(sets: Vec<&Value>, format: Format) -> TailwindConfig {
+    let mut tokens = Vec::new();
+    for set in sets {
+        collect(set, format, None, &mut Vec::new(), &mut tokens);
+    }
+    let by_path: HashMap<String, &Value> = tokens
+        .iter()
+        .map(|token| (token.path.join("."), token.value))
+        .collect();
+
+    let mut config = TailwindConfig::default();
+    for token in &tokens {
+        import(&mut config, token, &by_path);
+    }
+    config
+}
+
+/// The token sets of an export, in override order
+fn token_sets(root: &Value) -> Vec<&Value> {
+    let order = root
+        .pointer("/$metadata/tokenSetOrder")
+        .and_then(Value::as_array);
+    match order {
+        Some(order) => order
+            .iter()
+            .filter_map(Value::as_str)
+            .filter_map(|set| root.get(set))
+            .collect(),
+        None => vec![root],
+    }
+}
+
+/// Collects the token leaves below `value`, skipping `$`-prefixed metadata
+///
+/// `inherited` is the `$type` of the nearest enclosing group, for DTCG files.
+fn collect<'a>(
+    value: &'a Value,
+    format: Format,
+    inherited: Option<&'a str>,
+    path: &mut Vec<&'a str>,
+    out: &mut Vec<Token<'a>>,
+) {
+    let Value::Object(group) = value else {
+        return;
+    };
+    let kind = group
+        .get(format.type_key())
+        .and_then(Value::as_str)
+        .or(inherited);
+    if let Some(value) = group.get(format.value_key()) {
+        if let Some(kind) = kind {
+            out.push(Token {
+                path: path.clone(),
+                kind,
+                value,
+            });
+        }
+        return;
+    }
+    let inherited = match format {
+        Format::Figma => None,
+        Format::Dtcg => kind,
+    };
+    for (key, child) in group {
+        if key.starts_with('$') {
+            continue;
+        }
+        path.push(key);
+        collect(child, format, inherited, path, out);
+        path.pop();
+    }
+}
+
+/// Follows `{path.to.token}` aliases to a concrete value
+fn resolve<'a>(value: &'a Value, by_path: &HashMap<String, &'a Value>) -> Option<&'a Value> {
+    let mut value = value;
+    for _ in 0..MAX_ALIAS_DEPTH {
+        let alias = value
+            .as_str()
+            .and_then(|s| s.strip_prefix('{'))
+            .and_then(|s| s.strip_suffix('}'));
+        match alias {
+            Some(path) => value = by_path.get(path)?,
+            None => return Some(value),
+        }
+    }
+    None
+}
+
+/// Parses a length token (`16`, `"16"`, `"16px"`, `"1rem"`,
+/// `{ "value": 1, "unit": "rem" }`) in pixels
+fn parse_length(value: &Value) -> Option<f64> {
+    if let Some(px) = value.as_f64() {
+        return Some(px);
+    }
+    if let (Some(length), Some(unit)) = (value.get("value"), value.get("unit")) {
+        let length = length.as_f64()?;
+        return match unit.as_str()? {
+            "px" => Some(length),
+            "rem" => Some(rem::to_px(length)),
+            _ => None,
+        };
+    }
+    let value = value.as_str()?.trim();
+    if let Some(value) = value.strip_suffix("rem") {
+        return value.trim().parse().ok().map(rem::to_px);
+    }
+    value.strip_suffix("px").unwrap_or(value).trim().parse().ok()
+}
+
+/// Adds a token to the config if its type maps to a config scale
+fn import(config: &mut TailwindConfig, token: &Token, by_path: &HashMap<String, &Value>) {
+    let Some(name) = token.name() else {
+        return;
+    };
+    let Some(value) = resolve(token.value, by_path) else {
+        return;
+    };
+    match token.kind {
+        "color" => {
+            // DTCG colors may be objects with a `hex` fallback
+            let color = value
+                .as_str()
+                .or_else(|| value.get("hex").and_then(Value::as_str));
+            if let Some(color) = color {
+                insert_color(config, name, color);
+            }
+        }
+        "dimension" if token.in_group(RADIUS_GROUPS) => {
+            if let Some(px) = parse_length(value) {
+                config.border_radius.insert(name, px);
+            }
+        }
+        "dimension" if token.in_group(FONT_SIZE_GROUPS) => {
+            if let Some(px) = parse_length(value) {
+                config.font_size.insert(name, px as f32);
+            }
+        }
+        "spacing" | "sizing" | "dimension" => {
+            if let Some(px) = parse_length(value) {
+                config.spacing.insert(name, px);
+            }
+        }
+        "borderRadius" => {
+            if let Some(px) = parse_length(value) {
+                config.border_radius.insert(name, px);
+            }
+        }
+        "fontSizes" => {
+            if let Some(px) = parse_length(value) {
+                config.font_size.insert(name, px as f32);
+            }
+        }
+        "typography" => {
+            let font_size = value
+                .get("fontSize")
+                .and_then(|size| resolve(size, by_path))
+                .and_then(parse_length);
+            if let Some(px) = font_size {
+                config.font_size.insert(name, px as f32);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Adds a color token, as a shade of its family when its path has one
+fn insert_color(config: &mut TailwindConfig, name: String, color: &str) {
+    let Some((family, shade)) = name.rsplit_once('-') else {
+        config
+            .colors
+            .insert(name, ColorValue::Single(color.to_string()));
+        return;
+    };
+    let entry = config
+        .colors
+        .entry(family.to_string())
+        .or_insert_with(|| ColorValue::Shades(BTreeMap::new()));
+    if let ColorValue::Single(_) = entry {
+        *entry = ColorValue::Shades(BTreeMap::new());
+    }
+    if let ColorValue::Shades(shades) = entry {
+        shades.insert(shade.to_string(), color.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use peniko::Color;
+
+    use super::*;
+
+    #[test]
+    fn test_from_figma() {
+        let config = from_figma(
+            r##"{
+                "global": {
+                    "colors": {
+                        "accent": { "value": "#f97316", "type": "color" },
+                        "brand": {
+                            "500": { "value": "#6366f1", "type": "color" },
+                            "600": { "value": "{colors.primary}", "type": "color" }
+                        },
+                        "primary": { "value": "#4f46e5", "type": "color" }
+                    },
+                    "spacing": {
+                        "18": { "value": "72px", "type": "spacing" },
+                        "gutter": { "value": "1.5rem", "type": "spacing" },
+                        "double": { "value": "{spacing.18} * 2", "type": "spacing" }
+                    },
+                    "borderRadius": {
+                        "DEFAULT": { "value": "6", "type": "borderRadius" }
+                    },
+                    "typography": {
+                        "heading": {
+                            "value": { "fontFamily": "Inter", "fontSize": "{fontSizes.xl}" },
+                            "type": "typography"
+                        }
+                    },
+                    "fontSizes": {
+                        "xl": { "value": 22, "type": "fontSizes" }
+                    }
+                },
+                "dark": {
+                    "colors": {
+                        "accent": { "value": "#fb923c", "type": "color" }
+                    }
+                },
+                "$metadata": { "tokenSetOrder": ["global", "dark"] }
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.colors["accent"],
+            ColorValue::Single("#fb923c".to_string())
+        );
+        assert_eq!(
+            config.color("brand-600"),
+            Some(Color::from_rgb8(0x4f, 0x46, 0xe5))
+        );
+        assert_eq!(config.spacing("18"), Some(72.0));
+        assert_eq!(config.spacing("gutter"), Some(24.0));
+        assert_eq!(config.spacing("double"), None);
+        assert_eq!(config.radius(""), Some(6.0));
+        assert_eq!(config.font_size("heading"), Some(22.0));
+        assert_eq!(config.font_size("xl"), Some(22.0));
+    }
+
+    #[test]
+    fn test_from_dtcg() {
+        let config = from_dtcg(
+            r##"{
+                "color": {
+                    "$type": "color",
+                    "accent": { "$value": "{color.brand.600}" },
+                    "brand": {
+                        "500": { "$value": "#6366f1" },
+                        "600": {
+                            "$value": {
+                                "colorSpace": "srgb",
+                                "components": [0.31, 0.27, 0.9],
+                                "hex": "#4f46e5"
+                            }
+                        }
+                    }
+                },
+                "spacing": {
+                    "$type": "dimension",
+                    "18": { "$value": { "value": 72, "unit": "px" } },
+                    "gutter": { "$value": "1.5rem" }
+                },
+                "radius": {
+                    "$type": "dimension",
+                    "DEFAULT": { "$value": "6px" }
+                },
+                "fontSize": {
+                    "hero": { "$type": "dimension", "$value": "40px" }
+                },
+                "duration": {
+                    "fast": { "$type": "duration", "$value": "100ms" }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.color("brand-500"),
+            Some(Color::from_rgb8(0x63, 0x66, 0xf1))
+        );
+        assert_eq!(
+            config.color("accent"),
+            Some(Color::from_rgb8(0x4f, 0x46, 0xe5))
+        );
+        assert_eq!(config.spacing("18"), Some(72.0));
+        assert_eq!(config.spacing("gutter"), Some(24.0));
+        assert_eq!(config.radius(""), Some(6.0));
+        assert_eq!(config.font_size("hero"), Some(40.0));
+        assert!(config.spacing.get("fast").is_none());
+    }
+}
@@ -0,0 +1,83 @@
+//! Tracing of applied utilities (`tracing` feature)
+//!
+//! Emits [`tracing`] spans and events under the `floem_tailwind` target, so
+//! style hot paths can be profiled with any subscriber:
+//!
+//! - a `tw` span for each style pass of a
+//!   [`TailwindViewExt::tw`](crate::TailwindViewExt::tw) view, with the view
+//!   id and class string
+//! - an `apply_tw_classes` span with the class list, and a `TailwindConfig`
+//!   `apply_classes` span with the class string
+//! - a `TRACE` event for each utility method and `apply_tw_class` call
+//!
+//! The time spent resolving classes is the time spent in the spans; with
+//! `tracing-subscriber`, `FmtSpan::CLOSE` prints it when each span closes:
+//!
+//! ```rust,ignore
+//! tracing_subscriber::fmt()
+//!     .with_env_filter("floem_tailwind=debug")
+//!     .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+//!     .init();
+//! ```
+//!
+//! Without a subscriber interested in the target, the spans and events cost
+//! a cached check each.
+
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use floem::ViewId;
+use tracing::span::EnteredSpan;
+
+use crate::class::TwClass;
+
+/// The tracing target of all spans and events
+pub const TARGET: &str = "floem_tailwind";
+
+/// Classes as a space-separated list, formatted only when recorded
+pub(crate) struct ClassList<'a>(pub &'a [TwClass]);
+
+impl fmt::Display for ClassList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, class) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{class}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The class string of a `tw` view, and the view's id once it's known
+#[derive(Clone)]
+pub(crate) struct TracedView {
+    classes: Rc<str>,
+    view: Rc<Cell<Option<ViewId>>>,
+}
+
+impl TracedView {
+    pub(crate) fn new(classes: &str) -> Self {
+        TracedView {
+            classes: classes.into(),
+            view: Rc::default(),
+        }
+    }
+
+    /// Records the id of the view, which exists only after `style` returns it
+    pub(crate) fn set_view(&self, id: ViewId) {
+        self.view.set(Some(id));
+    }
+
+    /// Enters the span of a style pass
+    pub(crate) fn enter(&self) -> EnteredSpan {
+        tracing::debug_span!(
+            target: TARGET,
+            "tw",
+            view = ?self.view.get(),
+            classes = %self.classes,
+        )
+        .entered()
+    }
+}
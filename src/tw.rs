@@ -0,0 +1,210 @@
+//! Utilities as Floem style classes
+//!
+//! [`tw_class!`](crate::tw_class) declares a Floem `StyleClass` whose default
+//! style comes from a class string. Registering the classes on a root view
+//! with [`theme`] (or [`rule`] for your own) lets every view opt in with
+//! `.class(...)`, and lets an app override a class theme-wide through Floem's
+//! class mechanism instead of editing each view.
+//!
+//! [`tailwind_theme`] styles Floem's own widgets the same way.
+//!
+//! ```rust
+//! use floem::prelude::*;
+//! use floem_tailwind::{TailwindExt, tw, tw_class};
+//!
+//! tw_class!(
+//!     /// Destructive action button
+//!     pub DangerClass = "bg-red-500 text-white"
+//! );
+//!
+//! let app = v_stack((
+//!     button("Save").class(tw::BTN).class(tw::BTN_PRIMARY),
+//!     button("Delete").class(tw::BTN).class(DangerClass),
+//! ))
+//! .style(|s| {
+//!     tw::rule::<DangerClass>(s.apply(tw::theme()))
+//!         // App-wide override of the preset
+//!         .class(tw::BtnClass, |s| s.rounded_full())
+//! });
+//! ```
+
+use floem::style::{Style, StyleClass};
+use floem::views::{
+    ButtonClass, CheckboxClass, LabelClass, ListItemClass, PlaceholderTextClass, TextInputClass,
+    TooltipClass, scroll,
+};
+
+use crate::colors::{self, Family};
+use crate::presets::{self, ButtonVariant, Size};
+use crate::{TailwindExt, contrast, font_size, intern, rem, spacing};
+
+/// A `StyleClass` with a default style given as a class string
+pub trait TwStyleClass: StyleClass {
+    /// The utilities applied by this class
+    const CLASSES: &'static str;
+
+    /// The class's default style; unknown classes are ignored
+    ///
+    /// Colors follow the [`contrast`](crate::contrast) mode and, outside
+    /// variants such as `hover:`, the
+    /// [palette version](crate::theme::palette_version).
+    fn style() -> Style {
+        let s = Style::new().apply_tw_classes(&intern::parse_classes_lossy(Self::CLASSES));
+        crate::theme::apply_palette(crate::contrast::apply(s))
+    }
+}
+
+/// Declares a Floem `StyleClass` styled by a class string
+///
+/// ```rust
+/// floem_tailwind::tw_class!(pub ChipClass = "px-2 py-1 rounded-full bg-gray-100 text-xs");
+/// ```
+#[macro_export]
+macro_rules! tw_class {
+    ($(#[$meta:meta])* $v:vis $name:ident = $classes:expr) => {
+        $crate::__floem::style_class!($(#[$meta])* $v $name);
+
+        impl $crate::tw::TwStyleClass for $name {
+            const CLASSES: &'static str = $classes;
+        }
+    };
+}
+
+/// Registers a class's default style on `s`, typically the root view's style
+pub fn rule<C: TwStyleClass>(s: Style) -> Style {
+    s.class(C::default(), |s| s.apply(C::style()))
+}
+
+tw_class!(
+    /// Base button: padding, rounded corners, medium weight
+    pub BtnClass = "px-4 py-2 rounded-md font-medium cursor-pointer"
+);
+tw_class!(
+    /// Primary button colors, on top of [`BtnClass`]
+    pub BtnPrimaryClass = "bg-blue-500 text-white"
+);
+tw_class!(
+    /// Raised content surface
+    pub CardClass = "p-6 rounded-lg bg-white shadow-md"
+);
+tw_class!(
+    /// Small pill label
+    pub BadgeClass = "px-2 rounded-full text-xs font-medium bg-gray-100 text-gray-700"
+);
+tw_class!(
+    /// Bordered text input
+    pub InputClass = "px-3 py-2 rounded-md border border-gray-300 bg-white placeholder-gray-400"
+);
+
+/// btn
+pub const BTN: BtnClass = BtnClass;
+/// btn-primary
+pub const BTN_PRIMARY: BtnPrimaryClass = BtnPrimaryClass;
+/// card
+pub const CARD: CardClass = CardClass;
+/// badge
+pub const BADGE: BadgeClass = BadgeClass;
+/// input
+pub const INPUT: InputClass = InputClass;
+
+/// [`rule`] for a control, adding a [`focus_ring`](TailwindExt::focus_ring)
+fn focusable_rule<C: TwStyleClass>(s: Style) -> Style {
+    s.class(C::default(), |s| s.apply(C::style()).focus_ring())
+}
+
+/// Registers all preset classes with their default styles
+///
+/// Buttons and inputs show a focus ring for keyboard focus; turn it off for
+/// a view with [`no_focus_ring`](TailwindExt::no_focus_ring).
+pub fn theme() -> Style {
+    let s = focusable_rule::<BtnClass>(Style::new());
+    let s = rule::<BtnPrimaryClass>(s);
+    let s = rule::<CardClass>(s);
+    let s = rule::<BadgeClass>(s);
+    focusable_rule::<InputClass>(s)
+}
+
+/// A Floem app theme in Tailwind's look: default styles for Floem's buttons,
+/// text inputs, checkboxes, labels, list items, tooltips, and scrollbars
+///
+/// Set it on the root view; views inherit the class styles, and the app's
+/// own styles still override them. Buttons are [`presets::button`] outline
+/// buttons and inputs follow [`InputClass`], all with dark variants.
+///
+/// ```rust,no_run
+/// use floem::prelude::*;
+/// use floem_tailwind::tailwind_theme;
+///
+/// let app = v_stack((label(|| "Name"), text_input(RwSignal::new(String::new()))))
+///     .style(|s| s.apply(tailwind_theme()));
+/// ```
+pub fn tailwind_theme() -> Style {
+    let gray = |shade| colors::shade(Family::Gray, shade);
+    let s = Style::new()
+        .font_size(rem::scale_f32(font_size::TEXT_SM))
+        .color(gray(900))
+        .dark(|s| s.color(gray(100)))
+        .class(LabelClass, |s| {
+            s.color(gray(900)).dark(|s| s.color(gray(100)))
+        })
+        .class(ButtonClass, |s| {
+            s.apply(presets::button(
+                Family::Gray,
+                ButtonVariant::Outline,
+                Size::Md,
+            ))
+        })
+        .class(TextInputClass, |s| {
+            s.apply(InputClass::style())
+                .color(gray(900))
+                .focus_ring()
+                .dark(|s| {
+                    s.background(gray(900))
+                        .border_color(gray(700))
+                        .color(gray(100))
+                })
+        })
+        .class(PlaceholderTextClass, |s| {
+            s.color(gray(400)).dark(|s| s.color(gray(500)))
+        })
+        .class(CheckboxClass, |s| {
+            s.size(
+                rem::scale(spacing::SPACING_4),
+                rem::scale(spacing::SPACING_4),
+            )
+            .rounded()
+            .border_1()
+            .border_color(gray(300))
+            .background(colors::WHITE)
+            .focus_ring()
+            .dark(|s| s.background(gray(900)).border_color(gray(600)))
+        })
+        .class(ListItemClass, |s| s.apply(presets::menu_item()))
+        .class(TooltipClass, |s| s.apply(presets::tooltip()))
+        .class(scroll::Handle, |s| {
+            s.rounded_full()
+                .background(gray(300))
+                .hover(|s| s.background(gray(400)))
+                .dark(|s| s.background(gray(600)).hover(|s| s.background(gray(500))))
+        })
+        .class(scroll::Track, |s| s.background(colors::TRANSPARENT));
+    contrast::apply(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use floem::style::{StyleValue, TextColor};
+
+    use super::*;
+    use crate::theme::{PaletteVersion, Theme, with_palette_version, with_theme};
+
+    #[test]
+    fn test_tailwind_theme() {
+        let build =
+            |theme| with_palette_version(PaletteVersion::V3, || with_theme(theme, tailwind_theme));
+        let (light, dark) = (build(Theme::Light), build(Theme::Dark));
+        let text = |s: &Style| s.get_style_value(TextColor);
+        assert_eq!(text(&light), StyleValue::Val(Some(colors::gray::C900)));
+        assert_eq!(text(&dark), StyleValue::Val(Some(colors::gray::C100)));
+    }
+}
@@ -0,0 +1,112 @@
+//! Styling views directly with class strings
+//!
+//! ```rust
+//! use floem::prelude::*;
+//! use floem_tailwind::prelude::*;
+//!
+//! let toolbar = h_stack((label(|| "Title"), button("Close")))
+//!     .tw("flex gap-2 p-4 bg-white shadow-sm");
+//! let badge = label(|| "New").tw_style(|s| s.px_2().rounded_full().bg_blue_100());
+//! ```
+
+use floem::event::{Event, EventListener};
+use floem::style::Style;
+use floem::views::Decorators;
+
+use crate::TailwindExt;
+use crate::{contrast, density, direction, inspect, intern, theme, warning, window};
+
+/// Class-string styling for any view with Floem's `Decorators`
+pub trait TailwindViewExt: Decorators {
+    /// Style the view from a class string, e.g. `.tw("flex gap-2 p-4")`
+    ///
+    /// **Unknown classes are dropped**: a typo like `"p-4 bg-blu-500"` styles
+    /// the view with `p-4` alone. Debug builds report the first unknown class
+    /// of each view as a [`warning`](crate::warning); use
+    /// [`class::parse_classes`](crate::class::parse_classes) to reject such
+    /// strings instead.
+    ///
+    /// The string is parsed once. Spacing and radii follow the [`density`]
+    /// factor, logical utilities the [`direction`], dark styles and the
+    /// palette version the [`theme`], and colors the [`contrast`] mode. The
+    /// string is recorded for [`inspect::view_classes`] while recording is on.
+    fn tw(self, classes: &str) -> Self::DV {
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::check_classes(classes);
+        #[cfg(feature = "tracing")]
+        let traced = crate::trace::TracedView::new(classes);
+        #[cfg(feature = "tracing")]
+        let traced_view = traced.clone();
+        let source = classes;
+        let classes = intern::parse_classes(source).unwrap_or_else(|error| {
+            if cfg!(debug_assertions) {
+                warning::warn(format_args!("`tw(\"{source}\")`: {error}"));
+            }
+            intern::parse_classes_lossy(source)
+        });
+        let density = density::Source::current();
+        let direction = direction::Source::current();
+        let theme = theme::Source::current();
+        let view = self.style(move |s| {
+            #[cfg(feature = "tracing")]
+            let _span = traced.enter();
+            let s = theme.with(|| {
+                density.with(|| {
+                    direction::with_direction(direction.direction(), || {
+                        s.apply_tw_classes(&classes)
+                    })
+                })
+            });
+            theme::apply_palette(contrast::apply(s))
+        });
+        let id = floem::View::id(&view);
+        inspect::record_view(id, || source.to_string());
+        #[cfg(feature = "tracing")]
+        traced_view.set_view(id);
+        view
+    }
+
+    /// Style the view with a builder closure that runs once, for static styles
+    ///
+    /// Spacing and radii follow the [`density`] factor, logical utilities the
+    /// [`direction`] and dark styles the [`theme`] when the view is created,
+    /// and colors the [`contrast`] mode and palette version. Use
+    /// [`tw`](Self::tw) for spacing that follows density changes.
+    fn tw_style(self, f: impl FnOnce(Style) -> Style) -> Self::DV {
+        let direction = direction::Source::current().direction();
+        let theme = theme::Source::current();
+        let density = density::Source::current();
+        let style = theme.with(|| {
+            density.with(|| direction::with_direction(direction, || f(Style::new())))
+        });
+        let source = inspect::is_recording().then(|| inspect::to_class_string(&style));
+        let view =
+            self.style(move |s| s.apply(theme::apply_palette(contrast::apply(style.clone()))));
+        if let Some(source) = source {
+            inspect::record_view(floem::View::id(&view), || source);
+        }
+        view
+    }
+
+    /// Track the window size for [`breakpoint`](crate::TailwindExt::breakpoint)
+    /// styles; call on the root view of the window
+    fn track_window_size(self) -> Self::DV {
+        self.on_event_cont(EventListener::WindowResized, |event| {
+            if let Event::WindowResized(size) = event {
+                window::set_size(*size);
+            }
+        })
+    }
+
+    /// Track the OS light/dark preference for [`Theme::System`](crate::theme::Theme::System);
+    /// call on the root view of the window
+    fn track_system_theme(self) -> Self::DV {
+        self.on_event_cont(EventListener::ThemeChanged, |event| {
+            if let Event::ThemeChanged(system) = event {
+                theme::set_system(*system);
+            }
+        })
+    }
+}
+
+impl<V: Decorators> TailwindViewExt for V {}
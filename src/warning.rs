@@ -0,0 +1,40 @@
+//! Warnings about class strings and utilities
+//!
+//! In debug builds, problems that don't stop styling, such as unknown classes
+//! in an [`apply!`](crate::apply) definition or a
+//! [`tw`](crate::TailwindViewExt::tw) class string, are reported as warnings.
+//! They're printed to stderr unless the app sets a hook, e.g. to route them
+//! to its logger:
+//!
+//! ```rust
+//! floem_tailwind::warning::set_hook(|message| eprintln!("[ui] {message}"));
+//! ```
+//!
+//! Release builds don't check, so they never warn.
+
+use std::fmt::Display;
+use std::sync::RwLock;
+
+/// A function receiving each warning
+type Hook = Box<dyn Fn(&str) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Sends warnings to `hook` instead of stderr
+pub fn set_hook(hook: impl Fn(&str) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|error| error.into_inner()) = Some(Box::new(hook));
+}
+
+/// Sends warnings to stderr again
+pub fn reset_hook() {
+    *HOOK.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+/// Reports a warning to the hook, or to stderr
+pub(crate) fn warn(message: impl Display) {
+    let hook = HOOK.read().unwrap_or_else(|error| error.into_inner());
+    match &*hook {
+        Some(hook) => hook(&message.to_string()),
+        None => eprintln!("floem-tailwind: {message}"),
+    }
+}
@@ -0,0 +1,50 @@
+//! Window size tracking for breakpoint and viewport styles
+//!
+//! Floem's `responsive` styles use Floem's own breakpoints, and percentages
+//! are relative to the parent, so this crate keeps the window size in a
+//! signal of its own for `breakpoint` and the viewport helpers (`w_vw`,
+//! `h_vh`, ...). Track it from the root view of the window:
+//!
+//! ```rust,no_run
+//! use floem::prelude::*;
+//! use floem_tailwind::prelude::*;
+//! use floem_tailwind::screen::Breakpoint;
+//!
+//! let app = v_stack((label(|| "Sidebar"), label(|| "Content")))
+//!     .style(|s| s.flex_col().breakpoint(Breakpoint::Md, |s| s.flex_row()))
+//!     .track_window_size();
+//! let dialog = label(|| "Settings").style(|s| s.w_vw(50.0).max_h_vh(75.0));
+//! ```
+//!
+//! Styles that read the size rerun when the window is resized. With several
+//! windows, the size is the one most recently resized.
+
+use floem::kurbo::Size;
+use floem::reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+
+thread_local! {
+    /// Created in its own root scope so no view's disposal drops it
+    static WINDOW_SIZE: RwSignal<Size> = Scope::new().create_rw_signal(Size::ZERO);
+}
+
+/// The tracked window size, subscribing the caller to resizes
+///
+/// Zero until the tracking view receives its first resize event.
+pub fn size() -> Size {
+    WINDOW_SIZE.with(|size| size.get())
+}
+
+/// The tracked window width; see [`size`]
+pub fn width() -> f64 {
+    size().width
+}
+
+/// The tracked window height; see [`size`]
+pub fn height() -> f64 {
+    size().height
+}
+
+/// Records a new window size
+pub(crate) fn set_size(new: Size) {
+    WINDOW_SIZE.with(|size| size.set(new));
+}